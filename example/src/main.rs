@@ -2,14 +2,13 @@ use std::env;
 use std::sync::Arc;
 
 use dotenv::dotenv;
-use tokio::join;
 use voiceflousion::core::base_structs::ClientsManager;
 use voiceflousion::core::ClientBuilder;
 use voiceflousion::core::voiceflow::VoiceflowClient;
 use voiceflousion::integrations::telegram::TelegramClient;
 use voiceflousion::integrations::whatsapp::WhatsAppClient;
 use voiceflousion::server::handlers::base_dialog_handler;
-use voiceflousion::server::VoiceflousionServer;
+use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
 
 #[tokio::main]
 async fn main() {
@@ -45,17 +44,9 @@ async fn main() {
         |update, client| Box::pin(base_dialog_handler(update, client))
     }).set_clients_manager(whatsapp_client_manager);
 
-    let telegram_voiceflousion_server_closure = async {
-        telegram_voiceflousion_server
-            .run(([127, 0, 0, 1], 8080))
-            .await;
-    };
-
-    let whatsapp_voiceflousion_server_closure = async {
-        whatsapp_voiceflousion_server
-            .run(([127, 0, 0, 1], 8081))
-            .await;
-    };
-
-    join!(telegram_voiceflousion_server_closure, whatsapp_voiceflousion_server_closure);
+    VoiceflousionMultiServer::new()
+        .add_integration(telegram_voiceflousion_server)
+        .add_integration(whatsapp_voiceflousion_server)
+        .run(([127, 0, 0, 1], 8080))
+        .await;
 }
\ No newline at end of file
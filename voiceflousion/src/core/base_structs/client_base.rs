@@ -1,11 +1,12 @@
+use std::collections::HashMap;
 use std::ops::Deref;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::RwLock;
 use crate::core::base_structs::SessionsManager;
 use crate::core::ClientBuilder;
-use crate::core::subtypes::BotAuthToken;
-use crate::core::traits::Sender;
+use crate::core::subtypes::{BotAuthToken, InputKind, InputPolicy, QueueOverflowPolicy};
+use crate::core::traits::{CustomActionHandler, DeduplicationStore, HandoffHandler, Middleware, Sender};
 use crate::core::voiceflow::{State, VoiceflowClient};
 
 
@@ -29,6 +30,22 @@ pub struct ClientBase<H: Sender> {
     status: Arc<AtomicBool>,
     /// The bot authentication token, which may be updated or accessed as needed.
     bot_auth_token: Arc<RwLock<Option<BotAuthToken>>>,
+    /// A flag indicating whether Voiceflow responses are streamed to the client.
+    streaming: bool,
+    /// The handlers of Voiceflow custom actions mapped by the names of the actions.
+    custom_action_handlers: HashMap<String, Arc<dyn CustomActionHandler>>,
+    /// The policies of handling the user's inputs beyond text and buttons mapped by the kinds of the inputs.
+    input_policies: HashMap<InputKind, InputPolicy>,
+    /// The middlewares intercepting the interaction with Voiceflow in the order they are called.
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// The optional handler of the chats handed off to human agents.
+    handoff_handler: Option<Arc<dyn HandoffHandler>>,
+    /// The optional name of the Voiceflow custom action that hands the chat off to a human agent.
+    handoff_action: Option<String>,
+    /// The optional store of the processed updates, used for skipping redelivered updates.
+    deduplication_store: Option<Arc<dyn DeduplicationStore>>,
+    /// The optional depth of the chat queue and the policy of its overflow, used for processing the updates of the chat in order.
+    chat_queue: Option<(usize, QueueOverflowPolicy)>,
 }
 
 impl<H: Sender> ClientBase<H> {
@@ -50,15 +67,32 @@ impl<H: Sender> ClientBase<H> {
         let launch_state = builder.launch_state().clone();
         let status = builder.status();
         let secret_auth_token = builder.bot_auth_token().clone();
+        let session_store = builder.session_store().clone();
+        let streaming = builder.streaming();
+        let custom_action_handlers = builder.custom_action_handlers().clone();
+        let input_policies = builder.input_policies().clone();
+        let middlewares = builder.middlewares().clone();
+        let handoff_handler = builder.handoff_handler().clone();
+        let handoff_action = builder.handoff_action().clone();
+        let deduplication_store = builder.deduplication_store().clone();
+        let chat_queue = builder.chat_queue().clone();
         let sessions= builder.sessions();
 
         Self{
             client_id,
             voiceflow_client,
-            sessions: SessionsManager::new(sessions, session_duration, sessions_cleanup_interval),
+            sessions: SessionsManager::new(sessions, session_duration, sessions_cleanup_interval, session_store),
             sender,
             launch_state,
             status: Arc::new(AtomicBool::new(status)),
+            streaming,
+            custom_action_handlers,
+            input_policies,
+            middlewares,
+            handoff_handler,
+            handoff_action,
+            deduplication_store,
+            chat_queue,
             bot_auth_token: Arc::new(RwLock::new(
                 if let Some(token) = secret_auth_token{
                     Some(BotAuthToken::new(token))
@@ -120,6 +154,96 @@ impl<H: Sender> ClientBase<H> {
         &self.launch_state
     }
 
+    /// Checks if the streaming mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether Voiceflow responses are forwarded to the client block by block.
+    pub fn is_streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Returns the handler of the Voiceflow custom action with the specified name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the custom action.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the handler, or `None` if the action has no handler.
+    pub fn custom_action_handler(&self, name: &str) -> Option<&Arc<dyn CustomActionHandler>> {
+        self.custom_action_handlers.get(name)
+    }
+
+    /// Returns the policy of handling the specified kind of the user's input.
+    ///
+    /// If no policy is configured, reactions are ignored and all other kinds of input are forwarded to Voiceflow.
+    ///
+    /// # Parameters
+    ///
+    /// * `kind` - The kind of the input.
+    ///
+    /// # Returns
+    ///
+    /// The `InputPolicy` of the input kind.
+    pub fn input_policy(&self, kind: InputKind) -> InputPolicy {
+        match self.input_policies.get(&kind) {
+            Some(policy) => policy.clone(),
+            None if kind == InputKind::Reaction => InputPolicy::Ignore,
+            None => InputPolicy::Forward
+        }
+    }
+
+    /// Returns the middleware chain of the client.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the middlewares in the order they are called.
+    pub fn middlewares(&self) -> &Vec<Arc<dyn Middleware>> {
+        &self.middlewares
+    }
+
+    /// Returns the handler of the chats handed off to human agents.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the handoff handler.
+    pub fn handoff_handler(&self) -> Option<&Arc<dyn HandoffHandler>> {
+        self.handoff_handler.as_ref()
+    }
+
+    /// Returns the store of the processed updates.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the deduplication store.
+    pub fn deduplication_store(&self) -> Option<&Arc<dyn DeduplicationStore>> {
+        self.deduplication_store.as_ref()
+    }
+
+    /// Returns the depth of the chat queue and the policy of its overflow.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the depth and the overflow policy, `None` if the queued mode is disabled.
+    pub fn chat_queue(&self) -> Option<&(usize, QueueOverflowPolicy)> {
+        self.chat_queue.as_ref()
+    }
+
+    /// Checks if the Voiceflow custom action hands the chat off to a human agent.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the custom action.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the custom action is the handoff action of the client.
+    pub fn is_handoff_action(&self, name: &str) -> bool {
+        self.handoff_action.as_deref() == Some(name)
+    }
+
     /// Checks if the client is active.
     ///
     /// # Returns
@@ -186,7 +310,40 @@ impl<H: Sender> ClientBase<H> {
         let mut builder = ClientBuilder::new(client_id, api_key, voiceflow_client, max_connections_per_moment)
             .set_connection_duration(connection_duration)
             .set_launch_state(launch_state)
-            .set_status(status);
+            .set_status(status)
+            .set_streaming(self.streaming)
+            .set_custom_action_handlers(self.custom_action_handlers.clone())
+            .set_input_policies(self.input_policies.clone())
+            .set_middlewares(self.middlewares.clone())
+            .set_delivery_policy(self.sender.http_client().delivery_policy().clone());
+
+        builder = if let Some(handoff_handler) = &self.handoff_handler{
+            builder.set_handoff_handler(handoff_handler.clone())
+        }
+        else {
+            builder
+        };
+
+        builder = if let Some(handoff_action) = &self.handoff_action{
+            builder.set_handoff_action(handoff_action.clone())
+        }
+        else {
+            builder
+        };
+
+        builder = if let Some(deduplication_store) = &self.deduplication_store{
+            builder.set_deduplication_store(deduplication_store.clone())
+        }
+        else {
+            builder
+        };
+
+        builder = if let Some((depth, overflow_policy)) = &self.chat_queue{
+            builder.set_chat_queue(*depth, overflow_policy.clone())
+        }
+        else {
+            builder
+        };
 
         builder = if let Some(interval) =  self.sessions.cleanup_interval(){
             builder.allow_sessions_cleaning(interval)
@@ -202,6 +359,13 @@ impl<H: Sender> ClientBase<H> {
             builder
         };
 
+        builder = if let Some(store) = self.sessions.session_store(){
+            builder.set_session_store(store.clone())
+        }
+        else {
+            builder
+        };
+
         if let Some(duration) = self.sessions.valid_session_duration(){
             builder.set_session_duration(duration)
         }
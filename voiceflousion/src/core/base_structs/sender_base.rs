@@ -1,4 +1,4 @@
-use crate::core::subtypes::HttpClient;
+use crate::core::subtypes::{DeliveryPolicy, HttpClient};
 
 /// `SenderBase` is the foundational struct for managing HTTP requests and interactions with the API.
 ///
@@ -38,6 +38,30 @@ impl SenderBase{
         }
     }
 
+    /// Sets the policy of delivering the messages, i.e. the rate limits and the retry policy of the requests.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The policy of delivering the messages.
+    ///
+    /// # Returns
+    ///
+    /// The updated `SenderBase` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::base_structs::SenderBase;
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(30, 1000)), Some(RateLimit::new(1, 1000)), RetryPolicy::default());
+    /// let sender = SenderBase::new(10, "api_key".to_string(), Some(120)).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.http_client = self.http_client.set_delivery_policy(delivery_policy);
+        self
+    }
+
     /// Returns a reference to the HTTP client used for sending requests.
     ///
     /// # Returns
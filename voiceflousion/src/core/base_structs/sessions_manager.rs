@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::time::{interval, Duration};
 use crate::core::session_wrappers::{Session, SessionMap};
+use crate::core::traits::SessionStore;
 
 /// Manages the sessions and handles cleanup operations.
 ///
@@ -38,6 +39,7 @@ impl SessionsManager {
     /// * `sessions_option` - An optional vector of sessions to initialize the session map with.
     /// * `valid_session_duration` - The duration a session is considered valid in seconds.
     /// * `cleanup_interval` - The interval for cleanup in seconds.
+    /// * `session_store` - The optional store for persisting sessions.
     ///
     /// # Returns
     ///
@@ -53,15 +55,15 @@ impl SessionsManager {
     /// #[tokio::main]
     /// async fn main() -> () {
     ///     let sessions: Vec<Session> = vec![];
-    ///     let sessions_manager = SessionsManager::new(Some(sessions), Some(3600), Some(600));
+    ///     let sessions_manager = SessionsManager::new(Some(sessions), Some(3600), Some(600), None);
     /// }
     /// ```
-    pub fn new(sessions_option: Option<Vec<Session>>, valid_session_duration: Option<i64>, cleanup_interval: Option<u64>) -> Self {
+    pub fn new(sessions_option: Option<Vec<Session>>, valid_session_duration: Option<i64>, cleanup_interval: Option<u64>, session_store: Option<Arc<dyn SessionStore>>) -> Self {
         let manager = Self {
             session_map: Arc::new(
                 match sessions_option {
-                    None => SessionMap::new(valid_session_duration),
-                    Some(sessions) => SessionMap::from_sessions(sessions, valid_session_duration),
+                    None => SessionMap::new(valid_session_duration, session_store),
+                    Some(sessions) => SessionMap::from_sessions(sessions, valid_session_duration, session_store),
                 }
             ),
             cancel_token: Arc::new(AtomicBool::new(true)),
@@ -91,7 +93,7 @@ impl SessionsManager {
     /// #[tokio::main]
     /// async fn main() -> () {
     ///     let sessions: Vec<Session> = vec![];
-    ///     let sessions_manager = SessionsManager::new(Some(sessions), Some(3600), Some(600));
+    ///     let sessions_manager = SessionsManager::new(Some(sessions), Some(3600), Some(600), None);
     ///
     ///     let interval = sessions_manager.cleanup_interval();
     /// }
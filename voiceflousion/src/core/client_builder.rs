@@ -1,5 +1,8 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use crate::core::session_wrappers::Session;
+use crate::core::subtypes::{DeliveryPolicy, InputKind, InputPolicy, QueueOverflowPolicy};
+use crate::core::traits::{CustomActionHandler, DeduplicationStore, HandoffHandler, Middleware, SessionStore};
 use crate::core::voiceflow::{State, VoiceflowClient};
 
 
@@ -31,6 +34,26 @@ pub struct ClientBuilder {
     status: bool,
     /// The optional bot authentication token, used for additional security or identification purposes.
     bot_auth_token: Option<String>,
+    /// The optional session store, used for persisting sessions between restarts of the application.
+    session_store: Option<Arc<dyn SessionStore>>,
+    /// A flag indicating whether Voiceflow responses are forwarded to the client block by block as they are received.
+    streaming: bool,
+    /// The handlers of Voiceflow custom actions mapped by the names of the actions.
+    custom_action_handlers: HashMap<String, Arc<dyn CustomActionHandler>>,
+    /// The policies of handling the user's inputs beyond text and buttons mapped by the kinds of the inputs.
+    input_policies: HashMap<InputKind, InputPolicy>,
+    /// The middlewares intercepting the interaction with Voiceflow in the order they are called.
+    middlewares: Vec<Arc<dyn Middleware>>,
+    /// The optional handler of the chats handed off to human agents.
+    handoff_handler: Option<Arc<dyn HandoffHandler>>,
+    /// The optional name of the Voiceflow custom action that hands the chat off to a human agent.
+    handoff_action: Option<String>,
+    /// The optional store of the processed updates, used for skipping redelivered updates.
+    deduplication_store: Option<Arc<dyn DeduplicationStore>>,
+    /// The optional depth of the chat queue and the policy of its overflow, used for processing the updates of the chat in order.
+    chat_queue: Option<(usize, QueueOverflowPolicy)>,
+    /// The optional policy of delivering the messages, replacing the default delivery policy of the integration.
+    delivery_policy: Option<DeliveryPolicy>,
 }
 
 impl ClientBuilder {
@@ -69,7 +92,17 @@ impl ClientBuilder {
             sessions_cleanup_interval: None,
             launch_state: State::default(),
             status: true,
-            bot_auth_token: None
+            bot_auth_token: None,
+            session_store: None,
+            streaming: false,
+            custom_action_handlers: HashMap::new(),
+            input_policies: HashMap::new(),
+            middlewares: Vec::new(),
+            handoff_handler: None,
+            handoff_action: None,
+            deduplication_store: None,
+            chat_queue: None,
+            delivery_policy: None
         }
     }
 
@@ -263,6 +296,383 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the session store for the client builder.
+    ///
+    /// Sessions of the client are saved into the store after every interaction
+    /// and restored from it when they are missing in memory.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_store` - The store for persisting sessions.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::session_stores::InMemorySessionStore;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_session_store(Arc::new(InMemorySessionStore::new()));
+    /// ```
+    pub fn set_session_store(mut self, session_store: Arc<dyn SessionStore>) -> Self {
+        self.session_store = Some(session_store);
+        self
+    }
+
+    /// Sets the streaming mode for the client builder.
+    ///
+    /// In streaming mode every block of the Voiceflow response is sent to the client
+    /// as soon as it is received, instead of waiting for the whole response.
+    ///
+    /// # Parameters
+    ///
+    /// * `streaming` - The flag indicating whether streaming mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_streaming(true);
+    /// ```
+    pub fn set_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Adds a handler for the Voiceflow custom action with the specified name.
+    ///
+    /// When the Voiceflow dialog stops on the custom action, the handler is called with the payload
+    /// of the action, and the dialog is resumed with the path returned by the handler.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the custom action.
+    /// * `handler` - The handler of the custom action.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::sync::Arc;
+    /// use serde_json::Value;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::session_wrappers::LockedSession;
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::errors::VoiceflousionResult;
+    ///
+    /// fn check_payment<'a>(_payload: Value, _locked_session: &'a LockedSession<'a>) -> Pin<Box<dyn Future<Output = VoiceflousionResult<CustomActionResponse>> + Send + 'a>> {
+    ///     Box::pin(async move {
+    ///         Ok(CustomActionResponse::new("success".to_string()))
+    ///     })
+    /// }
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.add_custom_action_handler("check_payment".to_string(), check_payment);
+    /// ```
+    pub fn add_custom_action_handler(mut self, name: String, handler: impl CustomActionHandler + 'static) -> Self {
+        self.custom_action_handlers.insert(name, Arc::new(handler));
+        self
+    }
+
+    /// Sets the handlers of Voiceflow custom actions.
+    ///
+    /// # Parameters
+    ///
+    /// * `custom_action_handlers` - The handlers mapped by the names of the actions.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    pub(crate) fn set_custom_action_handlers(mut self, custom_action_handlers: HashMap<String, Arc<dyn CustomActionHandler>>) -> Self {
+        self.custom_action_handlers = custom_action_handlers;
+        self
+    }
+
+    /// Sets the policy of handling the specified kind of the user's input.
+    ///
+    /// By default reactions are ignored and all other kinds of input are forwarded to Voiceflow.
+    ///
+    /// # Parameters
+    ///
+    /// * `kind` - The kind of the input.
+    /// * `policy` - The policy of handling the input.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::subtypes::{InputKind, InputPolicy};
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_input_policy(InputKind::Sticker, InputPolicy::Reply("Stickers are not supported".to_string()));
+    /// ```
+    pub fn set_input_policy(mut self, kind: InputKind, policy: InputPolicy) -> Self {
+        self.input_policies.insert(kind, policy);
+        self
+    }
+
+    /// Sets the policies of handling the user's inputs.
+    ///
+    /// # Parameters
+    ///
+    /// * `input_policies` - The policies mapped by the kinds of the inputs.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    pub(crate) fn set_input_policies(mut self, input_policies: HashMap<InputKind, InputPolicy>) -> Self {
+        self.input_policies = input_policies;
+        self
+    }
+
+    /// Adds a middleware to the end of the middleware chain.
+    ///
+    /// The middlewares are called in the order they were added for every update,
+    /// request to Voiceflow, response of Voiceflow and result of the sender.
+    ///
+    /// # Parameters
+    ///
+    /// * `middleware` - The middleware to add.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use async_trait::async_trait;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::traits::Middleware;
+    /// use voiceflousion::core::voiceflow::{VoiceflowClient, VoiceflowRequest};
+    /// use voiceflousion::errors::VoiceflousionResult;
+    /// use serde_json::json;
+    ///
+    /// struct UserVariables;
+    ///
+    /// #[async_trait]
+    /// impl Middleware for UserVariables {
+    ///     async fn on_voiceflow_request(&self, request: &mut VoiceflowRequest) -> VoiceflousionResult<()> {
+    ///         request.add_variables(json!({"user_id": request.chat_id()}));
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.add_middleware(UserVariables);
+    /// ```
+    pub fn add_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the middleware chain.
+    ///
+    /// # Parameters
+    ///
+    /// * `middlewares` - The middlewares in the order they are called.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    pub(crate) fn set_middlewares(mut self, middlewares: Vec<Arc<dyn Middleware>>) -> Self {
+        self.middlewares = middlewares;
+        self
+    }
+
+    /// Sets the handler of the chats handed off to human agents.
+    ///
+    /// # Parameters
+    ///
+    /// * `handoff_handler` - The handler receiving the updates of the users while their chats are in handoff.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use async_trait::async_trait;
+    /// use voiceflousion::core::base_structs::UpdateBase;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::traits::HandoffHandler;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::errors::VoiceflousionResult;
+    ///
+    /// struct OperatorConsole;
+    ///
+    /// #[async_trait]
+    /// impl HandoffHandler for OperatorConsole {
+    ///     async fn on_user_update(&self, _chat_id: &str, _update: &UpdateBase) -> VoiceflousionResult<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_handoff_handler(Arc::new(OperatorConsole));
+    /// ```
+    pub fn set_handoff_handler(mut self, handoff_handler: Arc<dyn HandoffHandler>) -> Self {
+        self.handoff_handler = Some(handoff_handler);
+        self
+    }
+
+    /// Sets the store of the processed updates.
+    ///
+    /// The updates already marked in the store are skipped before interacting with Voiceflow,
+    /// so the updates redelivered by the client platform don't produce duplicate replies.
+    ///
+    /// # Parameters
+    ///
+    /// * `deduplication_store` - The store of the processed updates.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::deduplication_stores::InMemoryDeduplicationStore;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_deduplication_store(Arc::new(InMemoryDeduplicationStore::new(3600)));
+    /// ```
+    pub fn set_deduplication_store(mut self, deduplication_store: Arc<dyn DeduplicationStore>) -> Self {
+        self.deduplication_store = Some(deduplication_store);
+        self
+    }
+
+    /// Enables the queued mode of the chats.
+    ///
+    /// In the queued mode the updates arriving while the previous turn of the chat is running wait for it
+    /// and are processed in the order they were received, instead of failing with `SessionLockError`.
+    ///
+    /// # Parameters
+    ///
+    /// * `depth` - The maximum number of the updates waiting in the queue of the chat.
+    /// * `overflow_policy` - The policy of handling the update arriving to the full queue.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::subtypes::QueueOverflowPolicy;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_chat_queue(3, QueueOverflowPolicy::MergeTexts);
+    /// ```
+    pub fn set_chat_queue(mut self, depth: usize, overflow_policy: QueueOverflowPolicy) -> Self {
+        self.chat_queue = Some((depth, overflow_policy));
+        self
+    }
+
+    /// Sets the policy of delivering the messages to the client platform.
+    ///
+    /// The policy replaces the default delivery policy of the integration, i.e. the rate limits
+    /// of the bot and of every chat and the retry policy of the throttled messages.
+    /// It is not used by the web integration, which doesn't send the messages to a platform.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The policy of delivering the messages.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(20, 1000)), Some(RateLimit::new(1, 1000)), RetryPolicy::new(5, 500, 60000));
+    /// let builder = builder.set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.delivery_policy = Some(delivery_policy);
+        self
+    }
+
+    /// Sets the name of the Voiceflow custom action that hands the chat off to a human agent.
+    ///
+    /// When the Voiceflow dialog stops on the custom action, the chat is handed off
+    /// and the dialog waits for a path until the handoff is finished.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the custom action, e.g. `talk_to_agent`.
+    ///
+    /// # Returns
+    ///
+    /// The updated `ClientBuilder` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let mut builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let builder = builder.set_handoff_action("talk_to_agent".to_string());
+    /// ```
+    pub fn set_handoff_action(mut self, name: String) -> Self {
+        self.handoff_action = Some(name);
+        self
+    }
+
     /// Returns the client ID.
     ///
     /// # Returns
@@ -495,4 +905,218 @@ impl ClientBuilder {
     pub fn bot_auth_token(&self) -> &Option<String> {
         &self.bot_auth_token
     }
-}
\ No newline at end of file
+
+    /// Returns the session store.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional session store.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let session_store = builder.session_store();
+    /// ```
+    pub fn session_store(&self) -> &Option<Arc<dyn SessionStore>> {
+        &self.session_store
+    }
+
+    /// Returns whether the streaming mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether Voiceflow responses are streamed to the client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let streaming = builder.streaming();
+    /// ```
+    pub fn streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Returns the handlers of Voiceflow custom actions.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the handlers mapped by the names of the actions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let handlers = builder.custom_action_handlers();
+    /// ```
+    pub fn custom_action_handlers(&self) -> &HashMap<String, Arc<dyn CustomActionHandler>> {
+        &self.custom_action_handlers
+    }
+
+    /// Returns the policies of handling the user's inputs.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the policies mapped by the kinds of the inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let policies = builder.input_policies();
+    /// ```
+    pub fn input_policies(&self) -> &HashMap<InputKind, InputPolicy> {
+        &self.input_policies
+    }
+
+    /// Returns the middleware chain.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the middlewares in the order they are called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let middlewares = builder.middlewares();
+    /// ```
+    pub fn middlewares(&self) -> &Vec<Arc<dyn Middleware>> {
+        &self.middlewares
+    }
+
+    /// Returns the handler of the chats handed off to human agents.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional handoff handler.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let handoff_handler = builder.handoff_handler();
+    /// ```
+    pub fn handoff_handler(&self) -> &Option<Arc<dyn HandoffHandler>> {
+        &self.handoff_handler
+    }
+
+    /// Returns the name of the Voiceflow custom action that hands the chat off to a human agent.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional name of the custom action.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let handoff_action = builder.handoff_action();
+    /// ```
+    pub fn handoff_action(&self) -> &Option<String> {
+        &self.handoff_action
+    }
+
+    /// Returns the store of the processed updates.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional deduplication store.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let deduplication_store = builder.deduplication_store();
+    /// ```
+    pub fn deduplication_store(&self) -> &Option<Arc<dyn DeduplicationStore>> {
+        &self.deduplication_store
+    }
+
+    /// Returns the depth of the chat queue and the policy of its overflow.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional depth and overflow policy, `None` if the queued mode is disabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::subtypes::QueueOverflowPolicy;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10)
+    ///     .set_chat_queue(3, QueueOverflowPolicy::DropOldest);
+    /// assert_eq!(builder.chat_queue(), &Some((3, QueueOverflowPolicy::DropOldest)));
+    /// ```
+    pub fn chat_queue(&self) -> &Option<(usize, QueueOverflowPolicy)> {
+        &self.chat_queue
+    }
+
+    /// Returns the policy of delivering the messages to the client platform.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `DeliveryPolicy`, `None` if the default delivery policy of the integration is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::subtypes::DeliveryPolicy;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10)
+    ///     .set_delivery_policy(DeliveryPolicy::default());
+    /// assert_eq!(builder.delivery_policy(), &Some(DeliveryPolicy::default()));
+    /// ```
+    pub fn delivery_policy(&self) -> &Option<DeliveryPolicy> {
+        &self.delivery_policy
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, VecDeque};
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::Mutex;
+use crate::core::traits::DeduplicationStore;
+use crate::errors::VoiceflousionResult;
+
+/// The client ID and the update ID identifying an update.
+type UpdateKey = (String, String);
+
+/// The processed updates with the time they were marked.
+#[derive(Default)]
+struct ProcessedUpdates {
+    /// The map of the processed updates to the timestamps they were marked.
+    updates: HashMap<UpdateKey, i64>,
+    /// The processed updates in the order they were marked.
+    order: VecDeque<(i64, UpdateKey)>,
+}
+
+/// A deduplication store that keeps the processed updates in memory for a limited time.
+///
+/// `InMemoryDeduplicationStore` forgets the updates after the time to live,
+/// which should exceed the period the client platform redelivers the updates.
+pub struct InMemoryDeduplicationStore {
+    /// The time to live of the processed updates in seconds.
+    ttl: i64,
+    /// The processed updates.
+    processed_updates: Mutex<ProcessedUpdates>,
+}
+
+impl InMemoryDeduplicationStore {
+    /// Creates a new empty `InMemoryDeduplicationStore`.
+    ///
+    /// # Parameters
+    ///
+    /// * `ttl` - The time to live of the processed updates in seconds.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InMemoryDeduplicationStore`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::deduplication_stores::InMemoryDeduplicationStore;
+    ///
+    /// let store = InMemoryDeduplicationStore::new(3600);
+    /// ```
+    pub fn new(ttl: i64) -> Self {
+        Self {
+            ttl,
+            processed_updates: Mutex::new(ProcessedUpdates::default()),
+        }
+    }
+
+    /// Returns the time to live of the processed updates.
+    ///
+    /// # Returns
+    ///
+    /// The time to live in seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::deduplication_stores::InMemoryDeduplicationStore;
+    ///
+    /// let store = InMemoryDeduplicationStore::new(3600);
+    /// assert_eq!(store.ttl(), 3600);
+    /// ```
+    pub fn ttl(&self) -> i64 {
+        self.ttl
+    }
+}
+
+impl Default for InMemoryDeduplicationStore {
+    fn default() -> Self {
+        Self::new(3600)
+    }
+}
+
+#[async_trait]
+impl DeduplicationStore for InMemoryDeduplicationStore {
+    async fn mark_processed(&self, client_id: &str, update_id: &str) -> VoiceflousionResult<bool> {
+        let now = Utc::now().timestamp();
+        let mut lock = self.processed_updates.lock().await;
+        let ProcessedUpdates { updates, order } = &mut *lock;
+
+        // Forget the updates marked before the time to live
+        while let Some((marked_at, _)) = order.front() {
+            if now - marked_at < self.ttl {
+                break;
+            }
+            if let Some((_, key)) = order.pop_front() {
+                updates.remove(&key);
+            }
+        }
+
+        let key = (client_id.to_string(), update_id.to_string());
+        if updates.contains_key(&key) {
+            return Ok(false);
+        }
+        updates.insert(key.clone(), now);
+        order.push_back((now, key));
+        Ok(true)
+    }
+}
@@ -0,0 +1,3 @@
+mod in_memory_deduplication_store;
+
+pub use self::in_memory_deduplication_store::InMemoryDeduplicationStore;
\ No newline at end of file
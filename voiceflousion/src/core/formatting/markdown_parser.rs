@@ -0,0 +1,205 @@
+use crate::core::formatting::{RichTextSpan, TextStyle};
+
+/// Parses the common Markdown syntax of the texts generated by the Voiceflow AI steps.
+///
+/// The parser supports the bold, italic, strikethrough and code spans, the inline links,
+/// the bulleted lists and the headings, which are rendered bold. The delimiters which
+/// do not surround the text closely, such as `2 * 3`, are kept as they are.
+pub(super) struct MarkdownParser;
+
+impl MarkdownParser {
+    /// Parses the Markdown text into the text fragments.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The Markdown text.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the text fragments.
+    pub fn parse(text: &str) -> Vec<RichTextSpan> {
+        let mut spans = Vec::new();
+
+        for (index, line) in text.split('\n').enumerate() {
+            if index > 0 {
+                spans.push(RichTextSpan::new("\n".to_string(), TextStyle::default(), None));
+            }
+            let (line, style) = Self::parse_line_prefix(line, &mut spans);
+            Self::parse_inline(line, style, &None, &mut spans);
+        }
+
+        spans
+    }
+
+    /// Parses the list marker or the heading marker at the start of the line.
+    ///
+    /// # Parameters
+    ///
+    /// * `line` - The line of the text.
+    /// * `spans` - The text fragments to append the list bullet to.
+    ///
+    /// # Returns
+    ///
+    /// The rest of the line and its style.
+    fn parse_line_prefix<'a>(line: &'a str, spans: &mut Vec<RichTextSpan>) -> (&'a str, TextStyle) {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        let item = trimmed.strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .or_else(|| trimmed.strip_prefix("+ "));
+        if let Some(item) = item {
+            spans.push(RichTextSpan::new(format!("{}• ", indent), TextStyle::default(), None));
+            return (item, TextStyle::default());
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&level) {
+            if let Some(heading) = trimmed[level..].strip_prefix(' ') {
+                return (heading, TextStyle::default().with_bold());
+            }
+        }
+
+        (line, TextStyle::default())
+    }
+
+    /// Parses the inline formatting of the text.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to parse.
+    /// * `style` - The style of the surrounding text.
+    /// * `link` - The optional URL of the surrounding link.
+    /// * `spans` - The text fragments to append to.
+    fn parse_inline(text: &str, style: TextStyle, link: &Option<String>, spans: &mut Vec<RichTextSpan>) {
+        let mut plain = String::new();
+        let mut rest = text;
+        let mut previous: Option<char> = None;
+
+        while let Some(c) = rest.chars().next() {
+            // The escaped punctuation is kept literally
+            if c == '\\' {
+                if let Some(escaped) = rest[1..].chars().next().filter(|escaped| escaped.is_ascii_punctuation()) {
+                    plain.push(escaped);
+                    previous = Some(escaped);
+                    rest = &rest[1 + escaped.len_utf8()..];
+                    continue;
+                }
+            }
+
+            if let Some((inner, inner_style, inner_link, consumed)) = Self::parse_token(rest, previous, style) {
+                Self::push_plain(&mut plain, style, link, spans);
+                let inner_link = inner_link.or_else(|| link.clone());
+                if inner_style.is_code() {
+                    spans.push(RichTextSpan::new(inner.to_string(), inner_style, inner_link));
+                } else {
+                    Self::parse_inline(inner, inner_style, &inner_link, spans);
+                }
+                previous = rest[..consumed].chars().next_back();
+                rest = &rest[consumed..];
+                continue;
+            }
+
+            plain.push(c);
+            previous = Some(c);
+            rest = &rest[c.len_utf8()..];
+        }
+
+        Self::push_plain(&mut plain, style, link, spans);
+    }
+
+    /// Parses the formatted token at the start of the text.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text starting with the token.
+    /// * `previous` - The optional character before the token.
+    /// * `style` - The style of the surrounding text.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the inner text, its style, its optional link and the length of the token in bytes,
+    /// or `None` if the text does not start with a token.
+    fn parse_token<'a>(text: &'a str, previous: Option<char>, style: TextStyle) -> Option<(&'a str, TextStyle, Option<String>, usize)> {
+        if let Some(after) = text.strip_prefix('`') {
+            let end = after.find('`').filter(|end| *end > 0)?;
+            return Some((&after[..end], style.with_code(), None, end + 2));
+        }
+
+        if let Some(after) = text.strip_prefix('[') {
+            let text_end = after.find("](")?;
+            let url_start = text_end + 2;
+            let url_end = url_start + after[url_start..].find(')')?;
+            let url = &after[url_start..url_end];
+            if text_end == 0 || url.is_empty() || url.contains(char::is_whitespace) {
+                return None;
+            }
+            return Some((&after[..text_end], style, Some(url.to_string()), url_end + 2));
+        }
+
+        let delimiters: [(&str, fn(TextStyle) -> TextStyle); 5] = [
+            ("**", TextStyle::with_bold),
+            ("__", TextStyle::with_bold),
+            ("~~", TextStyle::with_strikethrough),
+            ("*", TextStyle::with_italic),
+            ("_", TextStyle::with_italic),
+        ];
+        delimiters.iter().find_map(|(delimiter, apply)| {
+            Self::match_delimited(text, previous, delimiter)
+                .map(|(inner, consumed)| (inner, apply(style), None, consumed))
+        })
+    }
+
+    /// Matches the text surrounded by the delimiter at the start of the text.
+    ///
+    /// The opening delimiter must be followed and the closing one must be preceded by a non-whitespace
+    /// character. The underscores inside the words, such as `snake_case`, are not delimiters.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text starting with the delimiter.
+    /// * `previous` - The optional character before the delimiter.
+    /// * `delimiter` - The delimiter.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the inner text and the length of the delimited text in bytes.
+    fn match_delimited<'a>(text: &'a str, previous: Option<char>, delimiter: &str) -> Option<(&'a str, usize)> {
+        let after = text.strip_prefix(delimiter)?;
+        let is_single = delimiter.len() == 1;
+        let is_underscore = delimiter.starts_with('_');
+
+        if after.is_empty() || after.starts_with(char::is_whitespace) || (is_single && after.starts_with(delimiter)) {
+            return None;
+        }
+        if is_underscore && previous.map_or(false, |c| c.is_alphanumeric()) {
+            return None;
+        }
+
+        after.match_indices(delimiter)
+            .map(|(index, _)| index)
+            .filter(|index| *index > 0)
+            .find(|index| {
+                let inner = &after[..*index];
+                let next = after[*index + delimiter.len()..].chars().next();
+                !inner.ends_with(char::is_whitespace)
+                    && !(is_single && (inner.ends_with(delimiter) || after[*index + 1..].starts_with(delimiter)))
+                    && !(is_underscore && next.map_or(false, |c| c.is_alphanumeric()))
+            })
+            .map(|index| (&after[..index], index + delimiter.len() * 2))
+    }
+
+    /// Appends the accumulated unformatted text to the fragments.
+    ///
+    /// # Parameters
+    ///
+    /// * `plain` - The accumulated text, cleared after appending.
+    /// * `style` - The style of the text.
+    /// * `link` - The optional URL of the text.
+    /// * `spans` - The text fragments to append to.
+    fn push_plain(plain: &mut String, style: TextStyle, link: &Option<String>, spans: &mut Vec<RichTextSpan>) {
+        if !plain.is_empty() {
+            spans.push(RichTextSpan::new(std::mem::take(plain), style, link.clone()));
+        }
+    }
+}
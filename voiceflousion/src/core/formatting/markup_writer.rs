@@ -0,0 +1,187 @@
+use crate::core::formatting::RichTextSpan;
+
+/// Represents a formatting mark of the text, the marks are nested in the order of the variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Mark {
+    /// The link to the URL.
+    Link(String),
+    /// The bold text.
+    Bold,
+    /// The underlined text.
+    Underline,
+    /// The italic text.
+    Italic,
+    /// The struck through text.
+    Strikethrough,
+    /// The monospaced code.
+    Code,
+}
+
+/// Defines the delimiters and the escaping of a markup syntax.
+pub(super) trait MarkupSyntax {
+    /// Checks whether the marks of the syntax may span several lines.
+    fn spans_lines(&self) -> bool;
+
+    /// Writes the opening delimiter of the mark.
+    ///
+    /// # Parameters
+    ///
+    /// * `mark` - The mark to open.
+    /// * `output` - The rendered text to append to.
+    fn open(&self, mark: &Mark, output: &mut String);
+
+    /// Writes the closing delimiter of the mark.
+    ///
+    /// # Parameters
+    ///
+    /// * `mark` - The mark to close.
+    /// * `opened_at` - The index of the output the mark was opened at.
+    /// * `output` - The rendered text to append to.
+    fn close(&self, mark: &Mark, opened_at: usize, output: &mut String);
+
+    /// Escapes the characters reserved by the syntax.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to escape.
+    /// * `is_code` - Whether the text is inside the code mark.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text.
+    fn escape(&self, text: &str, is_code: bool) -> String;
+}
+
+/// Renders the rich text with a markup syntax, keeping the marks properly nested.
+///
+/// The marks shared by the adjacent fragments stay open, so the nested formatting is written
+/// as `*bold _italic_ bold*` rather than as separate delimited fragments. The delimiters are
+/// put around the text without its leading and trailing whitespaces.
+pub(super) struct MarkupWriter;
+
+impl MarkupWriter {
+    /// Renders the text fragments with the markup syntax.
+    ///
+    /// # Parameters
+    ///
+    /// * `spans` - The text fragments.
+    /// * `syntax` - The markup syntax.
+    ///
+    /// # Returns
+    ///
+    /// The text with the markup.
+    pub fn write(spans: &[RichTextSpan], syntax: &impl MarkupSyntax) -> String {
+        let mut output = String::new();
+        let mut stack: Vec<(Mark, usize)> = Vec::new();
+
+        for span in spans {
+            let marks = Self::marks(span);
+
+            if syntax.spans_lines() {
+                Self::write_text(span.text(), &marks, syntax, &mut stack, &mut output);
+                continue;
+            }
+
+            for (index, line) in span.text().split('\n').enumerate() {
+                if index > 0 {
+                    Self::close_marks(&mut stack, 0, syntax, &mut output);
+                    output.push('\n');
+                }
+                Self::write_text(line, &marks, syntax, &mut stack, &mut output);
+            }
+        }
+
+        Self::close_marks(&mut stack, 0, syntax, &mut output);
+        output
+    }
+
+    /// Writes the text with the marks, closing and opening the marks which differ from the open ones.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to write.
+    /// * `marks` - The marks of the text.
+    /// * `syntax` - The markup syntax.
+    /// * `stack` - The open marks and the indexes of the output they were opened at.
+    /// * `output` - The rendered text to append to.
+    fn write_text(text: &str, marks: &[Mark], syntax: &impl MarkupSyntax, stack: &mut Vec<(Mark, usize)>, output: &mut String) {
+        // The whitespaces are written as they are to avoid the empty delimited fragments
+        if text.trim().is_empty() {
+            output.push_str(text);
+            return;
+        }
+
+        let common = stack.iter()
+            .zip(marks)
+            .take_while(|((open_mark, _), mark)| open_mark == *mark)
+            .count();
+        Self::close_marks(stack, common, syntax, output);
+
+        let trimmed = text.trim_start();
+        output.push_str(&text[..text.len() - trimmed.len()]);
+
+        for mark in &marks[common..] {
+            let opened_at = output.len();
+            syntax.open(mark, output);
+            stack.push((mark.clone(), opened_at));
+        }
+
+        output.push_str(&syntax.escape(trimmed, marks.contains(&Mark::Code)));
+    }
+
+    /// Closes the open marks above the kept ones, putting the delimiters before the trailing whitespaces.
+    ///
+    /// # Parameters
+    ///
+    /// * `stack` - The open marks and the indexes of the output they were opened at.
+    /// * `keep` - The number of the marks to keep open.
+    /// * `syntax` - The markup syntax.
+    /// * `output` - The rendered text to append to.
+    fn close_marks(stack: &mut Vec<(Mark, usize)>, keep: usize, syntax: &impl MarkupSyntax, output: &mut String) {
+        if stack.len() <= keep {
+            return;
+        }
+
+        let whitespace = output.split_off(output.trim_end().len());
+        while stack.len() > keep {
+            if let Some((mark, opened_at)) = stack.pop() {
+                syntax.close(&mark, opened_at, output);
+            }
+        }
+        output.push_str(&whitespace);
+    }
+
+    /// Returns the marks of the text fragment in the order of their nesting.
+    ///
+    /// # Parameters
+    ///
+    /// * `span` - The text fragment.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the marks.
+    fn marks(span: &RichTextSpan) -> Vec<Mark> {
+        let style = span.style();
+        let mut marks = Vec::new();
+
+        if let Some(url) = span.link() {
+            marks.push(Mark::Link(url.clone()));
+        }
+        if style.is_bold() {
+            marks.push(Mark::Bold);
+        }
+        if style.is_underline() {
+            marks.push(Mark::Underline);
+        }
+        if style.is_italic() {
+            marks.push(Mark::Italic);
+        }
+        if style.is_strikethrough() {
+            marks.push(Mark::Strikethrough);
+        }
+        if style.is_code() {
+            marks.push(Mark::Code);
+        }
+        marks
+    }
+}
@@ -0,0 +1,12 @@
+mod text_style;
+mod rich_text_span;
+mod rich_text;
+mod slate_parser;
+mod markdown_parser;
+mod markup_writer;
+mod telegram_markup;
+mod whatsapp_markup;
+
+pub use self::text_style::TextStyle;
+pub use self::rich_text_span::RichTextSpan;
+pub use self::rich_text::RichText;
\ No newline at end of file
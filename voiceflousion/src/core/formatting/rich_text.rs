@@ -0,0 +1,255 @@
+use std::ops::Range;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::formatting::markdown_parser::MarkdownParser;
+use crate::core::formatting::markup_writer::MarkupWriter;
+use crate::core::formatting::slate_parser::SlateParser;
+use crate::core::formatting::telegram_markup::{TelegramHtml, TelegramMarkdownV2};
+use crate::core::formatting::whatsapp_markup::WhatsAppMarkup;
+use crate::core::formatting::RichTextSpan;
+
+/// Represents a formatted text as a sequence of the styled fragments.
+///
+/// `RichText` is parsed from the Voiceflow slate documents or from the Markdown
+/// and rendered into the formatting syntaxes of the client platforms.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichText {
+    /// The fragments of the text.
+    spans: Vec<RichTextSpan>,
+}
+
+impl RichText {
+    /// Creates a new `RichText` from the fragments.
+    ///
+    /// The empty fragments are removed and the adjacent fragments of the same style and link are merged.
+    ///
+    /// # Parameters
+    ///
+    /// * `spans` - The fragments of the text.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RichText`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::{RichText, RichTextSpan, TextStyle};
+    ///
+    /// let rich_text = RichText::new(vec![
+    ///     RichTextSpan::new("Hello, ".to_string(), TextStyle::default(), None),
+    ///     RichTextSpan::new("World".to_string(), TextStyle::new(true, false, false, false, false), None),
+    /// ]);
+    /// assert_eq!(rich_text.plain_text(), "Hello, World");
+    /// ```
+    pub fn new(spans: Vec<RichTextSpan>) -> Self {
+        let mut merged: Vec<RichTextSpan> = Vec::with_capacity(spans.len());
+
+        for span in spans.into_iter().filter(|span| !span.text().is_empty()) {
+            match merged.last_mut() {
+                Some(last) if last.style() == span.style() && last.link() == span.link() => last.push_str(span.text()),
+                _ => merged.push(span)
+            }
+        }
+
+        Self { spans: merged }
+    }
+
+    /// Parses the content of the Voiceflow slate document.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - The array of the slate blocks, found in the `slate.content` field of the text trace.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the `RichText`, or `None` if the content is not an array of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let content = json!([{
+    ///     "children": [
+    ///         { "text": "Hello, " },
+    ///         { "text": "World", "fontWeight": "700" }
+    ///     ]
+    /// }]);
+    /// let rich_text = RichText::from_slate(&content).unwrap();
+    /// assert_eq!(rich_text.to_telegram_html(), "Hello, <b>World</b>");
+    /// ```
+    pub fn from_slate(content: &Value) -> Option<Self> {
+        SlateParser::parse(content).map(Self::new)
+    }
+
+    /// Parses the Markdown text.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The Markdown text.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `RichText`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("Read the **docs** at [Voiceflow](https://voiceflow.com)");
+    /// assert_eq!(rich_text.plain_text(), "Read the docs at Voiceflow");
+    ///
+    /// let plain = RichText::from_markdown("2 * 3 = 6, see snake_case_name");
+    /// assert!(plain.is_plain());
+    /// ```
+    pub fn from_markdown(text: &str) -> Self {
+        Self::new(MarkdownParser::parse(text))
+    }
+
+    /// Returns the fragments of the text.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of `RichTextSpan`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("Hello, *World*");
+    /// assert_eq!(rich_text.spans().len(), 2);
+    /// ```
+    pub fn spans(&self) -> &Vec<RichTextSpan> {
+        &self.spans
+    }
+
+    /// Returns the text without the formatting.
+    ///
+    /// # Returns
+    ///
+    /// A `String` of the concatenated fragments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("Hello, _World_");
+    /// assert_eq!(rich_text.plain_text(), "Hello, World");
+    /// ```
+    pub fn plain_text(&self) -> String {
+        self.spans.iter().map(|span| span.text().as_str()).collect()
+    }
+
+    /// Checks whether the text has no formatting and no links.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all the fragments are plain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// assert!(RichText::from_markdown("Hello, World").is_plain());
+    /// assert!(!RichText::from_markdown("Hello, *World*").is_plain());
+    /// ```
+    pub fn is_plain(&self) -> bool {
+        self.spans.iter().all(|span| span.style().is_plain() && span.link().is_none())
+    }
+
+    /// Returns the part of the text keeping its formatting.
+    ///
+    /// # Parameters
+    ///
+    /// * `range` - The byte range of the part in the plain text, lying on the character boundaries.
+    ///
+    /// # Returns
+    ///
+    /// The `RichText` of the part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("Hello, **World**");
+    /// assert_eq!(rich_text.slice(4..9).to_telegram_html(), "o, <b>Wo</b>");
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        let mut spans = Vec::new();
+        let mut offset = 0;
+
+        for span in &self.spans {
+            let span_end = offset + span.text().len();
+            let start = range.start.max(offset);
+            let end = range.end.min(span_end);
+            if start < end {
+                let text = span.text()[start - offset..end - offset].to_string();
+                spans.push(RichTextSpan::new(text, *span.style(), span.link().clone()));
+            }
+            offset = span_end;
+        }
+
+        Self::new(spans)
+    }
+
+    /// Renders the text into the HTML of the Telegram Bot API.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text for the `HTML` parse mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("**1 < 2** & [docs](https://example.com)");
+    /// assert_eq!(rich_text.to_telegram_html(), "<b>1 &lt; 2</b> &amp; <a href=\"https://example.com\">docs</a>");
+    /// ```
+    pub fn to_telegram_html(&self) -> String {
+        MarkupWriter::write(&self.spans, &TelegramHtml)
+    }
+
+    /// Renders the text into the MarkdownV2 of the Telegram Bot API.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text for the `MarkdownV2` parse mode.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("Total: **5.0** (approx.)");
+    /// assert_eq!(rich_text.to_telegram_markdown_v2(), "Total: *5\\.0* \\(approx\\.\\)");
+    /// ```
+    pub fn to_telegram_markdown_v2(&self) -> String {
+        MarkupWriter::write(&self.spans, &TelegramMarkdownV2)
+    }
+
+    /// Renders the text into the WhatsApp markup.
+    ///
+    /// # Returns
+    ///
+    /// The text with the `*bold*`, `_italic_`, `~strikethrough~` and ```` ```monospace``` ```` markup.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    ///
+    /// let rich_text = RichText::from_markdown("**Hello** _World_, see [docs](https://example.com)");
+    /// assert_eq!(rich_text.to_whatsapp_markup(), "*Hello* _World_, see docs (https://example.com)");
+    /// ```
+    pub fn to_whatsapp_markup(&self) -> String {
+        MarkupWriter::write(&self.spans, &WhatsAppMarkup)
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use crate::core::formatting::TextStyle;
+
+/// Represents a fragment of the rich text sharing the same style and link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RichTextSpan {
+    /// The text of the fragment.
+    text: String,
+    /// The style of the fragment.
+    style: TextStyle,
+    /// The optional URL the fragment links to.
+    link: Option<String>,
+}
+
+impl RichTextSpan {
+    /// Creates a new `RichTextSpan`.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text of the fragment.
+    /// * `style` - The style of the fragment.
+    /// * `link` - The optional URL the fragment links to.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RichTextSpan`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::{RichTextSpan, TextStyle};
+    ///
+    /// let span = RichTextSpan::new("Voiceflow".to_string(), TextStyle::default(), Some("https://voiceflow.com".to_string()));
+    /// ```
+    pub fn new(text: String, style: TextStyle, link: Option<String>) -> Self {
+        Self {
+            text,
+            style,
+            link,
+        }
+    }
+
+    /// Returns the text of the fragment.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::{RichTextSpan, TextStyle};
+    ///
+    /// let span = RichTextSpan::new("Hello".to_string(), TextStyle::default(), None);
+    /// assert_eq!(span.text(), "Hello");
+    /// ```
+    pub fn text(&self) -> &String {
+        &self.text
+    }
+
+    /// Returns the style of the fragment.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `TextStyle`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::{RichTextSpan, TextStyle};
+    ///
+    /// let span = RichTextSpan::new("Hello".to_string(), TextStyle::default(), None);
+    /// assert!(span.style().is_plain());
+    /// ```
+    pub fn style(&self) -> &TextStyle {
+        &self.style
+    }
+
+    /// Returns the URL the fragment links to.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional URL.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::{RichTextSpan, TextStyle};
+    ///
+    /// let span = RichTextSpan::new("Hello".to_string(), TextStyle::default(), None);
+    /// assert_eq!(span.link(), &None);
+    /// ```
+    pub fn link(&self) -> &Option<String> {
+        &self.link
+    }
+
+    /// Appends the text to the fragment.
+    pub(super) fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+}
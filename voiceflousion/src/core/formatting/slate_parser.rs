@@ -0,0 +1,128 @@
+use serde_json::Value;
+use crate::core::formatting::{RichTextSpan, TextStyle};
+
+/// Parses the rich text authored in the Voiceflow text editor and stored in the slate format.
+pub(super) struct SlateParser;
+
+impl SlateParser {
+    /// Parses the content of the slate document into the text fragments.
+    ///
+    /// The paragraphs and the list items are separated by line breaks, the list items
+    /// are prefixed with bullets or numbers.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - The array of the slate blocks.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the text fragments, or `None` if the content is not an array of blocks.
+    pub fn parse(content: &Value) -> Option<Vec<RichTextSpan>> {
+        let blocks = content.as_array()?;
+
+        let mut spans = Vec::new();
+        for (index, block) in blocks.iter().enumerate() {
+            if index > 0 {
+                spans.push(Self::plain_span("\n"));
+            }
+            Self::parse_node(block, TextStyle::default(), &None, &mut spans);
+        }
+
+        Some(spans)
+    }
+
+    /// Parses a slate node and its children into the text fragments.
+    ///
+    /// # Parameters
+    ///
+    /// * `node` - The slate node, either an element with children or a text leaf.
+    /// * `style` - The style inherited from the parent nodes.
+    /// * `link` - The optional URL inherited from the parent link element.
+    /// * `spans` - The text fragments to append to.
+    fn parse_node(node: &Value, style: TextStyle, link: &Option<String>, spans: &mut Vec<RichTextSpan>) {
+        if let Some(text) = node.get("text").and_then(|text| text.as_str()) {
+            spans.push(RichTextSpan::new(text.to_string(), Self::leaf_style(node, style), link.clone()));
+            return;
+        }
+
+        let children = match node.get("children").and_then(|children| children.as_array()) {
+            Some(children) => children,
+            None => return
+        };
+
+        match node.get("type").and_then(|node_type| node_type.as_str()) {
+            Some("link") => {
+                let link = node.get("url")
+                    .and_then(|url| url.as_str())
+                    .map(|url| url.to_string())
+                    .or_else(|| link.clone());
+                for child in children {
+                    Self::parse_node(child, style, &link, spans);
+                }
+            }
+            Some(list_type @ ("bulleted-list" | "numbered-list")) => {
+                for (index, item) in children.iter().enumerate() {
+                    if index > 0 {
+                        spans.push(Self::plain_span("\n"));
+                    }
+                    let marker = if list_type == "numbered-list" {
+                        format!("{}. ", index + 1)
+                    } else {
+                        "• ".to_string()
+                    };
+                    spans.push(Self::plain_span(&marker));
+                    Self::parse_node(item, style, link, spans);
+                }
+            }
+            _ => {
+                for child in children {
+                    Self::parse_node(child, style, link, spans);
+                }
+            }
+        }
+    }
+
+    /// Applies the marks of the slate text leaf to the inherited style.
+    ///
+    /// # Parameters
+    ///
+    /// * `leaf` - The slate text leaf.
+    /// * `style` - The style inherited from the parent nodes.
+    ///
+    /// # Returns
+    ///
+    /// The `TextStyle` of the leaf.
+    fn leaf_style(leaf: &Value, style: TextStyle) -> TextStyle {
+        let is_marked = |mark: &str| leaf.get(mark).and_then(|mark| mark.as_bool()).unwrap_or(false);
+
+        // Voiceflow stores the bold text as the font weight
+        let is_bold = is_marked("bold") || match leaf.get("fontWeight") {
+            Some(Value::String(weight)) => weight == "bold" || weight.parse::<u32>().map_or(false, |weight| weight >= 600),
+            Some(Value::Number(weight)) => weight.as_u64().map_or(false, |weight| weight >= 600),
+            _ => false
+        };
+
+        let mut style = style;
+        if is_bold {
+            style = style.with_bold();
+        }
+        if is_marked("italic") {
+            style = style.with_italic();
+        }
+        if is_marked("underline") {
+            style = style.with_underline();
+        }
+        if is_marked("strikeThrough") || is_marked("strikethrough") {
+            style = style.with_strikethrough();
+        }
+        if is_marked("code") {
+            style = style.with_code();
+        }
+        style
+    }
+
+    /// Creates an unformatted text fragment.
+    fn plain_span(text: &str) -> RichTextSpan {
+        RichTextSpan::new(text.to_string(), TextStyle::default(), None)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::core::formatting::markup_writer::{Mark, MarkupSyntax};
+
+/// The HTML syntax of the Telegram Bot API.
+pub(super) struct TelegramHtml;
+
+impl MarkupSyntax for TelegramHtml {
+    fn spans_lines(&self) -> bool {
+        true
+    }
+
+    fn open(&self, mark: &Mark, output: &mut String) {
+        match mark {
+            Mark::Link(url) => output.push_str(&format!("<a href=\"{}\">", self.escape(url, false))),
+            Mark::Bold => output.push_str("<b>"),
+            Mark::Underline => output.push_str("<u>"),
+            Mark::Italic => output.push_str("<i>"),
+            Mark::Strikethrough => output.push_str("<s>"),
+            Mark::Code => output.push_str("<code>")
+        }
+    }
+
+    fn close(&self, mark: &Mark, _opened_at: usize, output: &mut String) {
+        match mark {
+            Mark::Link(_) => output.push_str("</a>"),
+            Mark::Bold => output.push_str("</b>"),
+            Mark::Underline => output.push_str("</u>"),
+            Mark::Italic => output.push_str("</i>"),
+            Mark::Strikethrough => output.push_str("</s>"),
+            Mark::Code => output.push_str("</code>")
+        }
+    }
+
+    fn escape(&self, text: &str, _is_code: bool) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+/// The MarkdownV2 syntax of the Telegram Bot API.
+pub(super) struct TelegramMarkdownV2;
+
+impl TelegramMarkdownV2 {
+    /// The characters which must be escaped in the text.
+    const SPECIAL_CHARACTERS: &'static str = "_*[]()~`>#+-=|{}.!\\";
+
+    /// The characters which must be escaped in the code and the link URLs.
+    const CODE_SPECIAL_CHARACTERS: &'static str = "`\\";
+
+    /// Writes the delimiter, separating it from the preceding underscore delimiter.
+    ///
+    /// Telegram reads `___` greedily as the underline delimiter first, so the adjacent
+    /// italic and underline delimiters are separated with the ignored `\r`.
+    ///
+    /// # Parameters
+    ///
+    /// * `delimiter` - The delimiter to write.
+    /// * `output` - The rendered text to append to.
+    fn push_delimiter(delimiter: &str, output: &mut String) {
+        if delimiter.starts_with('_') && output.ends_with('_') && !output.ends_with("\\_") {
+            output.push('\r');
+        }
+        output.push_str(delimiter);
+    }
+
+    /// Escapes the characters with a backslash.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to escape.
+    /// * `special_characters` - The characters to escape.
+    ///
+    /// # Returns
+    ///
+    /// The escaped text.
+    fn escape_characters(text: &str, special_characters: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for c in text.chars() {
+            if special_characters.contains(c) {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+}
+
+impl MarkupSyntax for TelegramMarkdownV2 {
+    fn spans_lines(&self) -> bool {
+        true
+    }
+
+    fn open(&self, mark: &Mark, output: &mut String) {
+        match mark {
+            Mark::Link(_) => output.push('['),
+            Mark::Bold => output.push('*'),
+            Mark::Underline => Self::push_delimiter("__", output),
+            Mark::Italic => Self::push_delimiter("_", output),
+            Mark::Strikethrough => output.push('~'),
+            Mark::Code => output.push('`')
+        }
+    }
+
+    fn close(&self, mark: &Mark, _opened_at: usize, output: &mut String) {
+        match mark {
+            Mark::Link(url) => output.push_str(&format!("]({})", Self::escape_characters(url, ")\\"))),
+            Mark::Bold => output.push('*'),
+            Mark::Underline => Self::push_delimiter("__", output),
+            Mark::Italic => Self::push_delimiter("_", output),
+            Mark::Strikethrough => output.push('~'),
+            Mark::Code => output.push('`')
+        }
+    }
+
+    fn escape(&self, text: &str, is_code: bool) -> String {
+        if is_code {
+            Self::escape_characters(text, Self::CODE_SPECIAL_CHARACTERS)
+        } else {
+            Self::escape_characters(text, Self::SPECIAL_CHARACTERS)
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents the style of a fragment of the rich text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextStyle {
+    /// Whether the text is bold.
+    bold: bool,
+    /// Whether the text is italic.
+    italic: bool,
+    /// Whether the text is underlined.
+    underline: bool,
+    /// Whether the text is struck through.
+    strikethrough: bool,
+    /// Whether the text is monospaced code.
+    code: bool,
+}
+
+impl TextStyle {
+    /// Creates a new `TextStyle`.
+    ///
+    /// # Parameters
+    ///
+    /// * `bold` - Whether the text is bold.
+    /// * `italic` - Whether the text is italic.
+    /// * `underline` - Whether the text is underlined.
+    /// * `strikethrough` - Whether the text is struck through.
+    /// * `code` - Whether the text is monospaced code.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `TextStyle`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let bold = TextStyle::new(true, false, false, false, false);
+    /// ```
+    pub fn new(bold: bool, italic: bool, underline: bool, strikethrough: bool, code: bool) -> Self {
+        Self {
+            bold,
+            italic,
+            underline,
+            strikethrough,
+            code,
+        }
+    }
+
+    /// Returns whether the text is bold.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is bold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let style = TextStyle::new(true, false, false, false, false);
+    /// assert!(style.is_bold());
+    /// ```
+    pub fn is_bold(&self) -> bool {
+        self.bold
+    }
+
+    /// Returns whether the text is italic.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is italic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let style = TextStyle::new(false, true, false, false, false);
+    /// assert!(style.is_italic());
+    /// ```
+    pub fn is_italic(&self) -> bool {
+        self.italic
+    }
+
+    /// Returns whether the text is underlined.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is underlined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let style = TextStyle::new(false, false, true, false, false);
+    /// assert!(style.is_underline());
+    /// ```
+    pub fn is_underline(&self) -> bool {
+        self.underline
+    }
+
+    /// Returns whether the text is struck through.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is struck through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let style = TextStyle::new(false, false, false, true, false);
+    /// assert!(style.is_strikethrough());
+    /// ```
+    pub fn is_strikethrough(&self) -> bool {
+        self.strikethrough
+    }
+
+    /// Returns whether the text is monospaced code.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// let style = TextStyle::new(false, false, false, false, true);
+    /// assert!(style.is_code());
+    /// ```
+    pub fn is_code(&self) -> bool {
+        self.code
+    }
+
+    /// Checks whether the style has no formatting.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the text is neither bold, italic, underlined, struck through nor code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::TextStyle;
+    ///
+    /// assert!(TextStyle::default().is_plain());
+    /// ```
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the style with the bold flag set.
+    pub(super) fn with_bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Returns the style with the italic flag set.
+    pub(super) fn with_italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Returns the style with the underline flag set.
+    pub(super) fn with_underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Returns the style with the strikethrough flag set.
+    pub(super) fn with_strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Returns the style with the code flag set.
+    pub(super) fn with_code(mut self) -> Self {
+        self.code = true;
+        self
+    }
+}
@@ -0,0 +1,43 @@
+use crate::core::formatting::markup_writer::{Mark, MarkupSyntax};
+
+/// The formatting syntax of WhatsApp.
+///
+/// WhatsApp has no underline and no inline links, so the underline is dropped
+/// and the URL of a link is written after its text. The marks never span several lines.
+pub(super) struct WhatsAppMarkup;
+
+impl MarkupSyntax for WhatsAppMarkup {
+    fn spans_lines(&self) -> bool {
+        false
+    }
+
+    fn open(&self, mark: &Mark, output: &mut String) {
+        match mark {
+            Mark::Link(_) | Mark::Underline => {}
+            Mark::Bold => output.push('*'),
+            Mark::Italic => output.push('_'),
+            Mark::Strikethrough => output.push('~'),
+            Mark::Code => output.push_str("```")
+        }
+    }
+
+    fn close(&self, mark: &Mark, opened_at: usize, output: &mut String) {
+        match mark {
+            // The URL is omitted if the link text is the URL itself
+            Mark::Link(url) => {
+                if output[opened_at..].trim() != url {
+                    output.push_str(&format!(" ({})", url));
+                }
+            }
+            Mark::Underline => {}
+            Mark::Bold => output.push('*'),
+            Mark::Italic => output.push('_'),
+            Mark::Strikethrough => output.push('~'),
+            Mark::Code => output.push_str("```")
+        }
+    }
+
+    fn escape(&self, text: &str, _is_code: bool) -> String {
+        text.to_string()
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, OnceLock};
+use crate::core::traits::MetricsRecorder;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Counter of the updates received by the clients, labeled by `client_id`.
+pub const UPDATES_TOTAL: &str = "voiceflousion_updates_total";
+
+/// Counter of the failed updates, labeled by `client_id` and the `error` variant of `VoiceflousionError`.
+pub const ERRORS_TOTAL: &str = "voiceflousion_errors_total";
+
+/// Histogram of the round-trip time of the requests to Voiceflow in seconds, labeled by `project_id`.
+pub const VOICEFLOW_REQUEST_DURATION_SECONDS: &str = "voiceflousion_voiceflow_request_duration_seconds";
+
+/// Histogram of the duration of the sender calls in seconds, labeled by `client_id`.
+pub const SENDER_DURATION_SECONDS: &str = "voiceflousion_sender_duration_seconds";
+
+/// Gauge of the active sessions of the clients, labeled by `client_id`.
+pub const ACTIVE_SESSIONS: &str = "voiceflousion_active_sessions";
+
+/// The recorder installed for the whole application.
+static METRICS_RECORDER: OnceLock<Arc<dyn MetricsRecorder>> = OnceLock::new();
+
+/// Installs the metrics recorder for the whole application.
+///
+/// Until a recorder is installed, the metrics are not recorded. The recorder can be installed only once.
+///
+/// # Parameters
+///
+/// * `recorder` - The recorder receiving the metrics.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if a recorder is already installed.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use voiceflousion::core::metrics::{set_metrics_recorder, PrometheusMetrics};
+///
+/// let metrics = Arc::new(PrometheusMetrics::new());
+/// set_metrics_recorder(metrics.clone()).unwrap();
+/// ```
+pub fn set_metrics_recorder(recorder: Arc<dyn MetricsRecorder>) -> VoiceflousionResult<()> {
+    METRICS_RECORDER.set(recorder)
+        .map_err(|_| VoiceflousionError::ValidationError("MetricsRecorder".to_string(), "Metrics recorder is already installed".to_string()))
+}
+
+/// Returns the installed metrics recorder.
+///
+/// # Returns
+///
+/// An `Option` containing the installed `MetricsRecorder`.
+///
+/// # Example
+///
+/// ```
+/// use voiceflousion::core::metrics::metrics_recorder;
+///
+/// let recorder = metrics_recorder();
+/// ```
+pub fn metrics_recorder() -> Option<&'static Arc<dyn MetricsRecorder>> {
+    METRICS_RECORDER.get()
+}
+
+/// Increments the counter in the installed recorder.
+///
+/// # Parameters
+///
+/// * `name` - The name of the counter.
+/// * `labels` - The labels of the counter.
+pub(crate) async fn increment_counter(name: &'static str, labels: &[(&'static str, String)]) {
+    if let Some(recorder) = metrics_recorder() {
+        recorder.increment_counter(name, labels).await;
+    }
+}
+
+/// Records the value into the histogram of the installed recorder.
+///
+/// # Parameters
+///
+/// * `name` - The name of the histogram.
+/// * `labels` - The labels of the histogram.
+/// * `value` - The observed value.
+pub(crate) async fn record_histogram(name: &'static str, labels: &[(&'static str, String)], value: f64) {
+    if let Some(recorder) = metrics_recorder() {
+        recorder.record_histogram(name, labels, value).await;
+    }
+}
+
+/// Sets the value of the gauge in the installed recorder.
+///
+/// # Parameters
+///
+/// * `name` - The name of the gauge.
+/// * `labels` - The labels of the gauge.
+/// * `value` - The new value of the gauge.
+pub(crate) async fn set_gauge(name: &'static str, labels: &[(&'static str, String)], value: f64) {
+    if let Some(recorder) = metrics_recorder() {
+        recorder.set_gauge(name, labels, value).await;
+    }
+}
@@ -0,0 +1,15 @@
+mod metrics_facade;
+mod prometheus_metrics;
+
+pub use self::metrics_facade::{
+    set_metrics_recorder,
+    metrics_recorder,
+    UPDATES_TOTAL,
+    ERRORS_TOTAL,
+    VOICEFLOW_REQUEST_DURATION_SECONDS,
+    SENDER_DURATION_SECONDS,
+    ACTIVE_SESSIONS
+};
+pub use self::prometheus_metrics::PrometheusMetrics;
+
+pub(crate) use self::metrics_facade::{increment_counter, record_histogram, set_gauge};
\ No newline at end of file
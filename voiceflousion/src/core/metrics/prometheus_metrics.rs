@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::core::traits::MetricsRecorder;
+
+/// The default upper bounds of the histogram buckets in seconds.
+const DEFAULT_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// The name and the labels identifying a series of a metric.
+type SeriesKey = (&'static str, Vec<(&'static str, String)>);
+
+/// Represents the observations of a histogram series.
+struct HistogramSeries {
+    /// The counts of the observations in every bucket.
+    bucket_counts: Vec<u64>,
+    /// The sum of the observed values.
+    sum: f64,
+    /// The count of the observations.
+    count: u64,
+}
+
+/// A metrics recorder that keeps the metrics in memory and renders them in the Prometheus text format.
+///
+/// `PrometheusMetrics` is installed with `set_metrics_recorder()` and exposed by `VoiceflousionServer`
+/// on the `/metrics` endpoint with `VoiceflousionServer::set_metrics()`.
+pub struct PrometheusMetrics {
+    /// The upper bounds of the histogram buckets.
+    buckets: Vec<f64>,
+    /// The values of the counters.
+    counters: RwLock<BTreeMap<SeriesKey, u64>>,
+    /// The observations of the histograms.
+    histograms: RwLock<BTreeMap<SeriesKey, HistogramSeries>>,
+    /// The values of the gauges.
+    gauges: RwLock<BTreeMap<SeriesKey, f64>>,
+}
+
+impl PrometheusMetrics {
+    /// Creates a new `PrometheusMetrics` with the default histogram buckets.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `PrometheusMetrics`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::metrics::PrometheusMetrics;
+    ///
+    /// let metrics = PrometheusMetrics::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::with_buckets(DEFAULT_BUCKETS.to_vec())
+    }
+
+    /// Creates a new `PrometheusMetrics` with custom histogram buckets.
+    ///
+    /// # Parameters
+    ///
+    /// * `buckets` - The upper bounds of the histogram buckets.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `PrometheusMetrics`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::metrics::PrometheusMetrics;
+    ///
+    /// let metrics = PrometheusMetrics::with_buckets(vec![0.1, 0.5, 1.0, 5.0]);
+    /// ```
+    pub fn with_buckets(mut buckets: Vec<f64>) -> Self {
+        buckets.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            buckets,
+            counters: RwLock::new(BTreeMap::new()),
+            histograms: RwLock::new(BTreeMap::new()),
+            gauges: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Renders the recorded metrics in the Prometheus text format.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the metrics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::metrics::PrometheusMetrics;
+    /// use voiceflousion::core::traits::MetricsRecorder;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let metrics = PrometheusMetrics::new();
+    ///     metrics.increment_counter("updates_total", &[("client_id", "bot".to_string())]).await;
+    ///
+    ///     let text = metrics.render().await;
+    ///     assert!(text.contains("updates_total{client_id=\"bot\"} 1"));
+    /// }
+    /// ```
+    pub async fn render(&self) -> String {
+        let mut text = String::new();
+
+        let mut previous_name = "";
+        for ((name, labels), value) in self.counters.read().await.iter() {
+            write_type(&mut text, &mut previous_name, name, "counter");
+            let _ = writeln!(text, "{}{} {}", name, format_labels(labels, None), value);
+        }
+
+        previous_name = "";
+        for ((name, labels), value) in self.gauges.read().await.iter() {
+            write_type(&mut text, &mut previous_name, name, "gauge");
+            let _ = writeln!(text, "{}{} {}", name, format_labels(labels, None), value);
+        }
+
+        previous_name = "";
+        for ((name, labels), series) in self.histograms.read().await.iter() {
+            write_type(&mut text, &mut previous_name, name, "histogram");
+            let mut cumulative_count = 0;
+            for (bound, bucket_count) in self.buckets.iter().zip(series.bucket_counts.iter()) {
+                cumulative_count += bucket_count;
+                let _ = writeln!(text, "{}_bucket{} {}", name, format_labels(labels, Some(&bound.to_string())), cumulative_count);
+            }
+            let _ = writeln!(text, "{}_bucket{} {}", name, format_labels(labels, Some("+Inf")), series.count);
+            let _ = writeln!(text, "{}_sum{} {}", name, format_labels(labels, None), series.sum);
+            let _ = writeln!(text, "{}_count{} {}", name, format_labels(labels, None), series.count);
+        }
+
+        text
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MetricsRecorder for PrometheusMetrics {
+    async fn increment_counter(&self, name: &'static str, labels: &[(&'static str, String)]) {
+        let mut write_lock = self.counters.write().await;
+        *write_lock.entry((name, labels.to_vec())).or_insert(0) += 1;
+    }
+
+    async fn record_histogram(&self, name: &'static str, labels: &[(&'static str, String)], value: f64) {
+        let mut write_lock = self.histograms.write().await;
+        let series = write_lock.entry((name, labels.to_vec())).or_insert_with(|| HistogramSeries {
+            bucket_counts: vec![0; self.buckets.len()],
+            sum: 0.0,
+            count: 0,
+        });
+
+        // Count the value in the first bucket it fits, the buckets are accumulated on rendering
+        if let Some(index) = self.buckets.iter().position(|bound| value <= *bound) {
+            series.bucket_counts[index] += 1;
+        }
+        series.sum += value;
+        series.count += 1;
+    }
+
+    async fn set_gauge(&self, name: &'static str, labels: &[(&'static str, String)], value: f64) {
+        let mut write_lock = self.gauges.write().await;
+        write_lock.insert((name, labels.to_vec()), value);
+    }
+}
+
+/// Writes the `# TYPE` line of the metric if the metric differs from the previous one.
+///
+/// # Parameters
+///
+/// * `text` - The rendered metrics.
+/// * `previous_name` - The name of the previously rendered metric.
+/// * `name` - The name of the metric.
+/// * `metric_type` - The Prometheus type of the metric.
+fn write_type(text: &mut String, previous_name: &mut &'static str, name: &'static str, metric_type: &str) {
+    if *previous_name != name {
+        let _ = writeln!(text, "# TYPE {} {}", name, metric_type);
+        *previous_name = name;
+    }
+}
+
+/// Formats the labels of a series in the Prometheus text format.
+///
+/// # Parameters
+///
+/// * `labels` - The labels of the series.
+/// * `le` - The optional upper bound of the histogram bucket.
+///
+/// # Returns
+///
+/// A `String` with the formatted labels, empty if there are no labels.
+fn format_labels(labels: &[(&'static str, String)], le: Option<&str>) -> String {
+    let mut formatted_labels: Vec<String> = labels.iter()
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+    if let Some(le) = le {
+        formatted_labels.push(format!("le=\"{}\"", le));
+    }
+
+    if formatted_labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", formatted_labels.join(","))
+    }
+}
+
+/// Escapes the backslashes, quotes and line breaks of the label value.
+///
+/// # Parameters
+///
+/// * `value` - The label value.
+///
+/// # Returns
+///
+/// A `String` with the escaped value.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
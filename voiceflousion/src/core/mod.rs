@@ -3,6 +3,10 @@ pub mod session_wrappers;
 pub mod subtypes;
 pub mod voiceflow;
 pub mod base_structs;
+pub mod session_stores;
+pub mod deduplication_stores;
+pub mod metrics;
+pub mod formatting;
 mod client_builder;
 
 pub use self::client_builder::ClientBuilder;
\ No newline at end of file
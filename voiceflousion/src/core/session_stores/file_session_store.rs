@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::core::session_wrappers::StoredSession;
+use crate::core::traits::SessionStore;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// A session store that persists session snapshots into a JSON file.
+///
+/// `FileSessionStore` keeps a copy of the stored sessions in memory and rewrites the whole
+/// file after every change. The file is written into a temporary file first and then renamed,
+/// so a crash during writing never leaves a corrupted store behind.
+pub struct FileSessionStore {
+    /// The path to the JSON file.
+    path: PathBuf,
+    /// The map of stored sessions.
+    sessions: RwLock<HashMap<String, StoredSession>>,
+}
+
+impl FileSessionStore {
+    /// Creates a new `FileSessionStore` and loads the sessions from the file if it exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path to the JSON file with sessions.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `FileSessionStore` or a `VoiceflousionError`
+    /// if the existing file cannot be read or parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::session_stores::FileSessionStore;
+    ///
+    /// let store = FileSessionStore::new("sessions.json").unwrap();
+    /// ```
+    pub fn new(path: impl Into<PathBuf>) -> VoiceflousionResult<Self> {
+        let path = path.into();
+
+        // Load existing sessions if the file is already present
+        let sessions = if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|error| VoiceflousionError::SessionStoreError(format!("FileSessionStore reading {}", path.display()), error.to_string()))?;
+            if content.trim().is_empty() {
+                HashMap::new()
+            } else {
+                serde_json::from_str::<HashMap<String, StoredSession>>(&content)
+                    .map_err(|error| VoiceflousionError::SessionStoreError(format!("FileSessionStore parsing {}", path.display()), error.to_string()))?
+            }
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            sessions: RwLock::new(sessions),
+        })
+    }
+
+    /// Returns the path to the JSON file.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `PathBuf` of the file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::session_stores::FileSessionStore;
+    ///
+    /// let store = FileSessionStore::new("sessions.json").unwrap();
+    /// let path = store.path();
+    /// ```
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Writes the sessions into the file.
+    ///
+    /// # Parameters
+    ///
+    /// * `sessions` - The map of sessions to write.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the file was written.
+    async fn write_file(&self, sessions: &HashMap<String, StoredSession>) -> VoiceflousionResult<()> {
+        let content = serde_json::to_string(sessions)
+            .map_err(|error| VoiceflousionError::SessionStoreError("FileSessionStore serialization".to_string(), error.to_string()))?;
+
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+
+        tokio::fs::write(&temp_path, content).await
+            .map_err(|error| VoiceflousionError::SessionStoreError(format!("FileSessionStore writing {}", self.path.display()), error.to_string()))?;
+        tokio::fs::rename(&temp_path, &self.path).await
+            .map_err(|error| VoiceflousionError::SessionStoreError(format!("FileSessionStore writing {}", self.path.display()), error.to_string()))
+    }
+}
+
+#[async_trait]
+impl SessionStore for FileSessionStore {
+    async fn load_session(&self, chat_id: &str) -> VoiceflousionResult<Option<StoredSession>> {
+        let read_lock = self.sessions.read().await;
+        Ok(read_lock.get(chat_id).cloned())
+    }
+
+    async fn save_session(&self, session: StoredSession) -> VoiceflousionResult<()> {
+        // Write lock is held during writing to keep the file consistent with the map
+        let mut write_lock = self.sessions.write().await;
+        write_lock.insert(session.chat_id().clone(), session);
+        self.write_file(&write_lock).await
+    }
+
+    async fn delete_session(&self, chat_id: &str) -> VoiceflousionResult<()> {
+        let mut write_lock = self.sessions.write().await;
+        if write_lock.remove(chat_id).is_some() {
+            self.write_file(&write_lock).await?;
+        }
+        Ok(())
+    }
+}
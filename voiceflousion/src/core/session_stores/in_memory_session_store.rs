@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+use crate::core::session_wrappers::StoredSession;
+use crate::core::traits::SessionStore;
+use crate::errors::VoiceflousionResult;
+
+/// A session store that keeps session snapshots in memory.
+///
+/// `InMemorySessionStore` does not survive restarts of the application, but keeps sessions
+/// when a client is rebuilt from its `ClientBuilder` and serves as a reference implementation of `SessionStore`.
+#[derive(Default)]
+pub struct InMemorySessionStore {
+    /// The map of stored sessions.
+    sessions: RwLock<HashMap<String, StoredSession>>,
+}
+
+impl InMemorySessionStore {
+    /// Creates a new empty `InMemorySessionStore`.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InMemorySessionStore`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::session_stores::InMemorySessionStore;
+    ///
+    /// let store = InMemorySessionStore::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            sessions: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionStore for InMemorySessionStore {
+    async fn load_session(&self, chat_id: &str) -> VoiceflousionResult<Option<StoredSession>> {
+        let read_lock = self.sessions.read().await;
+        Ok(read_lock.get(chat_id).cloned())
+    }
+
+    async fn save_session(&self, session: StoredSession) -> VoiceflousionResult<()> {
+        let mut write_lock = self.sessions.write().await;
+        write_lock.insert(session.chat_id().clone(), session);
+        Ok(())
+    }
+
+    async fn delete_session(&self, chat_id: &str) -> VoiceflousionResult<()> {
+        let mut write_lock = self.sessions.write().await;
+        write_lock.remove(chat_id);
+        Ok(())
+    }
+}
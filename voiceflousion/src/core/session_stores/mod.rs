@@ -0,0 +1,7 @@
+mod in_memory_session_store;
+mod file_session_store;
+
+pub use self::{
+    in_memory_session_store::InMemorySessionStore,
+    file_session_store::FileSessionStore,
+};
\ No newline at end of file
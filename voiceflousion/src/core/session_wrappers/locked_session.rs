@@ -1,8 +1,8 @@
 use std::ops::Deref;
 use std::sync::Arc;
 use tokio::sync::MutexGuard;
-use crate::core::session_wrappers::Session;
-use crate::core::subtypes::SentMessage;
+use crate::core::session_wrappers::{Session, WaitingUpdate};
+use crate::core::subtypes::{QueueOverflowPolicy, SentMessage};
 use crate::errors::VoiceflousionResult;
 
 /// Represents a locked session for thread-safe operations.
@@ -14,6 +14,10 @@ pub struct LockedSession<'g> {
     session: &'g Arc<Session>,
     /// A guard that holds the lock on the session.
     _guard: MutexGuard<'g, bool>,
+    /// A flag indicating whether the lock was acquired after waiting in the queue of the session.
+    queued: bool,
+    /// The texts of the next messages merged into the queued update.
+    merged_texts: Vec<String>,
 }
 
 impl<'g> Deref for LockedSession<'g> {
@@ -56,9 +60,139 @@ impl<'g> LockedSession<'g> {
         Ok(Self {
             session,
             _guard: guard,
+            queued: false,
+            merged_texts: Vec::new(),
         })
     }
 
+    /// Creates a `LockedSession` from a session by waiting for the lock in the queue of the session.
+    ///
+    /// The updates of the session acquire the lock in the order they were queued.
+    /// If the queue is full, the update is handled according to the overflow policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - A reference to the session.
+    /// * `text` - The text of the update if it is a text message.
+    /// * `depth` - The maximum number of the updates waiting in the queue.
+    /// * `overflow_policy` - The policy of handling the update arriving to the full queue.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a `LockedSession`, or `None` if the update was dropped or merged into another update.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::LockedSession;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::subtypes::QueueOverflowPolicy;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), Some(1627554661), true));
+    ///     let locked_session = LockedSession::queue_from_session(&session, None, 5, &QueueOverflowPolicy::DropOldest).await;
+    ///     assert!(locked_session.is_some());
+    /// }
+    /// ```
+    pub async fn queue_from_session(session: &'g Arc<Session>, text: Option<&String>, depth: usize, overflow_policy: &QueueOverflowPolicy) -> Option<Self> {
+        // Lock the session right away if there is no previous turn
+        if let Ok(guard) = session.try_lock() {
+            return Some(Self {
+                session,
+                _guard: guard,
+                queued: false,
+                merged_texts: Vec::new(),
+            })
+        }
+
+        let waiting_update = {
+            let mut waiting_updates = session.waiting_updates().await;
+
+            // Apply the overflow policy if the queue is full
+            if waiting_updates.len() >= depth {
+                match overflow_policy {
+                    QueueOverflowPolicy::DropOldest => {
+                        let oldest_update = waiting_updates.pop_front()?;
+                        oldest_update.drop_update();
+                    },
+                    QueueOverflowPolicy::DropNewest => return None,
+                    QueueOverflowPolicy::MergeTexts => {
+                        if let (Some(text), Some(latest_update)) = (text, waiting_updates.back()) {
+                            if latest_update.is_text() {
+                                latest_update.merge_text(text.clone()).await;
+                            }
+                        }
+                        return None
+                    }
+                }
+            }
+
+            let waiting_update = Arc::new(WaitingUpdate::new(text.is_some()));
+            waiting_updates.push_back(waiting_update.clone());
+            waiting_update
+        };
+
+        // Wait for the previous turns and leave the queue
+        let guard = session.lock().await;
+        session.waiting_updates().await.retain(|update| !Arc::ptr_eq(update, &waiting_update));
+
+        if waiting_update.is_dropped() {
+            return None
+        }
+
+        Some(Self {
+            session,
+            _guard: guard,
+            queued: true,
+            merged_texts: waiting_update.take_merged_texts().await,
+        })
+    }
+
+    /// Checks if the lock was acquired after waiting in the queue of the session.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the update waited for the previous turns of the chat.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::LockedSession;
+    /// use voiceflousion::core::session_wrappers::Session;
+    ///
+    /// let session = Arc::new(Session::new("chat_id".to_string(), Some(1627554661), true));
+    /// let locked_session = LockedSession::try_from_session(&session).unwrap();
+    /// assert!(!locked_session.is_queued());
+    /// ```
+    pub fn is_queued(&self) -> bool {
+        self.queued
+    }
+
+    /// Returns the texts of the next messages merged into the queued update.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of the merged texts in the order they were received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::LockedSession;
+    /// use voiceflousion::core::session_wrappers::Session;
+    ///
+    /// let session = Arc::new(Session::new("chat_id".to_string(), Some(1627554661), true));
+    /// let locked_session = LockedSession::try_from_session(&session).unwrap();
+    /// assert!(locked_session.merged_texts().is_empty());
+    /// ```
+    pub fn merged_texts(&self) -> &Vec<String> {
+        &self.merged_texts
+    }
+
     /// Sets the previous message in the session.
     ///
     /// # Parameters
@@ -117,4 +251,27 @@ impl<'g> LockedSession<'g> {
     pub fn set_last_interaction(&self, last_interaction: Option<i64>) {
         self.store_last_interaction(last_interaction)
     }
+
+    /// Sets the flag indicating whether the chat is handed off to a human agent.
+    ///
+    /// # Parameters
+    ///
+    /// * `handoff` - The handoff flag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::LockedSession;
+    /// use voiceflousion::core::session_wrappers::Session;
+    ///
+    /// let session = Arc::new(Session::new("chat_id".to_string(), Some(1627554661), true));
+    /// let locked_session = LockedSession::try_from_session(&session);
+    /// if let Ok(locked_session) = locked_session{
+    ///     locked_session.set_handoff(true);
+    /// }
+    /// ```
+    pub fn set_handoff(&self, handoff: bool) {
+        self.store_handoff(handoff)
+    }
 }
\ No newline at end of file
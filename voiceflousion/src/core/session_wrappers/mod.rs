@@ -1,5 +1,7 @@
 mod locked_session;
 mod session;
+mod stored_session;
+mod waiting_update;
 pub mod session_map;
 
 #[cfg(feature = "advanced")]
@@ -14,4 +16,7 @@ pub(crate) use self::{
     locked_session::LockedSession,
     session::Session,
     session_map::SessionMap,
-};
\ No newline at end of file
+};
+
+pub use self::stored_session::StoredSession;
+pub(crate) use self::waiting_update::WaitingUpdate;
\ No newline at end of file
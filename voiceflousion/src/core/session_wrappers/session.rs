@@ -1,6 +1,8 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard, MutexGuard};
+use crate::core::session_wrappers::{StoredSession, WaitingUpdate};
 use crate::core::subtypes::{AtomicTimestamp, SentMessage};
 use crate::core::voiceflow::VoiceflowSession;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
@@ -13,6 +15,8 @@ pub struct Session {
     chat_id: String,
     /// The status of the session (active/inactive).
     status: Arc<AtomicBool>,
+    /// The flag indicating whether the chat is handed off to a human agent.
+    handoff: Arc<AtomicBool>,
     /// The timestamp of the last interaction.
     last_interaction: Arc<AtomicTimestamp>,
     /// The previous message sent in the session.
@@ -21,6 +25,8 @@ pub struct Session {
     voiceflow_session: VoiceflowSession,
     /// The lock for managing session concurrency.
     lock: Arc<Mutex<bool>>,
+    /// The updates waiting for the lock in the queued mode.
+    waiting_updates: Arc<Mutex<VecDeque<Arc<WaitingUpdate>>>>,
 }
 
 impl Session {
@@ -49,12 +55,55 @@ impl Session {
             chat_id,
             voiceflow_session,
             status: Arc::new(AtomicBool::new(status)),
+            handoff: Arc::new(AtomicBool::new(false)),
             last_interaction: Arc::new(AtomicTimestamp::new(last_interaction)),
             previous_message: Arc::new(RwLock::new(None)),
             lock: Arc::new(Mutex::new(true)),
+            waiting_updates: Arc::new(Mutex::new(VecDeque::new())),
         }
     }
 
+    /// Restores a session from its stored snapshot.
+    ///
+    /// # Parameters
+    ///
+    /// * `stored_session` - The `StoredSession` to restore the session from.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `Session` with the Voiceflow session and previous message of the snapshot.
+    pub(crate) fn from_stored_session(stored_session: StoredSession) -> Self {
+        let (chat_id, status, handoff, last_interaction, session_id, user_id, previous_message) = stored_session.into_parts();
+        Self {
+            chat_id,
+            voiceflow_session: VoiceflowSession::new(session_id, user_id),
+            status: Arc::new(AtomicBool::new(status)),
+            handoff: Arc::new(AtomicBool::new(handoff)),
+            last_interaction: Arc::new(AtomicTimestamp::new(last_interaction)),
+            previous_message: Arc::new(RwLock::new(previous_message)),
+            lock: Arc::new(Mutex::new(true)),
+            waiting_updates: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Creates a serializable snapshot of the session.
+    ///
+    /// # Returns
+    ///
+    /// A `StoredSession` with the current state of the session.
+    pub(crate) async fn to_stored_session(&self) -> StoredSession {
+        let previous_message = self.previous_message().await.clone();
+        StoredSession::new(
+            self.get_cloned_chat_id(),
+            self.is_active(),
+            self.is_in_handoff(),
+            self.get_last_interaction(),
+            self.voiceflow_session.session_id().clone(),
+            self.voiceflow_session.user_id().clone(),
+            previous_message
+        )
+    }
+
     /// Returns the previous message sent in the session.
     ///
     /// # Returns
@@ -102,6 +151,26 @@ impl Session {
         binding.try_lock().map_err(|_| VoiceflousionError::SessionLockError(self.get_cloned_chat_id()))
     }
 
+    /// Waits for the lock on the session.
+    ///
+    /// The waiters acquire the lock in the order they started waiting.
+    ///
+    /// # Returns
+    ///
+    /// A `MutexGuard` of the session lock.
+    pub(super) async fn lock(&self) -> MutexGuard<bool>{
+        self.lock.lock().await
+    }
+
+    /// Returns the updates waiting for the lock in the queued mode.
+    ///
+    /// # Returns
+    ///
+    /// A `MutexGuard` to the queue of the waiting updates.
+    pub(super) async fn waiting_updates(&self) -> MutexGuard<VecDeque<Arc<WaitingUpdate>>>{
+        self.waiting_updates.lock().await
+    }
+
     /// Stores the timestamp of the last interaction.
     ///
     /// # Parameters
@@ -175,6 +244,36 @@ impl Session {
         self.status.load(Ordering::Acquire)
     }
 
+    /// Stores the flag indicating whether the chat is handed off to a human agent.
+    ///
+    /// # Parameters
+    ///
+    /// * `handoff` - The handoff flag.
+    pub(super) fn store_handoff(&self, handoff: bool) {
+        self.handoff.store(handoff, Ordering::Release)
+    }
+
+    /// Checks if the chat is handed off to a human agent.
+    ///
+    /// While the chat is in handoff, the updates of the user are passed to the `HandoffHandler`
+    /// of the client instead of Voiceflow.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the chat is in handoff.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::session_wrappers::Session;
+    ///
+    /// let session = Session::new("chat_id".to_string(), Some(1627554661), true);
+    /// assert!(!session.is_in_handoff());
+    /// ```
+    pub fn is_in_handoff(&self) -> bool {
+        self.handoff.load(Ordering::Acquire)
+    }
+
     /// Returns a reference to the chat ID.
     ///
     /// # Returns
@@ -1,18 +1,24 @@
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::Arc;
 use chrono::Utc;
 use tokio::sync::RwLock;
+use tracing::error;
 use crate::core::session_wrappers::Session;
+use crate::core::traits::SessionStore;
 
 /// Represents a map of sessions with cleanup functionality.
 ///
 /// `SessionMap` manages sessions, allows adding, retrieving, and deleting sessions,
 /// and provides functionality for periodic cleanup of invalid sessions.
+/// If a `SessionStore` is set, sessions are persisted into it and restored from it on demand.
 pub struct SessionMap {
     /// The map of sessions.
     sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     /// The duration a session is considered valid in seconds.
     valid_session_duration: Option<i64>,
+    /// The optional store for persisting sessions.
+    session_store: Option<Arc<dyn SessionStore>>,
 }
 
 impl SessionMap {
@@ -21,14 +27,16 @@ impl SessionMap {
     /// # Parameters
     ///
     /// * `valid_session_duration` - The duration a session is considered valid in seconds.
+    /// * `session_store` - The optional store for persisting sessions.
     ///
     /// # Returns
     ///
     /// A new instance of `SessionMap`.
-    pub(crate) fn new(valid_session_duration: Option<i64>) -> Self {
+    pub(crate) fn new(valid_session_duration: Option<i64>, session_store: Option<Arc<dyn SessionStore>>) -> Self {
         Self {
             sessions: Arc::new(RwLock::new(HashMap::<String, Arc<Session>>::new())),
-            valid_session_duration
+            valid_session_duration,
+            session_store
         }
     }
 
@@ -38,18 +46,20 @@ impl SessionMap {
     ///
     /// * `sessions_vec` - A vector of sessions to initialize the map with.
     /// * `valid_session_duration` - The duration a session is considered valid in seconds.
+    /// * `session_store` - The optional store for persisting sessions.
     ///
     /// # Returns
     ///
     /// A new instance of `SessionMap`.
-    pub(crate) fn from_sessions(sessions_vec: Vec<Session>, valid_session_duration: Option<i64>) -> Self {
+    pub(crate) fn from_sessions(sessions_vec: Vec<Session>, valid_session_duration: Option<i64>, session_store: Option<Arc<dyn SessionStore>>) -> Self {
         let mut hash_map = HashMap::<String, Arc<Session>>::new();
         sessions_vec.into_iter().for_each(|session| {
             hash_map.insert(session.get_cloned_chat_id(), Arc::new(session));
         });
         Self {
             sessions: Arc::new(RwLock::new(hash_map)),
-            valid_session_duration
+            valid_session_duration,
+            session_store
         }
     }
 
@@ -62,8 +72,19 @@ impl SessionMap {
         self.valid_session_duration
     }
 
+    /// Returns the store used for persisting sessions.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `SessionStore`.
+    pub fn session_store(&self) -> &Option<Arc<dyn SessionStore>> {
+        &self.session_store
+    }
+
     /// Retrieves a session by chat ID.
     ///
+    /// If the session is not in the map, it is restored from the session store, if one is set.
+    ///
     /// # Parameters
     ///
     /// * `chat_id` - The chat ID of the session to retrieve.
@@ -72,13 +93,16 @@ impl SessionMap {
     ///
     /// An `Option` containing an `Arc` to the session if found and valid.
     pub async fn get_session(&self, chat_id: &String) -> Option<Arc<Session>> {
-        let read_lock = self.sessions.read().await;
-        if let Some(session) = read_lock.get(chat_id) {
-            if self.is_valid_session(session).await {
-                return Some(session.clone());
+        {
+            let read_lock = self.sessions.read().await;
+            if let Some(session) = read_lock.get(chat_id) {
+                if self.is_valid_session(session).await {
+                    return Some(session.clone());
+                }
+                return None;
             }
         }
-        None
+        self.restore_session(chat_id).await
     }
 
     /// Retrieves all sessions.
@@ -92,6 +116,16 @@ impl SessionMap {
         sessions
     }
 
+    /// Returns the count of the sessions in the map.
+    ///
+    /// # Returns
+    ///
+    /// The count of the sessions.
+    pub async fn sessions_count(&self) -> usize {
+        let read_lock = self.sessions.read().await;
+        read_lock.len()
+    }
+
     /// Adds a new session by chat ID or returns the existing session.
     ///
     /// # Parameters
@@ -102,10 +136,21 @@ impl SessionMap {
     ///
     /// An `Arc` to the newly added or existing session.
     pub async fn add_session(&self, chat_id: String) -> Arc<Session> {
-        let mut write_lock = self.sessions.write().await;
-        let session = write_lock.entry(chat_id.clone())
-            .or_insert_with(|| Arc::new(Session::new(chat_id, Some(Utc::now().timestamp()), true)))
-            .clone();
+        let (session, is_new) = {
+            let mut write_lock = self.sessions.write().await;
+            match write_lock.entry(chat_id.clone()) {
+                Entry::Occupied(entry) => (entry.get().clone(), false),
+                Entry::Vacant(entry) => {
+                    let session = entry.insert(Arc::new(Session::new(chat_id, Some(Utc::now().timestamp()), true)));
+                    (session.clone(), true)
+                }
+            }
+        };
+
+        // Persist only sessions that were created right now
+        if is_new {
+            self.persist_session(&session).await;
+        }
         session
     }
 
@@ -115,12 +160,80 @@ impl SessionMap {
     ///
     /// * `chat_id` - The chat ID of the session to delete.
     pub async fn delete_session(&self, chat_id: &String) {
+        {
+            let mut write_lock = self.sessions.write().await;
+            write_lock.remove(chat_id);
+        }
+        self.delete_stored_session(chat_id).await;
+    }
+
+    /// Saves the current state of the session into the session store.
+    ///
+    /// Does nothing if the session store is not set. Store failures are logged and
+    /// do not interrupt the interaction.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - A reference to the session to persist.
+    pub async fn persist_session(&self, session: &Session) {
+        if let Some(store) = &self.session_store {
+            let stored_session = session.to_stored_session().await;
+            if let Err(error) = store.save_session(stored_session).await {
+                error!(%error, "Failed to save the session");
+            }
+        }
+    }
+
+    /// Deletes the session from the session store.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the session to delete.
+    async fn delete_stored_session(&self, chat_id: &String) {
+        if let Some(store) = &self.session_store {
+            if let Err(error) = store.delete_session(chat_id).await {
+                error!(%error, "Failed to delete the stored session");
+            }
+        }
+    }
+
+    /// Restores a session from the session store and puts it into the map.
+    ///
+    /// Invalid stored sessions are deleted from the store.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the session to restore.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing an `Arc` to the restored session if found and valid.
+    async fn restore_session(&self, chat_id: &String) -> Option<Arc<Session>> {
+        let store = self.session_store.as_ref()?;
+        let stored_session = match store.load_session(chat_id).await {
+            Ok(stored_session) => stored_session?,
+            Err(error) => {
+                error!(%error, "Failed to load the stored session");
+                return None;
+            }
+        };
+
+        let session = Arc::new(Session::from_stored_session(stored_session));
+        if !self.is_valid_session(&session).await {
+            self.delete_stored_session(chat_id).await;
+            return None;
+        }
+
         let mut write_lock = self.sessions.write().await;
-        write_lock.remove(chat_id);
+        let session = write_lock.entry(chat_id.clone())
+            .or_insert(session)
+            .clone();
+        Some(session)
     }
 
     /// Deletes all invalid sessions.
     pub(crate) async fn delete_invalid_sessions(&self) {
+        let mut deleted_keys = Vec::new();
         let mut write_lock = self.sessions.write().await;
         let keys: Vec<String> = write_lock.keys().cloned().collect();
         for key in keys {
@@ -134,8 +247,14 @@ impl SessionMap {
             };
             if is_delete{
                 write_lock.remove(&key);
+                deleted_keys.push(key);
             }
         }
+        drop(write_lock);
+
+        for key in deleted_keys.iter() {
+            self.delete_stored_session(key).await;
+        }
     }
 
     /// Checks if a session is valid.
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use crate::core::subtypes::SentMessage;
+
+/// Represents a serializable snapshot of a session.
+///
+/// `StoredSession` contains everything required to restore a `Session` after a restart:
+/// the chat ID, status, handoff flag, last interaction time, Voiceflow session and user IDs
+/// and the previous message sent in the session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredSession {
+    /// The chat ID associated with the session.
+    chat_id: String,
+    /// The status of the session (active/inactive).
+    status: bool,
+    /// The flag indicating whether the chat is handed off to a human agent.
+    #[serde(default)]
+    handoff: bool,
+    /// The timestamp of the last interaction.
+    last_interaction: Option<i64>,
+    /// The session ID used in Voiceflow.
+    voiceflow_session_id: String,
+    /// The user ID used in Voiceflow.
+    voiceflow_user_id: String,
+    /// The previous message sent in the session.
+    previous_message: Option<SentMessage>,
+}
+
+impl StoredSession {
+    /// Creates a new `StoredSession`.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID associated with the session.
+    /// * `status` - The status of the session.
+    /// * `handoff` - The handoff flag of the session.
+    /// * `last_interaction` - The optional timestamp of the last interaction.
+    /// * `voiceflow_session_id` - The session ID used in Voiceflow.
+    /// * `voiceflow_user_id` - The user ID used in Voiceflow.
+    /// * `previous_message` - The optional previous message sent in the session.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `StoredSession`.
+    pub(crate) fn new(chat_id: String, status: bool, handoff: bool, last_interaction: Option<i64>, voiceflow_session_id: String, voiceflow_user_id: String, previous_message: Option<SentMessage>) -> Self {
+        Self {
+            chat_id,
+            status,
+            handoff,
+            last_interaction,
+            voiceflow_session_id,
+            voiceflow_user_id,
+            previous_message,
+        }
+    }
+
+    /// Returns a reference to the chat ID.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the chat ID string.
+    pub fn chat_id(&self) -> &String {
+        &self.chat_id
+    }
+
+    /// Returns the status of the stored session.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the session is active.
+    pub fn status(&self) -> bool {
+        self.status
+    }
+
+    /// Returns the handoff flag of the stored session.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the chat is handed off to a human agent.
+    pub fn handoff(&self) -> bool {
+        self.handoff
+    }
+
+    /// Returns the timestamp of the last interaction.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<i64>` containing the timestamp of the last interaction.
+    pub fn last_interaction(&self) -> Option<i64> {
+        self.last_interaction
+    }
+
+    /// Returns a reference to the Voiceflow session ID.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the Voiceflow session ID string.
+    pub fn voiceflow_session_id(&self) -> &String {
+        &self.voiceflow_session_id
+    }
+
+    /// Returns a reference to the Voiceflow user ID.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the Voiceflow user ID string.
+    pub fn voiceflow_user_id(&self) -> &String {
+        &self.voiceflow_user_id
+    }
+
+    /// Consumes the stored session and returns its parts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the chat ID, status, handoff flag, last interaction, Voiceflow session ID,
+    /// Voiceflow user ID and previous message.
+    pub(crate) fn into_parts(self) -> (String, bool, bool, Option<i64>, String, String, Option<SentMessage>) {
+        (self.chat_id, self.status, self.handoff, self.last_interaction, self.voiceflow_session_id, self.voiceflow_user_id, self.previous_message)
+    }
+}
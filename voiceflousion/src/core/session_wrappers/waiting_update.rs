@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Mutex;
+
+/// Represents an update waiting in the queue of the session for the previous turn of the chat.
+pub(crate) struct WaitingUpdate {
+    /// The flag indicating whether the update is a text message which can be merged with the next text messages.
+    is_text: bool,
+    /// The flag indicating whether the update is dropped from the queue.
+    dropped: AtomicBool,
+    /// The texts of the next messages merged into the update.
+    merged_texts: Mutex<Vec<String>>,
+}
+
+impl WaitingUpdate {
+    /// Creates a new `WaitingUpdate`.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_text` - Whether the update is a text message.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WaitingUpdate`.
+    pub fn new(is_text: bool) -> Self {
+        Self {
+            is_text,
+            dropped: AtomicBool::new(false),
+            merged_texts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Checks if the update is a text message.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the update is a text message.
+    pub fn is_text(&self) -> bool {
+        self.is_text
+    }
+
+    /// Drops the update from the queue.
+    pub fn drop_update(&self) {
+        self.dropped.store(true, Ordering::SeqCst);
+    }
+
+    /// Checks if the update is dropped from the queue.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the update is dropped.
+    pub fn is_dropped(&self) -> bool {
+        self.dropped.load(Ordering::SeqCst)
+    }
+
+    /// Merges the text of the next message into the update.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text of the next message.
+    pub async fn merge_text(&self, text: String) {
+        self.merged_texts.lock().await.push(text);
+    }
+
+    /// Takes the texts merged into the update.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the merged texts in the order they were received.
+    pub async fn take_merged_texts(&self) -> Vec<String> {
+        std::mem::take(&mut *self.merged_texts.lock().await)
+    }
+}
\ No newline at end of file
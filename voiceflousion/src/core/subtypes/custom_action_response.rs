@@ -0,0 +1,155 @@
+use serde_json::Value;
+use crate::core::voiceflow::State;
+
+/// Represents the result of a custom action handler.
+///
+/// `CustomActionResponse` contains the path the Voiceflow dialog is resumed with,
+/// the optional payload of the path and the optional state for updating the variables of the dialog.
+#[derive(Debug, Clone)]
+pub struct CustomActionResponse {
+    /// The name of the path to resume the dialog with.
+    path: String,
+
+    /// The optional payload of the path.
+    payload: Option<Value>,
+
+    /// The optional state for updating the variables of the dialog.
+    state: Option<State>,
+}
+
+impl CustomActionResponse {
+    /// Creates a new `CustomActionResponse` with the specified path.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The name of the path to resume the dialog with.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `CustomActionResponse`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string());
+    /// ```
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+            payload: None,
+            state: None,
+        }
+    }
+
+    /// Sets the payload of the path.
+    ///
+    /// # Parameters
+    ///
+    /// * `payload` - The payload of the path.
+    ///
+    /// # Returns
+    ///
+    /// The updated `CustomActionResponse` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string()).set_payload(json!({"order": 1}));
+    /// ```
+    pub fn set_payload(mut self, payload: Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    /// Sets the state for updating the variables of the dialog.
+    ///
+    /// # Parameters
+    ///
+    /// * `state` - The state with the variables.
+    ///
+    /// # Returns
+    ///
+    /// The updated `CustomActionResponse` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    /// use voiceflousion::core::voiceflow::State;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string()).set_state(State::new(vec![json!({"paid": true})]));
+    /// ```
+    pub fn set_state(mut self, state: State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Returns a reference to the name of the path.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the path string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string());
+    /// let path = response.path();
+    /// ```
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+
+    /// Returns a reference to the payload of the path.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string());
+    /// let payload = response.payload();
+    /// ```
+    pub fn payload(&self) -> &Option<Value> {
+        &self.payload
+    }
+
+    /// Returns a reference to the state for updating the variables of the dialog.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::CustomActionResponse;
+    ///
+    /// let response = CustomActionResponse::new("success".to_string());
+    /// let state = response.state();
+    /// ```
+    pub fn state(&self) -> &Option<State> {
+        &self.state
+    }
+
+    /// Consumes the response and returns its parts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the path, the optional payload and the optional state.
+    pub(crate) fn into_parts(self) -> (String, Option<Value>, Option<State>) {
+        (self.path, self.payload, self.state)
+    }
+}
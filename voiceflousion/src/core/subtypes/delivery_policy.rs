@@ -0,0 +1,99 @@
+use crate::core::subtypes::{RateLimit, RetryPolicy};
+
+/// Represents the policy of delivering the outbound requests to the client platform.
+///
+/// `DeliveryPolicy` combines the rate limit of the whole bot, the rate limit of every chat
+/// and the policy of retrying the throttled requests. Every integration provides its own
+/// default delivery policy matching the limits of the platform.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeliveryPolicy {
+    /// The optional rate limit of all the requests of the bot.
+    global_rate_limit: Option<RateLimit>,
+    /// The optional rate limit of the requests to every chat.
+    chat_rate_limit: Option<RateLimit>,
+    /// The policy of retrying the throttled requests.
+    retry_policy: RetryPolicy,
+}
+
+impl DeliveryPolicy {
+    /// Creates a new `DeliveryPolicy`.
+    ///
+    /// # Parameters
+    ///
+    /// * `global_rate_limit` - The optional rate limit of all the requests of the bot.
+    /// * `chat_rate_limit` - The optional rate limit of the requests to every chat.
+    /// * `retry_policy` - The policy of retrying the throttled requests.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `DeliveryPolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(30, 1000)), Some(RateLimit::new(1, 1000)), RetryPolicy::default());
+    /// ```
+    pub fn new(global_rate_limit: Option<RateLimit>, chat_rate_limit: Option<RateLimit>, retry_policy: RetryPolicy) -> Self {
+        Self {
+            global_rate_limit,
+            chat_rate_limit,
+            retry_policy,
+        }
+    }
+
+    /// Returns the rate limit of all the requests of the bot.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `RateLimit`, `None` if the requests of the bot are not limited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(30, 1000)), None, RetryPolicy::default());
+    /// assert_eq!(policy.global_rate_limit(), &Some(RateLimit::new(30, 1000)));
+    /// ```
+    pub fn global_rate_limit(&self) -> &Option<RateLimit> {
+        &self.global_rate_limit
+    }
+
+    /// Returns the rate limit of the requests to every chat.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `RateLimit`, `None` if the requests to the chats are not limited.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::new(None, Some(RateLimit::new(1, 1000)), RetryPolicy::default());
+    /// assert_eq!(policy.chat_rate_limit(), &Some(RateLimit::new(1, 1000)));
+    /// ```
+    pub fn chat_rate_limit(&self) -> &Option<RateLimit> {
+        &self.chat_rate_limit
+    }
+
+    /// Returns the policy of retrying the throttled requests.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `RetryPolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::default();
+    /// assert_eq!(policy.retry_policy(), &RetryPolicy::default());
+    /// ```
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+}
\ No newline at end of file
@@ -1,6 +1,11 @@
 use std::ops::Deref;
 use std::time::Duration;
-use reqwest::Client;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use serde_json::Value;
+use tokio::time::sleep;
+use tracing::warn;
+use crate::core::subtypes::{DeliveryPolicy, RateLimiter};
 
 /// A client for handling HTTP requests with session management.
 ///
@@ -11,7 +16,11 @@ pub struct HttpClient {
     /// The maximum number of idle connections per host.
     max_connections_per_moment: usize,
     /// Duration of the HTTP connection in seconds
-    connection_duration: u64
+    connection_duration: u64,
+    /// The policy of delivering the requests sent with retries.
+    delivery_policy: DeliveryPolicy,
+    /// The rate limiter of the requests sent with retries.
+    rate_limiter: RateLimiter
 }
 
 impl Deref for HttpClient {
@@ -60,10 +69,161 @@ impl HttpClient {
                 .pool_idle_timeout(Duration::from_secs(connection_duration))
                 .build().unwrap(),
             max_connections_per_moment: max_sessions_per_moment,
-            connection_duration
+            connection_duration,
+            delivery_policy: DeliveryPolicy::default(),
+            rate_limiter: RateLimiter::new(None, None)
         }
     }
 
+    /// Sets the policy of delivering the requests sent with retries.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The rate limits and the retry policy of the requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `HttpClient` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, HttpClient, RateLimit, RetryPolicy};
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(30, 1000)), None, RetryPolicy::default());
+    /// let http_client = HttpClient::new(10, None).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.rate_limiter = RateLimiter::new(*delivery_policy.global_rate_limit(), *delivery_policy.chat_rate_limit());
+        self.delivery_policy = delivery_policy;
+        self
+    }
+
+    /// Returns the policy of delivering the requests sent with retries.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `DeliveryPolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, HttpClient};
+    ///
+    /// let http_client = HttpClient::new(10, None);
+    /// assert_eq!(http_client.delivery_policy(), &DeliveryPolicy::default());
+    /// ```
+    pub fn delivery_policy(&self) -> &DeliveryPolicy {
+        &self.delivery_policy
+    }
+
+    /// Sends the request within the rate limits and retries it while it is throttled.
+    ///
+    /// The request is retried on connection errors, timeouts, `429 Too Many Requests`, server errors
+    /// and the errors recognized by `is_throttled`. The delay before the retry is taken from the `Retry-After` header
+    /// or the `retry_after` field of the response body, and otherwise grows with the exponential backoff.
+    /// The last response is returned once the retries are exhausted.
+    ///
+    /// # Parameters
+    ///
+    /// * `request` - The request to send.
+    /// * `chat_id` - The optional ID of the chat the request is sent to, used for the rate limit of the chat.
+    /// * `is_throttled` - The function recognizing the throttling errors of the platform in the JSON body of the failed response.
+    ///
+    /// # Returns
+    ///
+    /// The `Response` or the `reqwest::Error` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::HttpClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let http_client = HttpClient::new(10, None);
+    ///     let request = http_client.post("https://example.com/send");
+    ///     let response = http_client.send_with_retry(request, Some("chat_id"), |_| false).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    pub async fn send_with_retry(&self, request: RequestBuilder, chat_id: Option<&str>, is_throttled: fn(&Value) -> bool) -> reqwest::Result<Response> {
+        let retry_policy = self.delivery_policy.retry_policy();
+        let mut request = request;
+        let mut attempt = 0;
+
+        loop {
+            // The requests with the streamed body can't be cloned and are sent once
+            let next_request = if attempt < retry_policy.max_retries() {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            self.rate_limiter.acquire(chat_id).await;
+            let result = request.send().await;
+
+            let next_request = match next_request {
+                Some(next_request) => next_request,
+                None => return result
+            };
+
+            let delay = match result {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let headers = response.headers().clone();
+                    let body = response.bytes().await?;
+                    let json: Value = serde_json::from_slice(&body).unwrap_or_default();
+
+                    let throttled = status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() || is_throttled(&json);
+                    let delay = Self::retry_after(&headers, &json).unwrap_or_else(|| retry_policy.backoff(attempt));
+
+                    // Give the response back if the platform asks to wait longer than allowed
+                    if !throttled || delay > Duration::from_millis(retry_policy.max_delay()) {
+                        let mut response = http::Response::new(body);
+                        *response.status_mut() = status;
+                        *response.headers_mut() = headers;
+                        return Ok(Response::from(response));
+                    }
+                    warn!(%status, attempt = attempt + 1, delay_ms = delay.as_millis() as u64, "Request is throttled, retrying");
+                    delay
+                },
+                Err(error) if error.is_connect() || error.is_timeout() => {
+                    let delay = retry_policy.backoff(attempt);
+                    warn!(%error, attempt = attempt + 1, delay_ms = delay.as_millis() as u64, "Request failed, retrying");
+                    delay
+                },
+                Err(error) => return Err(error)
+            };
+
+            sleep(delay).await;
+            request = next_request;
+            attempt += 1;
+        }
+    }
+
+    /// Extracts the time to wait before the retry requested by the platform.
+    ///
+    /// # Parameters
+    ///
+    /// * `headers` - The headers of the response.
+    /// * `body` - The JSON body of the response.
+    ///
+    /// # Returns
+    ///
+    /// The optional `Duration` of the `Retry-After` header, or of the `retry_after` field of the body in seconds.
+    fn retry_after(headers: &HeaderMap, body: &Value) -> Option<Duration> {
+        headers.get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .or_else(|| body["parameters"]["retry_after"].as_f64())
+            .or_else(|| body["retry_after"].as_f64())
+            .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+            .map(Duration::from_secs_f64)
+    }
+
     /// Returns the maximum number of idle connections per host.
     ///
     /// # Returns
@@ -0,0 +1,140 @@
+use serde_json::{json, Value};
+
+/// Represents a contact sent by the user.
+///
+/// `InputContact` contains the phone number and the optional name and user ID of the contact.
+#[derive(Debug, Clone)]
+pub struct InputContact {
+    /// The phone number of the contact.
+    phone_number: String,
+    /// The optional name of the contact.
+    name: Option<String>,
+    /// The optional ID of the contact in the integration.
+    user_id: Option<String>,
+}
+
+impl InputContact {
+    /// Creates a new `InputContact`.
+    ///
+    /// # Parameters
+    ///
+    /// * `phone_number` - The phone number of the contact.
+    /// * `name` - The optional name of the contact.
+    /// * `user_id` - The optional ID of the contact in the integration.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InputContact`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), Some("John".to_string()), None);
+    /// ```
+    pub fn new(phone_number: String, name: Option<String>, user_id: Option<String>) -> Self {
+        Self {
+            phone_number,
+            name,
+            user_id,
+        }
+    }
+
+    /// Returns a reference to the phone number of the contact.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the phone number string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), None, None);
+    /// let phone_number = contact.phone_number();
+    /// ```
+    pub fn phone_number(&self) -> &String {
+        &self.phone_number
+    }
+
+    /// Returns a reference to the name of the contact.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), Some("John".to_string()), None);
+    /// let name = contact.name();
+    /// ```
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// Returns a reference to the ID of the contact in the integration.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional user ID string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), None, Some("12345".to_string()));
+    /// let user_id = contact.user_id();
+    /// ```
+    pub fn user_id(&self) -> &Option<String> {
+        &self.user_id
+    }
+
+    /// Returns the text sent to Voiceflow for the contact.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the phone number of the contact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), None, None);
+    /// assert_eq!(contact.text(), "+380000000000");
+    /// ```
+    pub fn text(&self) -> String {
+        self.phone_number.clone()
+    }
+
+    /// Builds the Voiceflow variables describing the contact.
+    ///
+    /// # Returns
+    ///
+    /// A JSON `Value` with the `input_type`, `input_phone_number`, `input_contact_name`
+    /// and `input_contact_id` variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputContact;
+    ///
+    /// let contact = InputContact::new("+380000000000".to_string(), None, None);
+    /// let variables = contact.to_variables();
+    /// assert_eq!(variables["input_type"], "contact");
+    /// ```
+    pub fn to_variables(&self) -> Value {
+        json!({
+            "input_type": "contact",
+            "input_phone_number": self.phone_number,
+            "input_contact_name": self.name,
+            "input_contact_id": self.user_id,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+/// Represents the kind of the user's input beyond text and buttons.
+///
+/// `InputKind` is used for configuring the `InputPolicy` of each kind of input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// An image or a photo.
+    Image,
+    /// A video.
+    Video,
+    /// An animation (GIF).
+    Animation,
+    /// An audio file.
+    Audio,
+    /// A voice note.
+    Voice,
+    /// A sticker.
+    Sticker,
+    /// A document or any other file.
+    Document,
+    /// A location.
+    Location,
+    /// A contact.
+    Contact,
+    /// A reaction to a message.
+    Reaction,
+}
@@ -0,0 +1,165 @@
+use serde_json::{json, Value};
+
+/// Represents a location sent by the user.
+///
+/// `InputLocation` contains the coordinates and the optional name and address of the location.
+#[derive(Debug, Clone)]
+pub struct InputLocation {
+    /// The latitude of the location.
+    latitude: f64,
+    /// The longitude of the location.
+    longitude: f64,
+    /// The optional name of the location.
+    name: Option<String>,
+    /// The optional address of the location.
+    address: Option<String>,
+}
+
+impl InputLocation {
+    /// Creates a new `InputLocation`.
+    ///
+    /// # Parameters
+    ///
+    /// * `latitude` - The latitude of the location.
+    /// * `longitude` - The longitude of the location.
+    /// * `name` - The optional name of the location.
+    /// * `address` - The optional address of the location.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InputLocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, None);
+    /// ```
+    pub fn new(latitude: f64, longitude: f64, name: Option<String>, address: Option<String>) -> Self {
+        Self {
+            latitude,
+            longitude,
+            name,
+            address,
+        }
+    }
+
+    /// Returns the latitude of the location.
+    ///
+    /// # Returns
+    ///
+    /// The latitude as `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, None);
+    /// let latitude = location.latitude();
+    /// ```
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude of the location.
+    ///
+    /// # Returns
+    ///
+    /// The longitude as `f64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, None);
+    /// let longitude = location.longitude();
+    /// ```
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns a reference to the name of the location.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, Some("Office".to_string()), None);
+    /// let name = location.name();
+    /// ```
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// Returns a reference to the address of the location.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional address string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, Some("Main street, 1".to_string()));
+    /// let address = location.address();
+    /// ```
+    pub fn address(&self) -> &Option<String> {
+        &self.address
+    }
+
+    /// Returns the text sent to Voiceflow for the location.
+    ///
+    /// The address of the location is used if it is present, otherwise the coordinates.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the text of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, None);
+    /// assert_eq!(location.text(), "50.45,30.52");
+    /// ```
+    pub fn text(&self) -> String {
+        self.address.clone().unwrap_or_else(|| format!("{},{}", self.latitude, self.longitude))
+    }
+
+    /// Builds the Voiceflow variables describing the location.
+    ///
+    /// # Returns
+    ///
+    /// A JSON `Value` with the `input_type`, `input_latitude`, `input_longitude`,
+    /// `input_location_name` and `input_address` variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputLocation;
+    ///
+    /// let location = InputLocation::new(50.45, 30.52, None, None);
+    /// let variables = location.to_variables();
+    /// assert_eq!(variables["input_type"], "location");
+    /// ```
+    pub fn to_variables(&self) -> Value {
+        json!({
+            "input_type": "location",
+            "input_latitude": self.latitude,
+            "input_longitude": self.longitude,
+            "input_location_name": self.name,
+            "input_address": self.address,
+        })
+    }
+}
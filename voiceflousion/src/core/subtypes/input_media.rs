@@ -0,0 +1,194 @@
+use serde_json::{json, Value};
+use crate::core::subtypes::InputMediaType;
+
+/// Represents a media or a file sent by the user.
+///
+/// `InputMedia` contains the type of the media, the ID of the file in the integration
+/// and the optional caption, MIME type and name of the file.
+#[derive(Debug, Clone)]
+pub struct InputMedia {
+    /// The type of the media.
+    media_type: InputMediaType,
+    /// The ID of the file in the integration.
+    file_id: String,
+    /// The optional caption of the media.
+    caption: Option<String>,
+    /// The optional MIME type of the file.
+    mime_type: Option<String>,
+    /// The optional name of the file.
+    file_name: Option<String>,
+}
+
+impl InputMedia {
+    /// Creates a new `InputMedia`.
+    ///
+    /// # Parameters
+    ///
+    /// * `media_type` - The type of the media.
+    /// * `file_id` - The ID of the file in the integration (or its URL if the integration has no file IDs).
+    /// * `caption` - The optional caption of the media.
+    /// * `mime_type` - The optional MIME type of the file.
+    /// * `file_name` - The optional name of the file.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InputMedia`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), Some("My cat".to_string()), None, None);
+    /// ```
+    pub fn new(media_type: InputMediaType, file_id: String, caption: Option<String>, mime_type: Option<String>, file_name: Option<String>) -> Self {
+        Self {
+            media_type,
+            file_id,
+            caption,
+            mime_type,
+            file_name,
+        }
+    }
+
+    /// Returns the type of the media.
+    ///
+    /// # Returns
+    ///
+    /// The `InputMediaType` of the media.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), None, None, None);
+    /// let media_type = media.media_type();
+    /// ```
+    pub fn media_type(&self) -> InputMediaType {
+        self.media_type
+    }
+
+    /// Returns a reference to the ID of the file.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the file ID string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), None, None, None);
+    /// let file_id = media.file_id();
+    /// ```
+    pub fn file_id(&self) -> &String {
+        &self.file_id
+    }
+
+    /// Returns a reference to the caption of the media.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional caption string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), Some("My cat".to_string()), None, None);
+    /// let caption = media.caption();
+    /// ```
+    pub fn caption(&self) -> &Option<String> {
+        &self.caption
+    }
+
+    /// Returns a reference to the MIME type of the file.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional MIME type string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Voice, "file_id".to_string(), None, Some("audio/ogg".to_string()), None);
+    /// let mime_type = media.mime_type();
+    /// ```
+    pub fn mime_type(&self) -> &Option<String> {
+        &self.mime_type
+    }
+
+    /// Returns a reference to the name of the file.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional file name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Document, "file_id".to_string(), None, None, Some("invoice.pdf".to_string()));
+    /// let file_name = media.file_name();
+    /// ```
+    pub fn file_name(&self) -> &Option<String> {
+        &self.file_name
+    }
+
+    /// Returns the text sent to Voiceflow for the media.
+    ///
+    /// The caption of the media is used if it is present, otherwise the type of the media.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the text of the media.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), None, None, None);
+    /// assert_eq!(media.text(), "image");
+    /// ```
+    pub fn text(&self) -> String {
+        self.caption.clone().unwrap_or_else(|| self.media_type.as_str().to_string())
+    }
+
+    /// Builds the Voiceflow variables describing the media.
+    ///
+    /// # Parameters
+    ///
+    /// * `url` - The optional download URL of the file.
+    ///
+    /// # Returns
+    ///
+    /// A JSON `Value` with the `input_type`, `input_file_id`, `input_url`, `input_caption`,
+    /// `input_mime_type` and `input_file_name` variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputMedia, InputMediaType};
+    ///
+    /// let media = InputMedia::new(InputMediaType::Image, "file_id".to_string(), None, None, None);
+    /// let variables = media.to_variables(Some(&"https://example.com/image.jpg".to_string()));
+    /// assert_eq!(variables["input_type"], "image");
+    /// ```
+    pub fn to_variables(&self, url: Option<&String>) -> Value {
+        json!({
+            "input_type": self.media_type.as_str(),
+            "input_file_id": self.file_id,
+            "input_url": url,
+            "input_caption": self.caption,
+            "input_mime_type": self.mime_type,
+            "input_file_name": self.file_name,
+        })
+    }
+}
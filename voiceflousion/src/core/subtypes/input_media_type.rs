@@ -0,0 +1,49 @@
+/// Represents the type of media sent by the user.
+///
+/// `InputMediaType` is used to distinguish the media of `InputMedia`
+/// received from the different integrations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMediaType {
+    /// An image or a photo.
+    Image,
+    /// A video.
+    Video,
+    /// An animation (GIF).
+    Animation,
+    /// An audio file.
+    Audio,
+    /// A voice note.
+    Voice,
+    /// A sticker.
+    Sticker,
+    /// A document or any other file.
+    Document,
+}
+
+impl InputMediaType {
+    /// Returns the string representation of the media type.
+    ///
+    /// # Returns
+    ///
+    /// A static string representing the media type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputMediaType;
+    ///
+    /// let media_type = InputMediaType::Image;
+    /// assert_eq!(media_type.as_str(), "image");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputMediaType::Image => "image",
+            InputMediaType::Video => "video",
+            InputMediaType::Animation => "animation",
+            InputMediaType::Audio => "audio",
+            InputMediaType::Voice => "voice",
+            InputMediaType::Sticker => "sticker",
+            InputMediaType::Document => "document",
+        }
+    }
+}
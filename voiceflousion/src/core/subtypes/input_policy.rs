@@ -0,0 +1,13 @@
+/// Represents the policy of handling a kind of the user's input.
+///
+/// `InputPolicy` defines whether the input is forwarded to Voiceflow, answered with a fallback text,
+/// or ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputPolicy {
+    /// Forward the input to Voiceflow.
+    Forward,
+    /// Reply to the user with the fallback text without forwarding the input to Voiceflow.
+    Reply(String),
+    /// Ignore the input.
+    Ignore,
+}
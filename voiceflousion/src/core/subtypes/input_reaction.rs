@@ -0,0 +1,116 @@
+use serde_json::{json, Value};
+
+/// Represents a reaction of the user to a message.
+///
+/// `InputReaction` contains the ID of the message the user reacted to and the emoji of the reaction.
+#[derive(Debug, Clone)]
+pub struct InputReaction {
+    /// The ID of the message the user reacted to.
+    message_id: String,
+    /// The emoji of the reaction. It is empty if the reaction was removed.
+    emoji: String,
+}
+
+impl InputReaction {
+    /// Creates a new `InputReaction`.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message the user reacted to.
+    /// * `emoji` - The emoji of the reaction.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `InputReaction`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputReaction;
+    ///
+    /// let reaction = InputReaction::new("message_id".to_string(), "👍".to_string());
+    /// ```
+    pub fn new(message_id: String, emoji: String) -> Self {
+        Self {
+            message_id,
+            emoji,
+        }
+    }
+
+    /// Returns a reference to the ID of the message the user reacted to.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the message ID string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputReaction;
+    ///
+    /// let reaction = InputReaction::new("message_id".to_string(), "👍".to_string());
+    /// let message_id = reaction.message_id();
+    /// ```
+    pub fn message_id(&self) -> &String {
+        &self.message_id
+    }
+
+    /// Returns a reference to the emoji of the reaction.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the emoji string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputReaction;
+    ///
+    /// let reaction = InputReaction::new("message_id".to_string(), "👍".to_string());
+    /// let emoji = reaction.emoji();
+    /// ```
+    pub fn emoji(&self) -> &String {
+        &self.emoji
+    }
+
+    /// Returns the text sent to Voiceflow for the reaction.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the emoji of the reaction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputReaction;
+    ///
+    /// let reaction = InputReaction::new("message_id".to_string(), "👍".to_string());
+    /// assert_eq!(reaction.text(), "👍");
+    /// ```
+    pub fn text(&self) -> String {
+        self.emoji.clone()
+    }
+
+    /// Builds the Voiceflow variables describing the reaction.
+    ///
+    /// # Returns
+    ///
+    /// A JSON `Value` with the `input_type`, `input_emoji` and `input_message_id` variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InputReaction;
+    ///
+    /// let reaction = InputReaction::new("message_id".to_string(), "👍".to_string());
+    /// let variables = reaction.to_variables();
+    /// assert_eq!(variables["input_type"], "reaction");
+    /// ```
+    pub fn to_variables(&self) -> Value {
+        json!({
+            "input_type": "reaction",
+            "input_emoji": self.emoji,
+            "input_message_id": self.message_id,
+        })
+    }
+}
@@ -1,6 +1,9 @@
+use crate::core::subtypes::{InputContact, InputKind, InputLocation, InputMedia, InputMediaType, InputReaction};
+
 /// Represents different types of interactions in the integration.
 ///
-/// `InteractionType` can be a text message, a button interaction with an index, or a carousel switch interaction.
+/// `InteractionType` can be a text message, a button interaction with an index, a carousel switch interaction,
+/// a button interaction on a specific carousel card, or a media, file, location, contact or reaction sent by the user.
 #[derive(Debug)]
 pub enum InteractionType {
     /// Represents a text interaction.
@@ -9,6 +12,20 @@ pub enum InteractionType {
     Button(usize),
     /// Represents a carousel switch interaction with a direction (`true` for next, `false` for previous).
     CarouselSwitch(bool),
+    /// Represents a button interaction on a carousel card with the associated card index and button index.
+    ///
+    /// Used by the platforms which display all the carousel cards at once.
+    CarouselButton(usize, usize),
+    /// Represents a media interaction (image, video, audio, voice, sticker or animation).
+    Media(InputMedia),
+    /// Represents a file (document) interaction.
+    File(InputMedia),
+    /// Represents a location interaction.
+    Location(InputLocation),
+    /// Represents a contact interaction.
+    Contact(InputContact),
+    /// Represents a reaction interaction.
+    Reaction(InputReaction),
 }
 
 impl InteractionType {
@@ -40,4 +57,38 @@ impl InteractionType {
             (None, None) => InteractionType::Text(message),
         }
     }
+
+    /// Returns the kind of the input for the interactions beyond text and buttons.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the `InputKind`, or `None` for text, button and carousel interactions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{InputKind, InputLocation, InteractionType};
+    ///
+    /// let interaction = InteractionType::Location(InputLocation::new(50.45, 30.52, None, None));
+    /// assert_eq!(interaction.input_kind(), Some(InputKind::Location));
+    /// ```
+    pub fn input_kind(&self) -> Option<InputKind> {
+        match self {
+            InteractionType::Media(media) | InteractionType::File(media) => {
+                Some(match media.media_type() {
+                    InputMediaType::Image => InputKind::Image,
+                    InputMediaType::Video => InputKind::Video,
+                    InputMediaType::Animation => InputKind::Animation,
+                    InputMediaType::Audio => InputKind::Audio,
+                    InputMediaType::Voice => InputKind::Voice,
+                    InputMediaType::Sticker => InputKind::Sticker,
+                    InputMediaType::Document => InputKind::Document,
+                })
+            },
+            InteractionType::Location(_) => Some(InputKind::Location),
+            InteractionType::Contact(_) => Some(InputKind::Contact),
+            InteractionType::Reaction(_) => Some(InputKind::Reaction),
+            _ => None
+        }
+    }
 }
\ No newline at end of file
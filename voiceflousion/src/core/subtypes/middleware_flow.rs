@@ -0,0 +1,13 @@
+/// Represents the decision of a middleware about an incoming update.
+///
+/// `MiddlewareFlow` defines whether the update continues to the next middleware and Voiceflow,
+/// is answered with a text without reaching Voiceflow, or is stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MiddlewareFlow {
+    /// Pass the update to the next middleware and Voiceflow.
+    Continue,
+    /// Reply to the user with the text without forwarding the update to Voiceflow.
+    Reply(String),
+    /// Stop the update without replying.
+    Stop,
+}
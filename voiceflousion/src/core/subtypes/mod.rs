@@ -3,12 +3,32 @@ mod sent_message;
 mod atomic_timestamp;
 mod http_client;
 mod bot_auth_token;
+mod custom_action_response;
+mod input_media;
+mod input_media_type;
+mod input_location;
+mod input_contact;
+mod input_reaction;
+mod input_kind;
+mod input_policy;
+mod middleware_flow;
+mod queue_overflow_policy;
+mod rate_limit;
+mod retry_policy;
+mod delivery_policy;
+mod rate_limiter;
+mod outbound_message;
 
 #[cfg(feature = "advanced")]
 pub use self::{
     sent_message::SentMessage,
     interaction_type::InteractionType,
     http_client::HttpClient,
+    input_media::InputMedia,
+    input_media_type::InputMediaType,
+    input_location::InputLocation,
+    input_contact::InputContact,
+    input_reaction::InputReaction,
 };
 
 #[cfg(not(feature = "advanced"))]
@@ -16,7 +36,22 @@ pub(crate) use self::{
     sent_message::SentMessage,
     interaction_type::InteractionType,
     http_client::HttpClient,
+    input_media::InputMedia,
+    input_media_type::InputMediaType,
+    input_location::InputLocation,
+    input_contact::InputContact,
+    input_reaction::InputReaction,
 };
 
 pub(super) use self::atomic_timestamp::AtomicTimestamp;
-pub use self::bot_auth_token::BotAuthToken;
\ No newline at end of file
+pub(super) use self::rate_limiter::RateLimiter;
+pub use self::bot_auth_token::BotAuthToken;
+pub use self::custom_action_response::CustomActionResponse;
+pub use self::input_kind::InputKind;
+pub use self::input_policy::InputPolicy;
+pub use self::middleware_flow::MiddlewareFlow;
+pub use self::queue_overflow_policy::QueueOverflowPolicy;
+pub use self::rate_limit::RateLimit;
+pub use self::retry_policy::RetryPolicy;
+pub use self::delivery_policy::DeliveryPolicy;
+pub use self::outbound_message::OutboundMessage;
\ No newline at end of file
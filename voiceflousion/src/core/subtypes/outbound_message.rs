@@ -0,0 +1,13 @@
+use crate::core::voiceflow::{State, VoiceflowMessage, VoiceflowRequestKind};
+
+/// Represents a message pushed to the user outside of the updates of the user.
+///
+/// `OutboundMessage` defines whether the message is generated by Voiceflow in response
+/// to a request, like an event or an intent, or is a ready message sent as is.
+#[derive(Debug, Clone)]
+pub enum OutboundMessage {
+    /// Request to the Voiceflow dialog with the optional state, the response of Voiceflow is sent to the user.
+    Request(VoiceflowRequestKind, Option<State>),
+    /// Ready message sent to the user as is.
+    Message(VoiceflowMessage),
+}
@@ -0,0 +1,14 @@
+/// Represents the policy of handling an update arriving to the full queue of the chat.
+///
+/// `QueueOverflowPolicy` is used by the queued mode of the client, where the updates of the same chat
+/// wait for the previous turn of the chat and are processed in the order they were received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueueOverflowPolicy {
+    /// Drop the oldest waiting update and queue the new one.
+    DropOldest,
+    /// Drop the new update.
+    DropNewest,
+    /// Merge the new text message into the latest waiting text message, so they are sent to Voiceflow as one request.
+    /// The new update is dropped if either of them is not a text message.
+    MergeTexts,
+}
\ No newline at end of file
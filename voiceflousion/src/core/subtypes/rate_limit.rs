@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+/// Represents the limit of the outbound requests, used as a token bucket.
+///
+/// `RateLimit` allows bursts of up to `requests` requests and refills them evenly over the `period`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of the requests sent during the period.
+    requests: u32,
+    /// The period of the limit in milliseconds.
+    period: u64,
+}
+
+impl RateLimit {
+    /// Creates a new `RateLimit`.
+    ///
+    /// # Parameters
+    ///
+    /// * `requests` - The maximum number of the requests sent during the period, at least 1.
+    /// * `period` - The period of the limit in milliseconds, at least 1.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RateLimit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    ///
+    /// // 30 requests per second
+    /// let rate_limit = RateLimit::new(30, 1000);
+    /// ```
+    pub fn new(requests: u32, period: u64) -> Self {
+        Self {
+            requests: requests.max(1),
+            period: period.max(1),
+        }
+    }
+
+    /// Returns the maximum number of the requests sent during the period.
+    ///
+    /// # Returns
+    ///
+    /// A `u32` representing the number of the requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    ///
+    /// let rate_limit = RateLimit::new(30, 1000);
+    /// assert_eq!(rate_limit.requests(), 30);
+    /// ```
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    /// Returns the period of the limit.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` representing the period in milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    ///
+    /// let rate_limit = RateLimit::new(30, 1000);
+    /// assert_eq!(rate_limit.period(), 1000);
+    /// ```
+    pub fn period(&self) -> u64 {
+        self.period
+    }
+
+    /// Returns the time of refilling one request.
+    ///
+    /// # Returns
+    ///
+    /// A `Duration` of refilling one request.
+    pub(crate) fn refill_interval(&self) -> Duration {
+        Duration::from_millis(self.period).div_f64(self.requests as f64)
+    }
+}
\ No newline at end of file
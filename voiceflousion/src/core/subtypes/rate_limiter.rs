@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Instant};
+use crate::core::subtypes::RateLimit;
+
+/// The token bucket of a rate limit.
+struct TokenBucket {
+    /// The number of the requests available for sending.
+    tokens: f64,
+    /// The time the tokens were refilled.
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a new full `TokenBucket`.
+    fn new(rate_limit: &RateLimit) -> Self {
+        Self {
+            tokens: rate_limit.requests() as f64,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Refills the tokens accumulated since the last refill.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the bucket is full.
+    fn refill(&mut self, rate_limit: &RateLimit, now: Instant) -> bool {
+        let capacity = rate_limit.requests() as f64;
+        let refilled = now.duration_since(self.refilled_at).as_secs_f64() / rate_limit.refill_interval().as_secs_f64();
+        self.tokens = (self.tokens + refilled).min(capacity);
+        self.refilled_at = now;
+        self.tokens >= capacity
+    }
+
+    /// Takes a token from the bucket.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the token is taken, or the time to wait for the next token.
+    fn try_take(&mut self, rate_limit: &RateLimit, now: Instant) -> Option<Duration> {
+        self.refill(rate_limit, now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(rate_limit.refill_interval().mul_f64(1.0 - self.tokens))
+        }
+    }
+}
+
+/// Limits the rate of the outbound requests of the bot and of every chat with the token buckets.
+pub(crate) struct RateLimiter {
+    /// The optional rate limit of all the requests.
+    global_rate_limit: Option<RateLimit>,
+    /// The optional rate limit of the requests to every chat.
+    chat_rate_limit: Option<RateLimit>,
+    /// The token bucket of all the requests.
+    global_bucket: Mutex<Option<TokenBucket>>,
+    /// The token buckets of the chats.
+    chat_buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// The number of the chat buckets, above which the full buckets are removed.
+    const CHAT_BUCKETS_CLEANUP_THRESHOLD: usize = 1024;
+
+    /// Creates a new `RateLimiter`.
+    ///
+    /// # Parameters
+    ///
+    /// * `global_rate_limit` - The optional rate limit of all the requests.
+    /// * `chat_rate_limit` - The optional rate limit of the requests to every chat.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RateLimiter`.
+    pub fn new(global_rate_limit: Option<RateLimit>, chat_rate_limit: Option<RateLimit>) -> Self {
+        Self {
+            global_bucket: Mutex::new(global_rate_limit.as_ref().map(TokenBucket::new)),
+            global_rate_limit,
+            chat_rate_limit,
+            chat_buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until the request to the chat is allowed by the rate limits.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The optional ID of the chat the request is sent to.
+    pub async fn acquire(&self, chat_id: Option<&str>) {
+        if let (Some(rate_limit), Some(chat_id)) = (&self.chat_rate_limit, chat_id) {
+            loop {
+                let wait = {
+                    let mut chat_buckets = self.chat_buckets.lock().await;
+                    let now = Instant::now();
+
+                    // Forget the chats which have not sent requests long enough to refill their buckets
+                    if chat_buckets.len() > Self::CHAT_BUCKETS_CLEANUP_THRESHOLD {
+                        chat_buckets.retain(|_, bucket| !bucket.refill(rate_limit, now));
+                    }
+
+                    chat_buckets.entry(chat_id.to_string())
+                        .or_insert_with(|| TokenBucket::new(rate_limit))
+                        .try_take(rate_limit, now)
+                };
+                match wait {
+                    Some(wait) => sleep(wait).await,
+                    None => break
+                }
+            }
+        }
+
+        if let Some(rate_limit) = &self.global_rate_limit {
+            loop {
+                let wait = match self.global_bucket.lock().await.as_mut() {
+                    Some(bucket) => bucket.try_take(rate_limit, Instant::now()),
+                    None => None
+                };
+                match wait {
+                    Some(wait) => sleep(wait).await,
+                    None => break
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
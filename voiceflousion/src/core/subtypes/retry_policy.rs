@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+/// Represents the policy of retrying the outbound requests throttled or failed by the client platform.
+///
+/// `RetryPolicy` retries the request with the exponential backoff, unless the platform
+/// asks to retry after the specific time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of the retries of the request.
+    max_retries: u32,
+    /// The delay before the first retry in milliseconds, doubled for every next retry.
+    base_delay: u64,
+    /// The maximum delay before a retry in milliseconds.
+    max_delay: u64,
+}
+
+impl RetryPolicy {
+    /// Creates a new `RetryPolicy`.
+    ///
+    /// If the platform asks to retry after a time longer than `max_delay`, the request is not retried.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_retries` - The maximum number of the retries of the request, 0 disables the retries.
+    /// * `base_delay` - The delay before the first retry in milliseconds, doubled for every next retry.
+    /// * `max_delay` - The maximum delay before a retry in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `RetryPolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RetryPolicy;
+    ///
+    /// let retry_policy = RetryPolicy::new(5, 200, 10000);
+    /// ```
+    pub fn new(max_retries: u32, base_delay: u64, max_delay: u64) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay: max_delay.max(base_delay),
+        }
+    }
+
+    /// Returns the maximum number of the retries of the request.
+    ///
+    /// # Returns
+    ///
+    /// A `u32` representing the number of the retries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RetryPolicy;
+    ///
+    /// let retry_policy = RetryPolicy::new(5, 200, 10000);
+    /// assert_eq!(retry_policy.max_retries(), 5);
+    /// ```
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay before the first retry.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` representing the delay in milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RetryPolicy;
+    ///
+    /// let retry_policy = RetryPolicy::new(5, 200, 10000);
+    /// assert_eq!(retry_policy.base_delay(), 200);
+    /// ```
+    pub fn base_delay(&self) -> u64 {
+        self.base_delay
+    }
+
+    /// Returns the maximum delay before a retry.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` representing the delay in milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RetryPolicy;
+    ///
+    /// let retry_policy = RetryPolicy::new(5, 200, 10000);
+    /// assert_eq!(retry_policy.max_delay(), 10000);
+    /// ```
+    pub fn max_delay(&self) -> u64 {
+        self.max_delay
+    }
+
+    /// Returns the delay before the retry of the exponential backoff.
+    ///
+    /// # Parameters
+    ///
+    /// * `attempt` - The number of the retry, starting from 0.
+    ///
+    /// # Returns
+    ///
+    /// A `Duration` of the delay, limited by the maximum delay.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self.base_delay.saturating_mul(2u64.saturating_pow(attempt));
+        Duration::from_millis(delay.min(self.max_delay))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, 500, 30000)
+    }
+}
\ No newline at end of file
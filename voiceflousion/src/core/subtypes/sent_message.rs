@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+use crate::core::traits::Versioned;
 use crate::core::voiceflow::dialog_blocks::VoiceflowButton;
 use crate::core::voiceflow::VoiceflowBlock;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
@@ -5,6 +7,7 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 /// Represents a sent message in the integration.
 ///
 /// `SentMessage` holds details of a message sent, including the associated block, message ID, and date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SentMessage {
     /// The block associated with the sent message, which may include text, buttons, cards, or carousels.
     block: VoiceflowBlock,
@@ -163,4 +166,6 @@ impl SentMessage {
             )),
         }
     }
-}
\ No newline at end of file
+}
+
+impl Versioned for SentMessage {}
\ No newline at end of file
@@ -1,10 +1,18 @@
+use std::future::Future;
 use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Instant;
 use async_trait::async_trait;
-use crate::core::base_structs::ClientBase;
-use crate::core::session_wrappers::LockedSession;
-use crate::core::subtypes::{InteractionType, SentMessage};
+use chrono::Utc;
+use serde_json::Value;
+use tracing::{debug, error, info_span, warn, Instrument};
+use crate::core::metrics::{increment_counter, record_histogram, set_gauge, ACTIVE_SESSIONS, ERRORS_TOTAL, SENDER_DURATION_SECONDS, UPDATES_TOTAL};
+use crate::core::base_structs::{ClientBase, UpdateBase};
+use crate::core::session_wrappers::{LockedSession, Session};
+use crate::core::subtypes::{CustomActionResponse, InputMedia, InputPolicy, InteractionType, MiddlewareFlow, OutboundMessage, SentMessage};
 use crate::core::traits::{Responder, Sender, Update};
-use crate::core::voiceflow::{State, VoiceflowBlock};
+use crate::core::voiceflow::{State, VoiceflowBlock, VoiceflowBlockStream, VoiceflowClient, VoiceflowMessage, VoiceflowRequest, VoiceflowRequestKind, VoiceflowSession};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowCustomAction, VoiceflowText};
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
 /// The `Client` trait adds methods for launching dialogs, sending messages,
@@ -50,27 +58,29 @@ pub trait Client: Sync + Send {
         // Get launch state for Voiceflow bot
         let state = self.client_base().launch_state().clone();
 
+        // Pass the request through the middleware chain
+        let request = VoiceflowRequest::new(locked_session.get_chat_id().clone(), VoiceflowRequestKind::Launch, Some(state));
+        let request = apply_request_middlewares(self.client_base(), request).await?;
+
+        // Stream the response block by block if streaming mode is enabled
+        if self.client_base().is_streaming() {
+            let stream = send_voiceflow_request_stream(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+            return self.send_voiceflow_stream(locked_session, None, stream).await;
+        }
+
         // Launch a new dialog with the Voiceflow client
-        let mut voiceflow_message = self.client_base().voiceflow_client().launch_dialog(voiceflow_session, state).await;
+        let voiceflow_message = send_voiceflow_request(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+
+        // Resume the dialog if it stopped on a handled custom action
+        let mut voiceflow_message = self.resolve_custom_actions(locked_session, voiceflow_message).await;
 
         // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
         if voiceflow_message.trim_end_block() {
             locked_session.set_last_interaction(None);
         }
 
-        let client_id = self.client_base().client_id();
-
-        // Send the Voiceflow message to the client and get the response
-        let response = self.client_base().sender().send_message(client_id, locked_session.get_chat_id(), voiceflow_message).await?;
-
-        // Retrieve the last message sent by the bot from the response
-        let bot_last_message = get_last_sent_message(&response);
-
-        // Update the session with the previous message
-        locked_session.set_previous_message(bot_last_message).await;
-
-        // Return the response
-        Ok(response)
+        // Send the Voiceflow message to the client
+        self.send_voiceflow_message(locked_session, voiceflow_message).await
     }
 
     /// Sends a message from Client to VoiceflowClient and sends the VoiceflowClient response to Client.
@@ -98,27 +108,89 @@ pub trait Client: Sync + Send {
         // Get the Voiceflow session associated with the locked session
         let voiceflow_session = locked_session.voiceflow_session();
 
+        // Pass the request through the middleware chain
+        let request = VoiceflowRequest::new(locked_session.get_chat_id().clone(), VoiceflowRequestKind::Text(message.clone()), state);
+        let request = apply_request_middlewares(self.client_base(), request).await?;
+
+        // Stream the response block by block if streaming mode is enabled
+        if self.client_base().is_streaming() {
+            let stream = send_voiceflow_request_stream(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+            return self.send_voiceflow_stream(locked_session, None, stream).await;
+        }
+
         // Send the message to the Voiceflow client
-        let mut voiceflow_message = self.client_base().voiceflow_client().send_message(voiceflow_session, state, message).await;
+        let voiceflow_message = send_voiceflow_request(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+
+        // Resume the dialog if it stopped on a handled custom action
+        let mut voiceflow_message = self.resolve_custom_actions(locked_session, voiceflow_message).await;
 
         // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
         if voiceflow_message.trim_end_block() {
             locked_session.set_last_interaction(None);
         }
 
-        let client_id = self.client_base().client_id();
+        // Send the Voiceflow message to the client
+        self.send_voiceflow_message(locked_session, voiceflow_message).await
+    }
 
-        // Send the Voiceflow message to the client and get the response
-        let response = self.client_base().sender().send_message(client_id, locked_session.get_chat_id(), voiceflow_message).await?;
+    /// Sends a media or a file from Client to VoiceflowClient and sends the VoiceflowClient response to Client.
+    ///
+    /// The media is sent as a text message (the caption or the type of the media) with the variables
+    /// describing the media, including the download URL resolved with `resolve_media_url()`.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `interaction_time` - The interaction time.
+    /// * `media` - The media sent by the user.
+    /// * `state` - The optional state for updating the dialog.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_media_to_voiceflow_dialog(&self, locked_session: &LockedSession, interaction_time: i64, media: &InputMedia, state: Option<State>) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        // Resolve the download URL of the media, the media is forwarded without it if the resolving fails
+        let url = self.resolve_media_url(media).await.unwrap_or_else(|error| {
+            warn!(%error, "Failed to resolve the media URL");
+            None
+        });
 
-        // Retrieve the last message sent by the bot from the response
-        let bot_last_message = get_last_sent_message(&response);
+        let state = add_input_variables(state, media.to_variables(url.as_ref()));
+        self.send_message_to_voiceflow_dialog(locked_session, interaction_time, &media.text(), Some(state)).await
+    }
 
-        // Update the session with the previous message
-        locked_session.set_previous_message(bot_last_message).await;
+    /// Resolves the download URL of a media sent by the user.
+    ///
+    /// The base implementation does not resolve the URL. Integrations override it to use their file APIs.
+    ///
+    /// # Parameters
+    ///
+    /// * `media` - The media sent by the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the optional download URL or a `VoiceflousionError` if the request fails.
+    async fn resolve_media_url(&self, _media: &InputMedia) -> VoiceflousionResult<Option<String>> {
+        Ok(None)
+    }
 
-        // Return the response
-        Ok(response)
+    /// Acknowledges the update on the client platform before interacting with Voiceflow.
+    ///
+    /// The base implementation does nothing. Integrations override it when the platform requires
+    /// the update to be acknowledged before the answer is sent (e.g. Discord interactions).
+    ///
+    /// # Parameters
+    ///
+    /// * `_update` - The update from the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the request fails.
+    async fn acknowledge_update(&self, _update: &Self::ClientUpdate<'_>) -> VoiceflousionResult<()> {
+        Ok(())
     }
 
     /// Sends a message from Client to choose a button in a VoiceflowClient and sends the VoiceflowClient response to Client.
@@ -146,7 +218,7 @@ pub trait Client: Sync + Send {
         // Get the Voiceflow session associated with the locked session
         let voiceflow_session = locked_session.voiceflow_session();
 
-        let voiceflow_message = {
+        let (voiceflow_message, url_text) = {
             let binding = locked_session.previous_message().await;
             let previous_message = binding.deref().as_ref()
                 .ok_or_else(|| VoiceflousionError::ClientRequestError("Client".to_string(),"Button cannot be handled in the start of the conversation".to_string()))?;
@@ -154,24 +226,159 @@ pub trait Client: Sync + Send {
 
             let payload = voiceflow_button.payload().clone();
 
-            // Send the button data to the Voiceflow client
-            let mut voiceflow_message = self.client_base().voiceflow_client().choose_button(voiceflow_session, state, payload).await;
+            // Pass the request through the middleware chain
+            let request = VoiceflowRequest::new(locked_session.get_chat_id().clone(), VoiceflowRequestKind::Button(payload), state);
+            let request = apply_request_middlewares(self.client_base(), request).await?;
 
-            if let Some(url_text) = voiceflow_button.get_url_text(){
-                voiceflow_message.shift_block(VoiceflowBlock::Text(url_text));
+            // Stream the response block by block if streaming mode is enabled
+            if self.client_base().is_streaming() {
+                let url_block = voiceflow_button.get_url_text().map(VoiceflowBlock::Text);
+                let stream = send_voiceflow_request_stream(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+                drop(binding);
+                return self.send_voiceflow_stream(locked_session, url_block, stream).await;
             }
 
-            // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
-            if voiceflow_message.trim_end_block() {
-                locked_session.set_last_interaction(None);
-            }
-            voiceflow_message
+            // Send the button data to the Voiceflow client
+            let voiceflow_message = send_voiceflow_request(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+
+            (voiceflow_message, voiceflow_button.get_url_text())
         };
 
+        // Resume the dialog if it stopped on a handled custom action
+        let mut voiceflow_message = self.resolve_custom_actions(locked_session, voiceflow_message).await;
+
+        if let Some(url_text) = url_text {
+            voiceflow_message.shift_block(VoiceflowBlock::Text(url_text));
+        }
+
+        // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
+        if voiceflow_message.trim_end_block() {
+            locked_session.set_last_interaction(None);
+        }
+
+        // Send the Voiceflow message to the client
+        self.send_voiceflow_message(locked_session, voiceflow_message).await
+    }
+
+    /// Sends the Voiceflow message to Client and stores the last sent message in the session.
+    ///
+    /// The message is passed through the middleware chain before it is sent,
+    /// and the result of the sender is passed to the middlewares after it.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `voiceflow_message` - The message from the Voiceflow client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_voiceflow_message(&self, locked_session: &LockedSession, mut voiceflow_message: VoiceflowMessage) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
         let client_id = self.client_base().client_id();
+        let chat_id = locked_session.get_chat_id();
+
+        // Pass the Voiceflow message through the middleware chain
+        apply_response_middlewares(self.client_base(), chat_id, &mut voiceflow_message).await?;
 
         // Send the Voiceflow message to the client and get the response
-        let response = self.client_base().sender().send_message(client_id, locked_session.get_chat_id(), voiceflow_message).await?;
+        let response = observe_sender_call(self.client_base(), chat_id, self.client_base().sender().send_message(client_id, chat_id, voiceflow_message)).await;
+        apply_sender_result_middlewares(self.client_base(), chat_id, &response).await;
+        let response = response?;
+
+        // Retrieve the last message sent by the bot from the response
+        let bot_last_message = get_last_sent_message(&response);
+
+        // Update the session with the previous message
+        locked_session.set_previous_message(bot_last_message).await;
+
+        // Return the response
+        Ok(response)
+    }
+
+    /// Sends the blocks of a `VoiceflowBlockStream` to Client as soon as they are received.
+    ///
+    /// The `End` block makes the session invalid and is not sent to Client. Every block is passed
+    /// through the middleware chain before it is sent. After the stream is finished, the result
+    /// of the sender is passed to the middlewares and the last sent message is stored in the session.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `first_block` - The optional block to send before the blocks of the stream.
+    /// * `stream` - The stream of blocks from the Voiceflow client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_voiceflow_stream(&self, locked_session: &LockedSession, first_block: Option<VoiceflowBlock>, mut stream: VoiceflowBlockStream) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        let client_base = self.client_base();
+        let chat_id = locked_session.get_chat_id();
+        let mut response = Vec::new();
+
+        let result: VoiceflousionResult<()> = async {
+            // Send the optional first block before the blocks of the stream
+            if let Some(block) = first_block {
+                response.extend(send_intercepted_block(client_base, chat_id, block).await?);
+            }
+
+            loop {
+                // The handled custom action the stream may stop on
+                let mut pending_custom_action = None;
+
+                // Send each block of the stream as soon as it is received
+                while let Some(block) = stream.next_block().await {
+                    // The custom action is followed by other blocks, so the dialog is not waiting for a path
+                    if let Some(custom_action) = pending_custom_action.take() {
+                        response.extend(send_intercepted_block(client_base, chat_id, VoiceflowBlock::CustomAction(custom_action)).await?);
+                    }
+                    match block {
+                        // If the block indicates the end of the dialog, clear the last interaction time to make session invalid
+                        VoiceflowBlock::End => {
+                            locked_session.set_last_interaction(None);
+                        },
+                        VoiceflowBlock::CustomAction(custom_action) if is_handled_custom_action(client_base, custom_action.name()) => {
+                            pending_custom_action = Some(custom_action);
+                        },
+                        block => {
+                            response.extend(send_intercepted_block(client_base, chat_id, block).await?);
+                        }
+                    }
+                }
+
+                // Resume the dialog if the stream stopped on a handled custom action
+                let custom_action = match pending_custom_action {
+                    Some(custom_action) => custom_action,
+                    None => break
+                };
+
+                // Hand the chat off to a human agent, the dialog waits for a path until the handoff is finished
+                if client_base.is_handoff_action(custom_action.name()) {
+                    if let Err(error) = self.hand_off_session(locked_session, Some(custom_action.payload().clone())).await {
+                        error!(%error, "Failed to hand off the chat");
+                    }
+                    break;
+                }
+
+                match self.handle_custom_action(locked_session, custom_action).await {
+                    Some(custom_action_response) => {
+                        let (path, payload, state) = custom_action_response.into_parts();
+                        stream = client_base.voiceflow_client().choose_path_stream(locked_session.voiceflow_session(), state, &path, payload).await;
+                    },
+                    None => break
+                }
+            }
+            Ok(())
+        }.await;
+
+        let response = result.map(|_| response);
+        apply_sender_result_middlewares(client_base, chat_id, &response).await;
+        let response = response?;
 
         // Retrieve the last message sent by the bot from the response
         let bot_last_message = get_last_sent_message(&response);
@@ -183,10 +390,91 @@ pub trait Client: Sync + Send {
         Ok(response)
     }
 
+    /// Resumes the Voiceflow dialog while it stops on custom actions with registered handlers.
+    ///
+    /// The custom action blocks the dialog stopped on are replaced with the blocks of the resumed dialog.
+    /// If the dialog stops on the handoff action of the client, the chat is handed off to a human agent.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `voiceflow_message` - The message from the Voiceflow client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowMessage` with the blocks of the message and the blocks of the resumed dialog.
+    async fn resolve_custom_actions(&self, locked_session: &LockedSession, mut voiceflow_message: VoiceflowMessage) -> VoiceflowMessage {
+        let mut resolved_message = VoiceflowMessage::default();
+
+        loop {
+            // The dialog waits for a path only if it stopped on a custom action
+            let is_handled = voiceflow_message.last_custom_action()
+                .is_some_and(|custom_action| is_handled_custom_action(self.client_base(), custom_action.name()));
+            if !is_handled {
+                break;
+            }
+
+            let custom_action = match voiceflow_message.trim_custom_action_block() {
+                Some(custom_action) => custom_action,
+                None => break
+            };
+            resolved_message.append(voiceflow_message);
+
+            // Hand the chat off to a human agent, the dialog waits for a path until the handoff is finished
+            if self.client_base().is_handoff_action(custom_action.name()) {
+                if let Err(error) = self.hand_off_session(locked_session, Some(custom_action.payload().clone())).await {
+                    error!(%error, "Failed to hand off the chat");
+                }
+                return resolved_message
+            }
+
+            // Resume the dialog with the path returned by the handler
+            voiceflow_message = match self.handle_custom_action(locked_session, custom_action).await {
+                Some(custom_action_response) => {
+                    let (path, payload, state) = custom_action_response.into_parts();
+                    self.client_base().voiceflow_client().choose_path(locked_session.voiceflow_session(), state, &path, payload).await
+                },
+                None => return resolved_message
+            };
+        }
+
+        resolved_message.append(voiceflow_message);
+        resolved_message
+    }
+
+    /// Calls the registered handler of the custom action.
+    ///
+    /// If the handler fails, the default path of the custom action is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `custom_action` - The custom action the dialog stopped on.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the `CustomActionResponse` to resume the dialog with, or `None` if the dialog cannot be resumed.
+    async fn handle_custom_action(&self, locked_session: &LockedSession, custom_action: VoiceflowCustomAction) -> Option<CustomActionResponse> {
+        let handler = self.client_base().custom_action_handler(custom_action.name())?.clone();
+
+        match handler(custom_action.payload().clone(), locked_session).await {
+            Ok(custom_action_response) => Some(custom_action_response),
+            Err(error) => {
+                warn!(%error, custom_action = %custom_action.name(), "Custom action handler failed");
+                custom_action.default_path().map(|path| CustomActionResponse::new(path.clone()))
+            }
+        }
+    }
+
     /// Interacts with the client based on the provided update.
     ///
-    /// This method determines the type of interaction (button press, text message or carousel switch),
+    /// This method determines the type of interaction (button press, text message, carousel switch or another input),
+    /// applies the configured `InputPolicy` to the inputs beyond text and buttons,
     /// processes the interaction with the Voiceflow client, and updates the session state accordingly.
+    /// The interaction is traced in the `interaction` span, the updates, errors and active sessions are recorded into the metrics.
     ///
     /// **This method has a base implementation for sending messages. Modify it only if you
     /// know what you are doing or have devised a better approach.**
@@ -200,57 +488,436 @@ pub trait Client: Sync + Send {
     ///
     /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
     async fn interact_with_client(&self, update: Self::ClientUpdate<'_>, update_state: Option<State>) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
-        if !self.client_base().is_active(){
-            return Err(VoiceflousionError::ClientRequestError(format!("Client {} is deactivated!", self.client_base().client_id()), "".to_string()))
-        }
-        // Get the interaction time from the update
-        let interaction_time = update.interaction_time();
-
-        // Check if a session exists for the given chat_id
-        if let Some(telegram_session) = self.client_base().sessions().get_session(update.chat_id()).await {
+        let client_id = self.client_base().client_id();
+        let span = info_span!("interaction", client_id = %client_id, chat_id = %update.chat_id(), update_id = %update.update_id());
+        increment_counter(UPDATES_TOTAL, &[("client_id", client_id.clone())]).await;
 
-            // Lock the session for safe access
-            let locked_session = LockedSession::try_from_session(&telegram_session)?;
+        let result: VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> = async move {
+            if !self.client_base().is_active(){
+                return Err(VoiceflousionError::ClientRequestError(format!("Client {} is deactivated!", self.client_base().client_id()), "".to_string()))
+            }
 
-            // Check if the update is deprecated
-            if let Some(message) = locked_session.previous_message().await.deref() {
-                update.is_deprecated(message.date())?
+            // Skip the update if it was already processed
+            if !is_new_update(self.client_base(), update.update_id()).await {
+                debug!("Skipping the redelivered update");
+                return Ok(Vec::new())
             }
 
-            // Handle the interaction based on its type
-            match update.interaction_type() {
-                // If it is a  regular button press
-                InteractionType::Button(button_index) => {
-                    // Handle the button interaction
-                    self.choose_button_in_voiceflow_dialog(&locked_session, interaction_time, update_state, button_index.clone()).await
-                },
-                // If it is a text message
-                InteractionType::Text(message) => {
-                    // Handle the text message
-                    self.send_message_to_voiceflow_dialog(&locked_session, interaction_time, message, update_state).await
+            // Acknowledge the update on the client platform before answering it
+            self.acknowledge_update(&update).await?;
+
+            // Pass the update through the middleware chain
+            match apply_update_middlewares(self.client_base(), &update).await? {
+                MiddlewareFlow::Continue => {},
+                MiddlewareFlow::Reply(text) => {
+                    // Reply with the text without forwarding the update to Voiceflow
+                    let response = self.client_base().sender().send_text(self.client_base().client_id(), VoiceflowText::new(text), update.chat_id()).await?;
+                    return Ok(vec![response])
                 },
-                // If it is a carousel switch button press
-                InteractionType::CarouselSwitch(switch_direction) => {
-                    // Handle carousel switch
-                    self.handle_carousel_switch(&locked_session, interaction_time, switch_direction.clone()).await
-                }
+                MiddlewareFlow::Stop => return Ok(Vec::new())
+            }
 
+            // Apply the policy of the input kind for the inputs beyond text and buttons
+            if let Some(kind) = update.interaction_type().input_kind() {
+                match self.client_base().input_policy(kind) {
+                    InputPolicy::Forward => {},
+                    InputPolicy::Reply(text) => {
+                        // Reply with the fallback text without forwarding the input to Voiceflow
+                        let response = self.client_base().sender().send_text(self.client_base().client_id(), VoiceflowText::new(text), update.chat_id()).await?;
+                        return Ok(vec![response])
+                    },
+                    InputPolicy::Ignore => return Ok(Vec::new())
+                }
             }
-        } else {
 
-            // If no session exists, create a new session and launch the dialog
-            let telegram_session = self.client_base().sessions().add_session(update.chat_id().clone()).await;
-            let locked_session = LockedSession::try_from_session(&telegram_session)?;
+            // Get the interaction time from the update
+            let interaction_time = update.interaction_time();
+
+            // Check if a session exists for the given chat_id
+            if let Some(telegram_session) = self.client_base().sessions().get_session(update.chat_id()).await {
+
+                // Lock the session for safe access
+                let locked_session = match lock_session(self.client_base(), &telegram_session, &update).await? {
+                    Some(locked_session) => locked_session,
+                    None => {
+                        debug!("Update is dropped from the chat queue");
+                        return Ok(Vec::new())
+                    }
+                };
+
+                // Check if the update is deprecated, the queued updates are answered in order regardless of the previous turns
+                if !locked_session.is_queued() {
+                    if let Some(message) = locked_session.previous_message().await.deref() {
+                        update.is_deprecated(message.date())?
+                    }
+                }
+
+                // Pass the update to the human agent if the chat is handed off
+                if locked_session.is_in_handoff() {
+                    locked_session.set_last_interaction(Some(interaction_time));
+                    let result = match self.client_base().handoff_handler() {
+                        Some(handoff_handler) => handoff_handler.on_user_update(update.chat_id(), &update).await.map(|_| Vec::new()),
+                        None => Ok(Vec::new())
+                    };
+
+                    // Save the updated session state into the session store
+                    self.client_base().sessions().persist_session(&locked_session).await;
+                    return result
+                }
+
+                // Handle the interaction based on its type
+                let result = match update.interaction_type() {
+                    // If it is a  regular button press
+                    InteractionType::Button(button_index) => {
+                        // Handle the button interaction
+                        self.choose_button_in_voiceflow_dialog(&locked_session, interaction_time, update_state, button_index.clone()).await
+                    },
+                    // If it is a text message
+                    InteractionType::Text(message) => {
+                        // Handle the text message together with the texts merged from the chat queue
+                        let message = merge_texts(message, locked_session.merged_texts());
+                        self.send_message_to_voiceflow_dialog(&locked_session, interaction_time, &message, update_state).await
+                    },
+                    // If it is a carousel switch button press
+                    InteractionType::CarouselSwitch(switch_direction) => {
+                        // Handle carousel switch
+                        self.handle_carousel_switch(&locked_session, interaction_time, switch_direction.clone()).await
+                    },
+                    // If it is a button press on a specific carousel card
+                    InteractionType::CarouselButton(card_index, button_index) => {
+                        // Select the card of the button and handle the button interaction
+                        match select_carousel_card(&locked_session, *card_index).await {
+                            Ok(()) => self.choose_button_in_voiceflow_dialog(&locked_session, interaction_time, update_state, *button_index).await,
+                            Err(error) => Err(error)
+                        }
+                    },
+                    // If it is a media or a file
+                    InteractionType::Media(media) | InteractionType::File(media) => {
+                        // Handle the media with its download URL
+                        self.send_media_to_voiceflow_dialog(&locked_session, interaction_time, media, update_state).await
+                    },
+                    // If it is a location
+                    InteractionType::Location(location) => {
+                        // Handle the location as a text message with the location variables
+                        let state = add_input_variables(update_state, location.to_variables());
+                        self.send_message_to_voiceflow_dialog(&locked_session, interaction_time, &location.text(), Some(state)).await
+                    },
+                    // If it is a contact
+                    InteractionType::Contact(contact) => {
+                        // Handle the contact as a text message with the contact variables
+                        let state = add_input_variables(update_state, contact.to_variables());
+                        self.send_message_to_voiceflow_dialog(&locked_session, interaction_time, &contact.text(), Some(state)).await
+                    },
+                    // If it is a reaction
+                    InteractionType::Reaction(reaction) => {
+                        // Handle the reaction as a text message with the reaction variables
+                        let state = add_input_variables(update_state, reaction.to_variables());
+                        self.send_message_to_voiceflow_dialog(&locked_session, interaction_time, &reaction.text(), Some(state)).await
+                    }
+
+                };
+
+                // Save the updated session state into the session store
+                self.client_base().sessions().persist_session(&locked_session).await;
+                result
+            } else {
 
-            // Check if the update is deprecated
-            if let Some(message) = locked_session.previous_message().await.deref() {
-                 update.is_deprecated(message.date())?
+                // If no session exists, create a new session and launch the dialog
+                let telegram_session = self.client_base().sessions().add_session(update.chat_id().clone()).await;
+                let locked_session = match lock_session(self.client_base(), &telegram_session, &update).await? {
+                    Some(locked_session) => locked_session,
+                    None => {
+                        debug!("Update is dropped from the chat queue");
+                        return Ok(Vec::new())
+                    }
+                };
+
+                // Check if the update is deprecated
+                if !locked_session.is_queued() {
+                    if let Some(message) = locked_session.previous_message().await.deref() {
+                         update.is_deprecated(message.date())?
+                    }
+                }
+
+                let result = self.launch_voiceflow_dialog(&locked_session, interaction_time).await;
+
+                // Save the updated session state into the session store
+                self.client_base().sessions().persist_session(&locked_session).await;
+                result
             }
+        }.instrument(span).await;
+
+        // Record the failure of the interaction and the sessions of the client
+        if let Err(error) = &result {
+            warn!(client_id = %client_id, %error, "Interaction failed");
+            increment_counter(ERRORS_TOTAL, &[("client_id", client_id.clone()), ("error", error.kind().to_string())]).await;
+        }
+        let sessions_count = self.client_base().sessions().sessions_count().await;
+        set_gauge(ACTIVE_SESSIONS, &[("client_id", client_id.clone())], sessions_count as f64).await;
+
+        result
+    }
+
+
+    /// Sends a path selection from Client to VoiceflowClient and sends the VoiceflowClient response to Client.
+    ///
+    /// The path is chosen in the dialog stopped on a custom action, e.g. when the chat returns from a human agent.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `interaction_time` - The interaction time.
+    /// * `path` - The name of the path.
+    /// * `state` - The optional state for updating the dialog.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn choose_path_in_voiceflow_dialog(&self, locked_session: &LockedSession, interaction_time: i64, path: &String, state: Option<State>) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        // Set the last interaction time for the session
+        locked_session.set_last_interaction(Some(interaction_time));
+
+        // Get the Voiceflow session associated with the locked session
+        let voiceflow_session = locked_session.voiceflow_session();
+
+        // Stream the response block by block if streaming mode is enabled
+        if self.client_base().is_streaming() {
+            let stream = self.client_base().voiceflow_client().choose_path_stream(voiceflow_session, state, path, None).await;
+            return self.send_voiceflow_stream(locked_session, None, stream).await;
+        }
+
+        // Send the path to the Voiceflow client
+        let voiceflow_message = self.client_base().voiceflow_client().choose_path(voiceflow_session, state, path, None).await;
+
+        // Resume the dialog if it stopped on a handled custom action
+        let mut voiceflow_message = self.resolve_custom_actions(locked_session, voiceflow_message).await;
+
+        // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
+        if voiceflow_message.trim_end_block() {
+            locked_session.set_last_interaction(None);
+        }
+
+        // Send the Voiceflow message to the client
+        self.send_voiceflow_message(locked_session, voiceflow_message).await
+    }
+
+    /// Hands the chat of the locked session off to a human agent.
+    ///
+    /// The `HandoffHandler` of the client is notified before the session is switched to the handoff mode.
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session of the chat.
+    /// * `payload` - The optional payload of the handoff.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the handler rejects the handoff.
+    async fn hand_off_session(&self, locked_session: &LockedSession, payload: Option<Value>) -> VoiceflousionResult<()> {
+        if let Some(handoff_handler) = self.client_base().handoff_handler() {
+            handoff_handler.on_handoff_started(locked_session.get_chat_id(), payload).await?;
+        }
+        locked_session.set_handoff(true);
+
+        // Keep the session valid while the chat waits for the agent
+        locked_session.set_last_interaction(Some(Utc::now().timestamp()));
+        Ok(())
+    }
+
+    /// Pauses the Voiceflow bot for the chat and hands it off to a human agent.
+    ///
+    /// While the chat is in handoff, the updates of the user are passed to the `HandoffHandler` of the client.
+    /// The session is created if the chat has no session yet.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `payload` - The optional payload of the handoff passed to the `HandoffHandler`.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the session is busy or the handler rejects the handoff.
+    async fn start_handoff(&self, chat_id: &String, payload: Option<Value>) -> VoiceflousionResult<()> {
+        let sessions = self.client_base().sessions();
+        let session = match sessions.get_session(chat_id).await {
+            Some(session) => session,
+            None => sessions.add_session(chat_id.clone()).await
+        };
+        let locked_session = LockedSession::try_from_session(&session)?;
+
+        let result = self.hand_off_session(&locked_session, payload).await;
+
+        // Save the updated session state into the session store
+        sessions.persist_session(&locked_session).await;
+        result
+    }
+
+    /// Sends the reply of a human agent to the chat in handoff through the sender of the client.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `text` - The text of the reply.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `SenderResponder` or a `VoiceflousionError` if the chat isn't in handoff or the request fails.
+    async fn send_operator_message(&self, chat_id: &String, text: String) -> VoiceflousionResult<<Self::ClientSender<'_> as Sender>::SenderResponder> {
+        get_handoff_session(self.client_base(), chat_id).await?;
+        self.client_base().sender().send_text(self.client_base().client_id(), VoiceflowText::new(text), chat_id).await
+    }
+
+    /// Returns the chat from a human agent to the Voiceflow bot.
+    ///
+    /// If the path is provided, the dialog stopped on the handoff action is resumed with it
+    /// and the response of Voiceflow is sent to the user. Otherwise, the bot answers the next update of the user.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `path` - The optional name of the path to resume the dialog with.
+    /// * `state` - The optional state for updating the dialog.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the chat isn't in handoff or the request fails.
+    async fn finish_handoff(&self, chat_id: &String, path: Option<String>, state: Option<State>) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        let session = get_handoff_session(self.client_base(), chat_id).await?;
+        let locked_session = LockedSession::try_from_session(&session)?;
 
-            self.launch_voiceflow_dialog(&locked_session, interaction_time).await
+        if let Some(handoff_handler) = self.client_base().handoff_handler() {
+            handoff_handler.on_handoff_finished(chat_id).await?;
         }
+        locked_session.set_handoff(false);
+
+        let result = match path {
+            Some(path) => self.choose_path_in_voiceflow_dialog(&locked_session, Utc::now().timestamp(), &path, state).await,
+            None => Ok(Vec::new())
+        };
+
+        // Save the updated session state into the session store
+        self.client_base().sessions().persist_session(&locked_session).await;
+        result
     }
 
+    /// Sends a request from the backend to VoiceflowClient and sends the VoiceflowClient response to Client.
+    ///
+    /// The request is passed through the middleware chain like the requests made with the user's input.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `interaction_time` - The interaction time.
+    /// * `request` - The request to the Voiceflow dialog.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_request_to_voiceflow_dialog(&self, locked_session: &LockedSession, interaction_time: i64, request: VoiceflowRequest) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        // Set the last interaction time for the session
+        locked_session.set_last_interaction(Some(interaction_time));
+
+        // Get the Voiceflow session associated with the locked session
+        let voiceflow_session = locked_session.voiceflow_session();
+
+        // Pass the request through the middleware chain
+        let request = apply_request_middlewares(self.client_base(), request).await?;
+
+        // Stream the response block by block if streaming mode is enabled
+        if self.client_base().is_streaming() {
+            let stream = send_voiceflow_request_stream(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+            return self.send_voiceflow_stream(locked_session, None, stream).await;
+        }
+
+        // Send the request to the Voiceflow client
+        let voiceflow_message = send_voiceflow_request(self.client_base().voiceflow_client(), voiceflow_session, request).await;
+
+        // Resume the dialog if it stopped on a handled custom action
+        let mut voiceflow_message = self.resolve_custom_actions(locked_session, voiceflow_message).await;
+
+        // If the Voiceflow message indicates the end of the block, clear the last interaction time to make session invalid
+        if voiceflow_message.trim_end_block() {
+            locked_session.set_last_interaction(None);
+        }
+
+        // Send the Voiceflow message to the client
+        self.send_voiceflow_message(locked_session, voiceflow_message).await
+    }
+
+    /// Pushes a message to the chat outside of the updates of the user, e.g. to notify the user that the order is shipped.
+    ///
+    /// The request is sent to Voiceflow and its response is sent to the user, or the ready message is sent as is.
+    /// The message is sent through the locked session of the chat and stored as its previous message,
+    /// so the buttons of the pushed message work when they are pressed. The session is created if the chat has no session yet.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `message` - The message to push.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the session is busy,
+    /// the chat is handed off to a human agent for the Voiceflow requests, or the request fails.
+    async fn push_message(&self, chat_id: &String, message: OutboundMessage) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        let client_id = self.client_base().client_id();
+        let span = info_span!("push", client_id = %client_id, chat_id = %chat_id);
+
+        let result: VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> = async move {
+            if !self.client_base().is_active(){
+                return Err(VoiceflousionError::ClientRequestError(format!("Client {} is deactivated!", self.client_base().client_id()), "".to_string()))
+            }
+
+            let sessions = self.client_base().sessions();
+            let session = match sessions.get_session(chat_id).await {
+                Some(session) => session,
+                None => sessions.add_session(chat_id.clone()).await
+            };
+            let locked_session = lock_outbound_session(self.client_base(), &session).await?;
+            let interaction_time = Utc::now().timestamp();
+
+            let result = match message {
+                OutboundMessage::Request(_, _) if locked_session.is_in_handoff() => {
+                    Err(VoiceflousionError::ValidationError("Client".to_string(), format!("Chat {} is handed off to a human agent", chat_id)))
+                },
+                OutboundMessage::Request(kind, state) => {
+                    // The dialog is launched with the launch state of the client if no state is provided
+                    let state = match (&kind, state) {
+                        (VoiceflowRequestKind::Launch, None) => Some(self.client_base().launch_state().clone()),
+                        (_, state) => state
+                    };
+                    let request = VoiceflowRequest::new(chat_id.clone(), kind, state);
+                    self.send_request_to_voiceflow_dialog(&locked_session, interaction_time, request).await
+                },
+                OutboundMessage::Message(voiceflow_message) => {
+                    // Keep the session valid for pressing the buttons of the message
+                    locked_session.set_last_interaction(Some(interaction_time));
+                    self.send_voiceflow_message(&locked_session, voiceflow_message).await
+                }
+            };
+
+            // Save the updated session state into the session store
+            sessions.persist_session(&locked_session).await;
+            result
+        }.instrument(span).await;
+
+        // Record the failure of the push
+        if let Err(error) = &result {
+            warn!(client_id = %client_id, chat_id = %chat_id, %error, "Push failed");
+            increment_counter(ERRORS_TOTAL, &[("client_id", client_id.clone()), ("error", error.kind().to_string())]).await;
+        }
+
+        result
+    }
 
     /// Handles carousel switch interactions on the client.
     ///
@@ -285,4 +952,349 @@ pub fn get_last_sent_message<R: Responder>(response: &[R]) -> Option<SentMessage
     else{
         None
     }
-}
\ No newline at end of file
+}
+
+/// Adds the variables describing the user's input to the state.
+///
+/// # Parameters
+///
+/// * `state` - The optional state for updating the dialog.
+/// * `variables` - The variables describing the input.
+///
+/// # Returns
+///
+/// The `State` with the input variables.
+fn add_input_variables(state: Option<State>, variables: Value) -> State {
+    let mut state = state.unwrap_or_default();
+    state.push(variables);
+    state
+}
+
+/// Selects the card of the carousel in the previous message, keeping the mark of the carousel.
+///
+/// # Parameters
+///
+/// * `locked_session` - The locked session for the interaction.
+/// * `card_index` - The index of the card to select.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the previous message
+/// is not a carousel or the card index is out of bounds.
+async fn select_carousel_card(locked_session: &LockedSession<'_>, card_index: usize) -> VoiceflousionResult<()> {
+    let binding = locked_session.previous_message().await;
+    let previous_message = binding.deref().as_ref()
+        .ok_or_else(|| VoiceflousionError::ClientRequestError("Client".to_string(), "Carousel button cannot be handled in the start of the conversation".to_string()))?;
+
+    match previous_message.block() {
+        VoiceflowBlock::Carousel(carousel) if card_index < carousel.len() => {
+            carousel.set_selected_card(card_index, carousel.get_selected_mark());
+            Ok(())
+        },
+        VoiceflowBlock::Carousel(_) => Err(VoiceflousionError::ValidationError(
+            "Client".to_string(),
+            format!("Carousel card index {} out of bounds", card_index),
+        )),
+        _ => Err(VoiceflousionError::ValidationError(
+            "Client".to_string(),
+            "There is no carousel in the previous message".to_string(),
+        ))
+    }
+}
+
+/// Locks the session for the interaction with the update.
+///
+/// In the queued mode of the client the update waits for the previous turns of the chat,
+/// otherwise the lock fails if the previous turn is running.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the chat queue settings.
+/// * `session` - The session of the chat.
+/// * `update` - The update from the client.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing an `Option` with the `LockedSession`, `None` if the update was dropped from the chat queue,
+/// or a `VoiceflousionError` if the session is locked and the queued mode is disabled.
+async fn lock_session<'g, H: Sender>(client_base: &ClientBase<H>, session: &'g Arc<Session>, update: &UpdateBase) -> VoiceflousionResult<Option<LockedSession<'g>>> {
+    match client_base.chat_queue() {
+        Some((depth, overflow_policy)) => {
+            let text = match update.interaction_type() {
+                InteractionType::Text(text) => Some(text),
+                _ => None
+            };
+            Ok(LockedSession::queue_from_session(session, text, *depth, overflow_policy).await)
+        },
+        None => LockedSession::try_from_session(session).map(Some)
+    }
+}
+
+/// Locks the session of the chat for a message pushed outside of the updates of the user.
+///
+/// If the queued mode is enabled, the push waits for the previous turns of the chat in the queue.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the chat queue settings.
+/// * `session` - The session of the chat.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing the `LockedSession` or a `VoiceflousionError` if the session is locked
+/// and the queued mode is disabled, or the push was dropped from the chat queue.
+pub(crate) async fn lock_outbound_session<'g, H: Sender>(client_base: &ClientBase<H>, session: &'g Arc<Session>) -> VoiceflousionResult<LockedSession<'g>> {
+    match client_base.chat_queue() {
+        Some((depth, overflow_policy)) => {
+            LockedSession::queue_from_session(session, None, *depth, overflow_policy).await
+                .ok_or_else(|| VoiceflousionError::SessionLockError(session.get_chat_id().clone()))
+        },
+        None => LockedSession::try_from_session(session)
+    }
+}
+
+/// Joins the text of the message with the texts of the next messages merged from the chat queue.
+///
+/// # Parameters
+///
+/// * `message` - The text of the message.
+/// * `merged_texts` - The texts of the next messages.
+///
+/// # Returns
+///
+/// A `String` with the texts separated by line breaks.
+fn merge_texts(message: &String, merged_texts: &[String]) -> String {
+    std::iter::once(message).chain(merged_texts.iter()).cloned().collect::<Vec<String>>().join("\n")
+}
+
+/// Marks the update as processed in the deduplication store of the client.
+///
+/// If the store cannot be accessed, the update is considered new, so the updates aren't lost while the store is unavailable.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the deduplication store.
+/// * `update_id` - The ID of the update.
+///
+/// # Returns
+///
+/// `true` if the update wasn't processed before or the client has no deduplication store, `false` if it is a redelivery.
+async fn is_new_update<H: Sender>(client_base: &ClientBase<H>, update_id: &String) -> bool {
+    let deduplication_store = match client_base.deduplication_store() {
+        Some(deduplication_store) if !update_id.is_empty() => deduplication_store,
+        _ => return true
+    };
+
+    match deduplication_store.mark_processed(client_base.client_id(), update_id).await {
+        Ok(is_new) => is_new,
+        Err(error) => {
+            warn!(%error, "Failed to check the update in the deduplication store");
+            true
+        }
+    }
+}
+
+/// Passes the update through the middleware chain.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the middlewares.
+/// * `update` - The update from the client.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing the first `MiddlewareFlow` which is not `Continue`, or `Continue` if all the middlewares passed the update.
+async fn apply_update_middlewares<H: Sender>(client_base: &ClientBase<H>, update: &UpdateBase) -> VoiceflousionResult<MiddlewareFlow> {
+    for middleware in client_base.middlewares() {
+        match middleware.on_update(update).await? {
+            MiddlewareFlow::Continue => continue,
+            flow => return Ok(flow)
+        }
+    }
+    Ok(MiddlewareFlow::Continue)
+}
+
+/// Passes the request to Voiceflow through the middleware chain.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the middlewares.
+/// * `request` - The request to Voiceflow.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing the request rewritten by the middlewares.
+async fn apply_request_middlewares<H: Sender>(client_base: &ClientBase<H>, mut request: VoiceflowRequest) -> VoiceflousionResult<VoiceflowRequest> {
+    for middleware in client_base.middlewares() {
+        middleware.on_voiceflow_request(&mut request).await?;
+    }
+    Ok(request)
+}
+
+/// Passes the response of Voiceflow through the middleware chain.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the middlewares.
+/// * `chat_id` - The chat ID of the user.
+/// * `voiceflow_message` - The message from the Voiceflow client.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` indicating whether all the middlewares passed the message.
+async fn apply_response_middlewares<H: Sender>(client_base: &ClientBase<H>, chat_id: &String, voiceflow_message: &mut VoiceflowMessage) -> VoiceflousionResult<()> {
+    for middleware in client_base.middlewares() {
+        middleware.on_voiceflow_response(chat_id, voiceflow_message).await?;
+    }
+    Ok(())
+}
+
+/// Passes the result of the sender to the middleware chain.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the middlewares.
+/// * `chat_id` - The chat ID of the user.
+/// * `result` - The result of the sender.
+async fn apply_sender_result_middlewares<H: Sender>(client_base: &ClientBase<H>, chat_id: &String, result: &VoiceflousionResult<Vec<H::SenderResponder>>) {
+    if client_base.middlewares().is_empty() {
+        return;
+    }
+
+    let sent_messages = result.as_ref()
+        .map(|response| response.iter().map(|responder| responder.create_sent_message()).collect::<Vec<SentMessage>>());
+
+    for middleware in client_base.middlewares() {
+        middleware.on_sender_result(chat_id, sent_messages.as_deref().map_err(|error| *error)).await;
+    }
+}
+
+/// Sends a block of the streamed Voiceflow response passed through the middleware chain.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the sender and the middlewares.
+/// * `chat_id` - The chat ID of the user.
+/// * `block` - The block to send.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing a vector of `SenderResponder` or a `VoiceflousionError` if the request fails.
+async fn send_intercepted_block<H: Sender>(client_base: &ClientBase<H>, chat_id: &String, block: VoiceflowBlock) -> VoiceflousionResult<Vec<H::SenderResponder>> {
+    let client_id = client_base.client_id();
+
+    if client_base.middlewares().is_empty() {
+        let response = observe_sender_call(client_base, chat_id, client_base.sender().send_block(client_id, block, chat_id)).await?;
+        return Ok(response.into_iter().collect());
+    }
+
+    // Wrap the block into a message for the middlewares
+    let mut voiceflow_message = VoiceflowMessage::default();
+    voiceflow_message.add_block(block);
+    apply_response_middlewares(client_base, chat_id, &mut voiceflow_message).await?;
+
+    observe_sender_call(client_base, chat_id, client_base.sender().send_message(client_id, chat_id, voiceflow_message)).await
+}
+
+/// Traces the call of the sender in the `sender` span and records its duration into the metrics.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the sender.
+/// * `chat_id` - The chat ID of the user.
+/// * `sender_call` - The call of the sender.
+///
+/// # Returns
+///
+/// The result of the sender call.
+async fn observe_sender_call<H: Sender, T>(client_base: &ClientBase<H>, chat_id: &String, sender_call: impl Future<Output = VoiceflousionResult<T>> + Send) -> VoiceflousionResult<T> {
+    let client_id = client_base.client_id();
+    let span = info_span!("sender", client_id = %client_id, chat_id = %chat_id);
+    let started = Instant::now();
+
+    let result = sender_call.instrument(span).await;
+
+    record_histogram(SENDER_DURATION_SECONDS, &[("client_id", client_id.clone())], started.elapsed().as_secs_f64()).await;
+    if let Err(error) = &result {
+        error!(client_id = %client_id, chat_id = %chat_id, %error, "Sender call failed");
+    }
+    result
+}
+
+/// Sends the request to the Voiceflow client.
+///
+/// # Parameters
+///
+/// * `voiceflow_client` - The Voiceflow client.
+/// * `voiceflow_session` - The Voiceflow session of the user.
+/// * `request` - The request to send.
+///
+/// # Returns
+///
+/// A `VoiceflowMessage` containing the response from the Voiceflow API.
+async fn send_voiceflow_request(voiceflow_client: &VoiceflowClient, voiceflow_session: &VoiceflowSession, request: VoiceflowRequest) -> VoiceflowMessage {
+    match request.into_parts() {
+        (VoiceflowRequestKind::Launch, state) => voiceflow_client.launch_dialog(voiceflow_session, state.unwrap_or_default()).await,
+        (VoiceflowRequestKind::Text(text), state) => voiceflow_client.send_message(voiceflow_session, state, &text).await,
+        (VoiceflowRequestKind::Button(payload), state) => voiceflow_client.choose_button(voiceflow_session, state, payload).await,
+        (VoiceflowRequestKind::Event(event, payload), state) => voiceflow_client.send_event(voiceflow_session, state, &event, payload).await,
+        (VoiceflowRequestKind::Intent(intent), state) => voiceflow_client.send_intent(voiceflow_session, state, &intent).await,
+        (VoiceflowRequestKind::Path(path, payload), state) => voiceflow_client.choose_path(voiceflow_session, state, &path, payload).await
+    }
+}
+
+/// Sends the request to the Voiceflow client and streams the response.
+///
+/// # Parameters
+///
+/// * `voiceflow_client` - The Voiceflow client.
+/// * `voiceflow_session` - The Voiceflow session of the user.
+/// * `request` - The request to send.
+///
+/// # Returns
+///
+/// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+async fn send_voiceflow_request_stream(voiceflow_client: &VoiceflowClient, voiceflow_session: &VoiceflowSession, request: VoiceflowRequest) -> VoiceflowBlockStream {
+    match request.into_parts() {
+        (VoiceflowRequestKind::Launch, state) => voiceflow_client.launch_dialog_stream(voiceflow_session, state.unwrap_or_default()).await,
+        (VoiceflowRequestKind::Text(text), state) => voiceflow_client.send_message_stream(voiceflow_session, state, &text).await,
+        (VoiceflowRequestKind::Button(payload), state) => voiceflow_client.choose_button_stream(voiceflow_session, state, payload).await,
+        (VoiceflowRequestKind::Event(event, payload), state) => voiceflow_client.send_event_stream(voiceflow_session, state, &event, payload).await,
+        (VoiceflowRequestKind::Intent(intent), state) => voiceflow_client.send_intent_stream(voiceflow_session, state, &intent).await,
+        (VoiceflowRequestKind::Path(path, payload), state) => voiceflow_client.choose_path_stream(voiceflow_session, state, &path, payload).await
+    }
+}
+
+/// Checks if the dialog stopped on the custom action waits for the client to choose a path.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the custom action handlers.
+/// * `name` - The name of the custom action.
+///
+/// # Returns
+///
+/// A boolean indicating whether the custom action has a handler or hands the chat off to a human agent.
+fn is_handled_custom_action<H: Sender>(client_base: &ClientBase<H>, name: &str) -> bool {
+    client_base.custom_action_handler(name).is_some() || client_base.is_handoff_action(name)
+}
+
+/// Returns the session of the chat in handoff.
+///
+/// # Parameters
+///
+/// * `client_base` - The base of the client with the sessions.
+/// * `chat_id` - The chat ID of the user.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing the session or a `VoiceflousionError` if the chat has no session or isn't in handoff.
+async fn get_handoff_session<H: Sender>(client_base: &ClientBase<H>, chat_id: &String) -> VoiceflousionResult<Arc<Session>> {
+    let session = client_base.sessions().get_session(chat_id).await
+        .ok_or_else(|| VoiceflousionError::ClientRequestError("Client".to_string(), format!("Chat {} has no session", chat_id)))?;
+
+    if !session.is_in_handoff() {
+        return Err(VoiceflousionError::ValidationError("Client".to_string(), format!("Chat {} isn't handed off to a human agent", chat_id)));
+    }
+    Ok(session)
+}
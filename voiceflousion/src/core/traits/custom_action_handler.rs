@@ -0,0 +1,39 @@
+use std::future::Future;
+use std::pin::Pin;
+use serde_json::Value;
+use crate::core::session_wrappers::LockedSession;
+use crate::core::subtypes::CustomActionResponse;
+use crate::errors::VoiceflousionResult;
+
+/// Trait for functions that handle Voiceflow custom actions.
+///
+/// `CustomActionHandler` is a trait that defines a handler for a custom action the Voiceflow dialog stopped on.
+/// The handler is an asynchronous function that takes the payload of the custom action and the locked session,
+/// and returns a future that resolves to a `CustomActionResponse` with the path to resume the dialog with.
+///
+/// # Example
+///
+/// ```
+/// use std::future::Future;
+/// use std::pin::Pin;
+/// use serde_json::Value;
+/// use voiceflousion::core::session_wrappers::LockedSession;
+/// use voiceflousion::core::subtypes::CustomActionResponse;
+/// use voiceflousion::errors::VoiceflousionResult;
+///
+/// fn check_payment<'a>(payload: Value, _locked_session: &'a LockedSession<'a>) -> Pin<Box<dyn Future<Output = VoiceflousionResult<CustomActionResponse>> + Send + 'a>> {
+///     Box::pin(async move {
+///         let path = if payload["order_id"].is_null() { "failure" } else { "success" };
+///         Ok(CustomActionResponse::new(path.to_string()))
+///     })
+/// }
+/// ```
+pub trait CustomActionHandler: for<'a> Fn(Value, &'a LockedSession<'a>) -> Pin<Box<dyn Future<Output = VoiceflousionResult<CustomActionResponse>> + Send + 'a>> + Send + Sync {}
+
+/// Implementation of `CustomActionHandler` for any function that matches the required signature.
+///
+/// This implementation allows any function or closure that matches the required signature to be used as a `CustomActionHandler`.
+impl<F> CustomActionHandler for F
+where
+    F: for<'a> Fn(Value, &'a LockedSession<'a>) -> Pin<Box<dyn Future<Output = VoiceflousionResult<CustomActionResponse>> + Send + 'a>> + Send + Sync,
+{}
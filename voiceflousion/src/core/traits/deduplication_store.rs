@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use crate::errors::VoiceflousionResult;
+
+/// A trait for tracking the processed updates to skip their redeliveries.
+///
+/// The `DeduplicationStore` trait is used by the `Client` trait before interacting with Voiceflow,
+/// so the updates redelivered by the client platform don't produce duplicate Voiceflow turns and replies.
+/// The updates are identified by the client ID and the update ID, so a single store can be shared
+/// by several clients or application instances.
+#[async_trait]
+pub trait DeduplicationStore: Send + Sync {
+    /// Marks the update as processed.
+    ///
+    /// The check and the mark must be atomic for the stores shared by several application instances.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client receiving the update.
+    /// * `update_id` - The ID of the update.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing `true` if the update wasn't processed before,
+    /// `false` if it is a redelivery, or a `VoiceflousionError` if the store cannot be accessed.
+    async fn mark_processed(&self, client_id: &str, update_id: &str) -> VoiceflousionResult<bool>;
+}
\ No newline at end of file
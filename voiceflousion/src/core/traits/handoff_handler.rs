@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use crate::core::base_structs::UpdateBase;
+use crate::errors::VoiceflousionResult;
+
+/// A trait for routing the chats handed off from the Voiceflow bot to human agents.
+///
+/// While a chat is in handoff, the updates of the user are passed to `on_user_update()` instead of Voiceflow.
+/// The replies of the agent are sent with `Client::send_operator_message()`, and the chat is returned
+/// to the bot with `Client::finish_handoff()`.
+///
+/// # Example
+///
+/// ```
+/// use async_trait::async_trait;
+/// use voiceflousion::core::base_structs::UpdateBase;
+/// use voiceflousion::core::subtypes::InteractionType;
+/// use voiceflousion::core::traits::HandoffHandler;
+/// use voiceflousion::errors::VoiceflousionResult;
+///
+/// struct OperatorConsole;
+///
+/// #[async_trait]
+/// impl HandoffHandler for OperatorConsole {
+///     async fn on_user_update(&self, chat_id: &str, update: &UpdateBase) -> VoiceflousionResult<()> {
+///         if let InteractionType::Text(text) = update.interaction_type() {
+///             println!("User {}: {}", chat_id, text);
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait HandoffHandler: Send + Sync {
+    /// Notifies that the chat is handed off to a human agent.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID of the user.
+    /// * `_payload` - The optional payload of the handoff, e.g. the payload of the Voiceflow custom action.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the handoff is accepted.
+    async fn on_handoff_started(&self, _chat_id: &String, _payload: Option<Value>) -> VoiceflousionResult<()> {
+        Ok(())
+    }
+
+    /// Receives the update of the user while the chat is in handoff.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `update` - The update of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the update is delivered to the agent.
+    async fn on_user_update(&self, chat_id: &str, update: &UpdateBase) -> VoiceflousionResult<()>;
+
+    /// Notifies that the chat is returned to the Voiceflow bot.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the chat can be returned to the bot.
+    async fn on_handoff_finished(&self, _chat_id: &String) -> VoiceflousionResult<()> {
+        Ok(())
+    }
+}
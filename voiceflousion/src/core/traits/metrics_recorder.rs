@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+
+/// A trait for recording the metrics of the clients and Voiceflow requests.
+///
+/// The `MetricsRecorder` trait is the backend of the metrics facade in `core::metrics`.
+/// A recorder is installed once with `set_metrics_recorder()` and receives the counters,
+/// histograms and gauges of the crate, so the metrics can be shipped to any monitoring system.
+///
+/// # Example
+///
+/// ```
+/// use async_trait::async_trait;
+/// use voiceflousion::core::traits::MetricsRecorder;
+///
+/// struct StdoutRecorder;
+///
+/// #[async_trait]
+/// impl MetricsRecorder for StdoutRecorder {
+///     async fn increment_counter(&self, name: &'static str, labels: &[(&'static str, String)]) {
+///         println!("{} {:?} +1", name, labels);
+///     }
+///
+///     async fn record_histogram(&self, name: &'static str, labels: &[(&'static str, String)], value: f64) {
+///         println!("{} {:?} {}", name, labels, value);
+///     }
+///
+///     async fn set_gauge(&self, name: &'static str, labels: &[(&'static str, String)], value: f64) {
+///         println!("{} {:?} = {}", name, labels, value);
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait MetricsRecorder: Send + Sync {
+    /// Increments the counter by one.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the counter.
+    /// * `labels` - The labels of the counter.
+    async fn increment_counter(&self, name: &'static str, labels: &[(&'static str, String)]);
+
+    /// Records the value into the histogram.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the histogram.
+    /// * `labels` - The labels of the histogram.
+    /// * `value` - The observed value.
+    async fn record_histogram(&self, name: &'static str, labels: &[(&'static str, String)], value: f64);
+
+    /// Sets the value of the gauge.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the gauge.
+    /// * `labels` - The labels of the gauge.
+    /// * `value` - The new value of the gauge.
+    async fn set_gauge(&self, name: &'static str, labels: &[(&'static str, String)], value: f64);
+}
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use crate::core::base_structs::UpdateBase;
+use crate::core::subtypes::{MiddlewareFlow, SentMessage};
+use crate::core::voiceflow::{VoiceflowMessage, VoiceflowRequest};
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// A trait for intercepting the interaction of the client with Voiceflow.
+///
+/// Middlewares are configured on `ClientBuilder` and called in the order they were added.
+/// Every hook has a default implementation that does nothing, so a middleware implements only the hooks it needs.
+/// An error returned by a hook interrupts the interaction and is returned from `Client::interact_with_client()`.
+///
+/// # Example
+///
+/// ```
+/// use async_trait::async_trait;
+/// use voiceflousion::core::base_structs::UpdateBase;
+/// use voiceflousion::core::subtypes::{InteractionType, MiddlewareFlow};
+/// use voiceflousion::core::traits::Middleware;
+/// use voiceflousion::core::voiceflow::VoiceflowRequest;
+/// use voiceflousion::errors::VoiceflousionResult;
+/// use serde_json::json;
+///
+/// struct ProfanityFilter;
+///
+/// #[async_trait]
+/// impl Middleware for ProfanityFilter {
+///     async fn on_update(&self, update: &UpdateBase) -> VoiceflousionResult<MiddlewareFlow> {
+///         match update.interaction_type() {
+///             InteractionType::Text(text) if text.contains("badword") => Ok(MiddlewareFlow::Reply("Please, be polite".to_string())),
+///             _ => Ok(MiddlewareFlow::Continue)
+///         }
+///     }
+///
+///     async fn on_voiceflow_request(&self, request: &mut VoiceflowRequest) -> VoiceflousionResult<()> {
+///         request.add_variables(json!({"filtered": true}));
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Intercepts the incoming update before it is handled.
+    ///
+    /// # Parameters
+    ///
+    /// * `_update` - The incoming update.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `MiddlewareFlow` of the update. By default, the update continues.
+    async fn on_update(&self, _update: &UpdateBase) -> VoiceflousionResult<MiddlewareFlow> {
+        Ok(MiddlewareFlow::Continue)
+    }
+
+    /// Intercepts the request with the user's input before it is sent to Voiceflow.
+    ///
+    /// # Parameters
+    ///
+    /// * `_request` - The request to Voiceflow, which can be rewritten.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the request can be sent.
+    async fn on_voiceflow_request(&self, _request: &mut VoiceflowRequest) -> VoiceflousionResult<()> {
+        Ok(())
+    }
+
+    /// Intercepts the response of Voiceflow before it is sent to the user.
+    ///
+    /// In the streaming mode the hook is called for every block of the response wrapped into a message.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID of the user.
+    /// * `_message` - The response of Voiceflow, which can be rewritten.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the response can be sent.
+    async fn on_voiceflow_response(&self, _chat_id: &String, _message: &mut VoiceflowMessage) -> VoiceflousionResult<()> {
+        Ok(())
+    }
+
+    /// Observes the result of sending the response to the user.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID of the user.
+    /// * `_result` - The messages sent to the user or the error of the sender.
+    async fn on_sender_result(&self, _chat_id: &String, _result: Result<&[SentMessage], &VoiceflousionError>) {}
+}
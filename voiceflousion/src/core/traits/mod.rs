@@ -2,10 +2,26 @@ mod update;
 mod client;
 mod sender;
 mod responder;
+mod session_store;
+mod versioned;
+mod custom_action_handler;
+mod middleware;
+mod handoff_handler;
+mod metrics_recorder;
+mod deduplication_store;
 
 pub use self::update::Update;
 pub use self::client::Client;
 pub use self::responder::Responder;
+pub use self::session_store::SessionStore;
+pub use self::custom_action_handler::CustomActionHandler;
+pub use self::middleware::Middleware;
+pub use self::handoff_handler::HandoffHandler;
+pub use self::metrics_recorder::MetricsRecorder;
+pub use self::deduplication_store::DeduplicationStore;
+pub use self::versioned::{Versioned, SCHEMA_VERSION};
+#[cfg(feature = "whatsapp")]
+pub(crate) use self::client::lock_outbound_session;
 
 #[cfg(not(feature = "advanced"))]
 pub(crate) use self::{
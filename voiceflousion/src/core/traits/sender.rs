@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use crate::core::base_structs::SenderBase;
 use crate::core::traits::Responder;
 use crate::core::voiceflow::{VoiceflowBlock, VoiceflowMessage};
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowCustomAction, VoiceflowImage, VoiceflowKnowledgeBase, VoiceflowNoReply, VoiceflowText};
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
 /// A trait that defines the functionality for sending messages to a client.
@@ -40,41 +40,73 @@ pub trait Sender: Deref<Target=SenderBase> + Send + Sync + Sized {
 
         // Iterate over each block in the message and send it using the appropriate method
         for block in message.into_iter() {
-            match block {
-                VoiceflowBlock::Text(text) => {
-                    let result = self.send_text(client_id, text, chat_id).await?;
-                    responses.push(result)
-                },
-                VoiceflowBlock::Image(image) => {
-                    let result = self.send_image(client_id, image, chat_id).await?;
-                    responses.push(result)
-                },
-                VoiceflowBlock::Buttons(buttons) => {
-                    let result = self.send_buttons(client_id, buttons, chat_id).await?;
-                    responses.push(result)
-                },
-                VoiceflowBlock::Card(card) => {
-                    let result = self.send_card(client_id, card, chat_id).await?;
-                    responses.push(result)
-                },
-                VoiceflowBlock::Carousel(carousel) => {
-                    if !carousel.is_empty() {
-                        let result = self.send_carousel(client_id, carousel, chat_id).await?;
-                        responses.push(result)
-                    }
-                }
-                _ => {
-                    return Err(VoiceflousionError::ClientRequestInvalidBodyError(
-                        "Sender send_message".to_string(),
-                        "Unsendable block type in the VoiceflowMessage".to_string(),
-                    ))
-                },
+            if let Some(result) = self.send_block(client_id, block, chat_id).await? {
+                responses.push(result)
             }
         }
 
         Ok(responses)
     }
 
+    /// Sends a single `VoiceflowBlock` to a client.
+    ///
+    /// This method sends the block using the appropriate method (`send_text`, `send_image`, etc.).
+    /// It is used for forwarding blocks of a `VoiceflowBlockStream` as soon as they are received.
+    ///
+    /// **This method has a base implementation for sending messages. Modify it only if you
+    /// know what you are doing or have devised a better approach.**
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client.
+    /// * `block` - The `VoiceflowBlock` to send.
+    /// * `chat_id` - The chat ID of the client to send the message to.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `SenderResponder`, `None` if there was nothing to send,
+    /// or a `VoiceflousionError` if the request fails.
+    async fn send_block(&self, client_id: &String, block: VoiceflowBlock, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        match block {
+            VoiceflowBlock::Text(text) => {
+                self.send_text(client_id, text, chat_id).await.map(Some)
+            },
+            VoiceflowBlock::Image(image) => {
+                self.send_image(client_id, image, chat_id).await.map(Some)
+            },
+            VoiceflowBlock::Buttons(buttons) => {
+                self.send_buttons(client_id, buttons, chat_id).await.map(Some)
+            },
+            VoiceflowBlock::Card(card) => {
+                self.send_card(client_id, card, chat_id).await.map(Some)
+            },
+            VoiceflowBlock::Carousel(carousel) => {
+                if carousel.is_empty() {
+                    return Ok(None);
+                }
+                self.send_carousel(client_id, carousel, chat_id).await.map(Some)
+            },
+            VoiceflowBlock::Audio(audio) => {
+                self.send_audio(client_id, audio, chat_id).await
+            },
+            VoiceflowBlock::CustomAction(custom_action) => {
+                self.send_custom_action(client_id, custom_action, chat_id).await
+            },
+            VoiceflowBlock::NoReply(no_reply) => {
+                self.send_no_reply(client_id, no_reply, chat_id).await
+            },
+            VoiceflowBlock::KnowledgeBase(knowledge_base) => {
+                self.send_knowledge_base(client_id, knowledge_base, chat_id).await
+            },
+            _ => {
+                Err(VoiceflousionError::ClientRequestInvalidBodyError(
+                    "Sender send_message".to_string(),
+                    "Unsendable block type in the VoiceflowMessage".to_string(),
+                ))
+            },
+        }
+    }
+
     /// Sends a text message to a client.
     ///
     /// # Parameters
@@ -139,4 +171,76 @@ pub trait Sender: Deref<Target=SenderBase> + Send + Sync + Sized {
     ///
     /// A `VoiceflousionResult` containing a `SenderResponder` or a `VoiceflousionError` if the request fails.
     async fn send_carousel(&self, client_id: &String, carousel: VoiceflowCarousel, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder>;
+
+    /// Sends an audio message to a client.
+    ///
+    /// The base implementation falls back to sending the message of the audio or its URL as a text.
+    /// If there is nothing to send, the audio is skipped.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client.
+    /// * `audio` - The `VoiceflowAudio` block to send.
+    /// * `chat_id` - The chat ID of the client to send the message to.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_audio(&self, client_id: &String, audio: VoiceflowAudio, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        match audio.fallback_text() {
+            Some(text) => self.send_text(client_id, text, chat_id).await.map(Some),
+            None => Ok(None)
+        }
+    }
+
+    /// Handles a custom action for a client.
+    ///
+    /// Custom actions are not shown to the user, so the base implementation skips them.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client.
+    /// * `custom_action` - The `VoiceflowCustomAction` block.
+    /// * `chat_id` - The chat ID of the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_custom_action(&self, _client_id: &String, _custom_action: VoiceflowCustomAction, _chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        Ok(None)
+    }
+
+    /// Handles a no-reply timeout for a client.
+    ///
+    /// The no-reply timeout is not shown to the user, so the base implementation skips it.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client.
+    /// * `no_reply` - The `VoiceflowNoReply` block.
+    /// * `chat_id` - The chat ID of the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_no_reply(&self, _client_id: &String, _no_reply: VoiceflowNoReply, _chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        Ok(None)
+    }
+
+    /// Sends knowledge base sources to a client.
+    ///
+    /// The answer of the knowledge base is sent as a text block, so the base implementation skips the sources.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the client.
+    /// * `knowledge_base` - The `VoiceflowKnowledgeBase` block.
+    /// * `chat_id` - The chat ID of the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `SenderResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_knowledge_base(&self, _client_id: &String, _knowledge_base: VoiceflowKnowledgeBase, _chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        Ok(None)
+    }
 }
\ No newline at end of file
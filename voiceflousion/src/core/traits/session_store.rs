@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use crate::core::session_wrappers::StoredSession;
+use crate::errors::VoiceflousionResult;
+
+/// A trait for persisting sessions outside of the process memory.
+///
+/// The `SessionStore` trait is used by `SessionMap` to save, restore and delete
+/// session snapshots, so that conversations survive restarts of the application.
+/// Every client should have its own store, because sessions are identified by their chat ID only.
+#[async_trait]
+pub trait SessionStore: Send + Sync {
+    /// Loads a stored session by chat ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the session to load.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `StoredSession` if it exists,
+    /// or a `VoiceflousionError` if the store cannot be read.
+    async fn load_session(&self, chat_id: &str) -> VoiceflousionResult<Option<StoredSession>>;
+
+    /// Saves a session snapshot, replacing the previous snapshot with the same chat ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The `StoredSession` to save.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the session was saved.
+    async fn save_session(&self, session: StoredSession) -> VoiceflousionResult<()>;
+
+    /// Deletes a stored session by chat ID.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the session to delete.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating whether the session was deleted.
+    async fn delete_session(&self, chat_id: &str) -> VoiceflousionResult<()>;
+}
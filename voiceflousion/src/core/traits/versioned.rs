@@ -0,0 +1,87 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// The current version of the serialization schema for dialog blocks and messages.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Envelope that binds serialized data to the schema version it was written with.
+#[derive(Serialize, Deserialize)]
+struct VersionedData<T> {
+    /// The version of the schema.
+    version: u32,
+    /// The serialized data.
+    data: T,
+}
+
+/// A trait for serializing data with a stable, versioned schema.
+///
+/// The `Versioned` trait wraps the serialized data into an envelope with the schema version,
+/// so stored bot output can be safely restored and replayed by later versions of the crate.
+pub trait Versioned: Serialize + DeserializeOwned {
+    /// Serializes the data into a JSON `Value` with the schema version.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the JSON `Value` or a `VoiceflousionError` if the serialization fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::traits::Versioned;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    /// use voiceflousion::core::voiceflow::VoiceflowBlock;
+    ///
+    /// let block = VoiceflowBlock::Text(VoiceflowText::new("text".to_string()));
+    /// let value = block.to_versioned_json().unwrap();
+    /// ```
+    fn to_versioned_json(&self) -> VoiceflousionResult<Value> {
+        serde_json::to_value(VersionedData { version: SCHEMA_VERSION, data: self })
+            .map_err(|error| VoiceflousionError::ValidationError("Versioned data serialization".to_string(), error.to_string()))
+    }
+
+    /// Deserializes the data from a JSON `Value` with the schema version.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The JSON `Value` created by `to_versioned_json()`.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the deserialized data or a `VoiceflousionError`
+    /// if the schema version is not supported or the value is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::traits::Versioned;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    /// use voiceflousion::core::voiceflow::VoiceflowBlock;
+    ///
+    /// let card1 = VoiceflowCard::new(None, Some("Title1".to_string()), None, None);
+    /// let card2 = VoiceflowCard::new(None, Some("Title2".to_string()), None, None);
+    /// let carousel = VoiceflowCarousel::new(vec![card1, card2], false);
+    /// carousel.set_selected_card(1, 1627554661);
+    ///
+    /// let value = VoiceflowBlock::Carousel(carousel).to_versioned_json().unwrap();
+    /// if let VoiceflowBlock::Carousel(restored) = VoiceflowBlock::from_versioned_json(value).unwrap() {
+    ///     assert_eq!(restored.get_selected_index(), 1);
+    ///     assert_eq!(restored.get_selected_mark(), 1627554661);
+    /// }
+    /// ```
+    fn from_versioned_json(value: Value) -> VoiceflousionResult<Self> {
+        let versioned: VersionedData<Value> = serde_json::from_value(value)
+            .map_err(|error| VoiceflousionError::ValidationError("Versioned data envelope".to_string(), error.to_string()))?;
+
+        if versioned.version == 0 || versioned.version > SCHEMA_VERSION {
+            return Err(VoiceflousionError::ValidationError(
+                "Versioned data version".to_string(),
+                format!("Unsupported schema version {}, the latest supported version is {}", versioned.version, SCHEMA_VERSION)
+            ));
+        }
+
+        serde_json::from_value(versioned.data)
+            .map_err(|error| VoiceflousionError::ValidationError("Versioned data deserialization".to_string(), error.to_string()))
+    }
+}
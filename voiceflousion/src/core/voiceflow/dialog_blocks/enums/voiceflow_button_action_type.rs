@@ -1,8 +1,10 @@
+use serde::{Deserialize, Serialize};
 /// Represents the type of action for a Voiceflow button.
 ///
 /// `VoiceflowButtonActionType` is an enum that can represent different types of actions
 /// such as opening a URL or following a path, within the Voiceflow dialog.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "url", rename_all = "snake_case")]
 pub(crate) enum VoiceflowButtonActionType {
     /// An action to open a URL.
     Url(String),
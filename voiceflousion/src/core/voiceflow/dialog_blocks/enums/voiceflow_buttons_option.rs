@@ -1,10 +1,12 @@
+use serde::{Deserialize, Serialize};
 use crate::core::voiceflow::dialog_blocks::voiceflow_text::VoiceflowText;
 
 /// Represents an option for Voiceflow buttons.
 ///
 /// `VoiceflowButtonsOption` is an enum that can represent different options
 /// such as associating text or being empty.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "text", rename_all = "snake_case")]
 pub enum VoiceflowButtonsOption {
     /// An option to associate text with the buttons.
     Text(VoiceflowText),
@@ -4,6 +4,11 @@ mod voiceflow_buttons;
 mod voiceflow_card;
 mod voiceflow_image;
 mod voiceflow_button;
+mod voiceflow_audio;
+mod voiceflow_custom_action;
+mod voiceflow_no_reply;
+mod voiceflow_knowledge_base;
+mod voiceflow_knowledge_base_source;
 pub(crate) mod traits;
 pub mod enums;
 
@@ -14,7 +19,12 @@ pub use self::{
     voiceflow_carousel::VoiceflowCarousel,
     voiceflow_image::VoiceflowImage,
     voiceflow_text::VoiceflowText,
-    voiceflow_button::VoiceflowButton
+    voiceflow_button::VoiceflowButton,
+    voiceflow_audio::VoiceflowAudio,
+    voiceflow_custom_action::VoiceflowCustomAction,
+    voiceflow_no_reply::VoiceflowNoReply,
+    voiceflow_knowledge_base::VoiceflowKnowledgeBase,
+    voiceflow_knowledge_base_source::VoiceflowKnowledgeBaseSource
 };
 
 #[cfg(not(feature = "advanced"))]
@@ -24,5 +34,10 @@ pub(crate) use self::{
     voiceflow_carousel::VoiceflowCarousel,
     voiceflow_image::VoiceflowImage,
     voiceflow_text::VoiceflowText,
-    voiceflow_button::VoiceflowButton
+    voiceflow_button::VoiceflowButton,
+    voiceflow_audio::VoiceflowAudio,
+    voiceflow_custom_action::VoiceflowCustomAction,
+    voiceflow_no_reply::VoiceflowNoReply,
+    voiceflow_knowledge_base::VoiceflowKnowledgeBase,
+    voiceflow_knowledge_base_source::VoiceflowKnowledgeBaseSource
 };
\ No newline at end of file
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::voiceflow::dialog_blocks::traits::FromValue;
+use crate::core::voiceflow::dialog_blocks::VoiceflowText;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents an audio in a Voiceflow dialog.
+///
+/// `VoiceflowAudio` contains the source of the audio from a `speak` trace and
+/// the optional message (transcript) of the audio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceflowAudio {
+    /// The source of the audio. It can be a URL or a data URI.
+    src: String,
+
+    /// The optional message of the audio.
+    message: Option<String>,
+}
+
+impl VoiceflowAudio {
+    /// Creates a new `VoiceflowAudio` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `src` - The source of the audio.
+    /// * `message` - The optional message of the audio.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowAudio`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    ///
+    /// let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), Some("Hello".to_string()));
+    /// ```
+    pub fn new(src: String, message: Option<String>) -> Self {
+        Self {
+            src,
+            message,
+        }
+    }
+
+    /// Returns a reference to the source of the audio.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the source string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    ///
+    /// let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    /// let src = audio.src();
+    /// ```
+    pub fn src(&self) -> &String {
+        &self.src
+    }
+
+    /// Returns a reference to the message of the audio.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional message string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    ///
+    /// let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), Some("Hello".to_string()));
+    /// let message = audio.message();
+    /// ```
+    pub fn message(&self) -> &Option<String> {
+        &self.message
+    }
+
+    /// Returns the URL of the audio if the source is an HTTP(S) URL.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the URL of the audio, or `None` if the source is not a URL (for example, a data URI).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    ///
+    /// let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    /// let url = audio.url();
+    /// ```
+    pub fn url(&self) -> Option<&String> {
+        if self.src.starts_with("http://") || self.src.starts_with("https://") {
+            Some(&self.src)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the text that can be sent instead of the audio.
+    ///
+    /// The message of the audio is used if it is present, otherwise the URL of the audio.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the fallback `VoiceflowText`, or `None` if there is nothing to send.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    ///
+    /// let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), Some("Hello".to_string()));
+    /// let text = audio.fallback_text();
+    /// ```
+    pub fn fallback_text(&self) -> Option<VoiceflowText> {
+        self.message.clone()
+            .filter(|message| !message.is_empty())
+            .or_else(|| self.url().cloned())
+            .map(VoiceflowText::new)
+    }
+}
+
+impl FromValue for VoiceflowAudio {
+    /// Attempts to convert a JSON `Value` into a `VoiceflowAudio` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A reference to the JSON `Value` to convert from.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `VoiceflowAudio` instance if the conversion
+    /// succeeds, or a `VoiceflousionError` if the conversion fails. If the trace has no audio source,
+    /// `None` is returned.
+    fn from_value(value: &Value) -> VoiceflousionResult<Option<Self>> {
+        // Extract the "payload" field from the "trace" object in the JSON value.
+        let payload = value["trace"].get("payload").ok_or_else(|| {
+            VoiceflousionError::VoiceflowBlockConvertationError(
+                "VoiceflowAudio audio payload".to_string(),
+                value.clone(),
+            )
+        })?;
+
+        // Extract the audio source, return None if it is absent or empty.
+        let src = match payload.get("src").and_then(|src| src.as_str()) {
+            Some(src) if !src.is_empty() => src.to_string(),
+            _ => return Ok(None),
+        };
+
+        // Extract the optional message of the audio.
+        let message = payload.get("message")
+            .and_then(|message| message.as_str())
+            .map(|message| message.to_string());
+
+        // Return the constructed `VoiceflowAudio` instance.
+        Ok(Some(Self::new(src, message)))
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonActionType;
 use crate::core::voiceflow::dialog_blocks::traits::FromValue;
@@ -7,7 +8,7 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 /// Represents a button in a Voiceflow dialog.
 ///
 /// `VoiceflowButton` contains the name, action type, and payload of the button.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowButton {
     /// The action type of the button.
     action_type: VoiceflowButtonActionType,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use chrono::Utc;
 use serde_json::Value;
@@ -10,7 +11,7 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 ///
 /// `VoiceflowButtons` contains a list of `VoiceflowButton` instances, an optional
 /// buttons option, and a timestamp indicating when the buttons were marked.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowButtons {
     /// The optional buttons option providing additional context or data.
     option: VoiceflowButtonsOption,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::core::voiceflow::dialog_blocks::traits::FromValue;
 use crate::core::voiceflow::dialog_blocks::VoiceflowButtons;
@@ -6,7 +7,7 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 /// Represents a card in a Voiceflow dialog.
 ///
 /// `VoiceflowCard` contains optional fields for an image URL, title, description, and buttons.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowCard {
     /// The optional URL of the image.
     image_url: Option<String>,
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
 use chrono::Utc;
@@ -10,7 +11,8 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 ///
 /// `VoiceflowCarousel` contains a list of `VoiceflowCard` instances and a flag indicating whether the carousel has images.
 /// The carousel allows for easy navigation between cards and keeps track of the selected card's index and the timestamp when it was selected.
-#[derive(Debug, Clone)]
+/// The selection state is serialized together with the cards, so it can be restored later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowCarousel {
     /// The list of cards in the carousel.
     cards: Vec<VoiceflowCard>,
@@ -84,6 +86,26 @@ impl VoiceflowCarousel {
         self.has_images
     }
 
+    /// Returns the cards of the carousel.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of `VoiceflowCard` instances.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    ///
+    /// let card = VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None);
+    /// let cards = vec![card];
+    /// let carousel = VoiceflowCarousel::new(cards, true);
+    /// let cards = carousel.cards();
+    /// ```
+    pub fn cards(&self) -> &Vec<VoiceflowCard> {
+        &self.cards
+    }
+
     /// Returns the number of cards in the carousel.
     ///
     /// # Returns
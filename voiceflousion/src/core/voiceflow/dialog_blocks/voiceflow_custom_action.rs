@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::voiceflow::dialog_blocks::traits::FromValue;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents a custom action in a Voiceflow dialog.
+///
+/// `VoiceflowCustomAction` contains the name of the custom action (or extension) trace,
+/// its arbitrary payload and the names of the paths the dialog can be resumed with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceflowCustomAction {
+    /// The name of the custom action.
+    name: String,
+
+    /// The payload of the custom action.
+    payload: Value,
+
+    /// The names of the paths defined for the custom action.
+    paths: Vec<String>,
+
+    /// The optional index of the default path.
+    default_path: Option<usize>,
+}
+
+impl VoiceflowCustomAction {
+    /// Creates a new `VoiceflowCustomAction` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the custom action.
+    /// * `payload` - The payload of the custom action.
+    /// * `paths` - The names of the paths defined for the custom action.
+    /// * `default_path` - The optional index of the default path.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowCustomAction`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
+    ///
+    /// let action = VoiceflowCustomAction::new("check_payment".to_string(), json!({"order": 1}), vec!["success".to_string()], Some(0));
+    /// ```
+    pub fn new(name: String, payload: Value, paths: Vec<String>, default_path: Option<usize>) -> Self {
+        Self {
+            name,
+            payload,
+            paths,
+            default_path,
+        }
+    }
+
+    /// Returns a reference to the name of the custom action.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::Value;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
+    ///
+    /// let action = VoiceflowCustomAction::new("check_payment".to_string(), Value::Null, vec![], None);
+    /// let name = action.name();
+    /// ```
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns a reference to the payload of the custom action.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the payload `Value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::Value;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
+    ///
+    /// let action = VoiceflowCustomAction::new("check_payment".to_string(), Value::Null, vec![], None);
+    /// let payload = action.payload();
+    /// ```
+    pub fn payload(&self) -> &Value {
+        &self.payload
+    }
+
+    /// Returns a reference to the names of the paths defined for the custom action.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of path names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::Value;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
+    ///
+    /// let action = VoiceflowCustomAction::new("check_payment".to_string(), Value::Null, vec!["success".to_string()], None);
+    /// let paths = action.paths();
+    /// ```
+    pub fn paths(&self) -> &Vec<String> {
+        &self.paths
+    }
+
+    /// Returns the name of the default path, if it is defined.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the default path name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::Value;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
+    ///
+    /// let action = VoiceflowCustomAction::new("check_payment".to_string(), Value::Null, vec!["success".to_string()], Some(0));
+    /// let default_path = action.default_path();
+    /// ```
+    pub fn default_path(&self) -> Option<&String> {
+        self.default_path.and_then(|index| self.paths.get(index))
+    }
+}
+
+impl FromValue for VoiceflowCustomAction {
+    /// Attempts to convert a JSON `Value` into a `VoiceflowCustomAction` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A reference to the JSON `Value` to convert from.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `VoiceflowCustomAction` instance if the conversion
+    /// succeeds, or a `VoiceflousionError` if the conversion fails.
+    fn from_value(value: &Value) -> VoiceflousionResult<Option<Self>> {
+        let trace = &value["trace"];
+
+        // Extract the name of the custom action from the trace type.
+        let name = trace.get("type")
+            .and_then(|name| name.as_str())
+            .ok_or_else(|| VoiceflousionError::VoiceflowBlockConvertationError(
+                "VoiceflowCustomAction action name".to_string(),
+                value.clone()
+            ))?
+            .to_string();
+
+        // Extract the payload, which can be of any type.
+        let payload = trace.get("payload").cloned().unwrap_or(Value::Null);
+
+        // Extract the names of the paths from their events.
+        let paths = trace.get("paths")
+            .and_then(|paths| paths.as_array())
+            .map(|paths| paths.iter()
+                .filter_map(|path| path["event"]["type"].as_str().map(|path| path.to_string()))
+                .collect())
+            .unwrap_or_default();
+
+        // Extract the optional index of the default path.
+        let default_path = trace.get("defaultPath")
+            .and_then(|index| index.as_u64())
+            .map(|index| index as usize);
+
+        // Return the constructed `VoiceflowCustomAction` instance.
+        Ok(Some(Self::new(name, payload, paths, default_path)))
+    }
+}
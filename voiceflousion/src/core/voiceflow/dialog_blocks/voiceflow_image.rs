@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use crate::core::voiceflow::dialog_blocks::traits::FromValue;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
@@ -5,7 +6,7 @@ use crate::errors::{VoiceflousionError, VoiceflousionResult};
 /// Represents an image in a Voiceflow dialog.
 ///
 /// `VoiceflowImage` contains the URL and optional dimensions (height and width) of the image.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowImage {
     /// The URL of the image.
     url: String,
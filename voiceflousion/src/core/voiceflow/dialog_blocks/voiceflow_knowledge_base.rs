@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::voiceflow::dialog_blocks::traits::FromValue;
+use crate::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBaseSource;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents the sources of a knowledge base answer in a Voiceflow dialog.
+///
+/// `VoiceflowKnowledgeBase` contains the documents used by Voiceflow to generate the answer.
+/// The answer itself is delivered as a separate text block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceflowKnowledgeBase {
+    /// The list of source documents.
+    sources: Vec<VoiceflowKnowledgeBaseSource>,
+}
+
+impl VoiceflowKnowledgeBase {
+    /// Creates a new `VoiceflowKnowledgeBase` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `sources` - A list of `VoiceflowKnowledgeBaseSource` instances.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowKnowledgeBase`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowKnowledgeBase, VoiceflowKnowledgeBaseSource};
+    ///
+    /// let source = VoiceflowKnowledgeBaseSource::new(Some("FAQ".to_string()), None, None);
+    /// let knowledge_base = VoiceflowKnowledgeBase::new(vec![source]);
+    /// ```
+    pub fn new(sources: Vec<VoiceflowKnowledgeBaseSource>) -> Self {
+        Self {
+            sources
+        }
+    }
+
+    /// Returns a reference to the list of source documents.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of `VoiceflowKnowledgeBaseSource` instances.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBase;
+    ///
+    /// let knowledge_base = VoiceflowKnowledgeBase::new(vec![]);
+    /// let sources = knowledge_base.sources();
+    /// ```
+    pub fn sources(&self) -> &Vec<VoiceflowKnowledgeBaseSource> {
+        &self.sources
+    }
+}
+
+impl FromValue for VoiceflowKnowledgeBase {
+    /// Attempts to convert a JSON `Value` into a `VoiceflowKnowledgeBase` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A reference to the JSON `Value` to convert from.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `VoiceflowKnowledgeBase` instance if the conversion
+    /// succeeds, or a `VoiceflousionError` if the conversion fails. If there are no sources, `None` is returned.
+    fn from_value(value: &Value) -> VoiceflousionResult<Option<Self>> {
+        // Extract the "payload" field from the "trace" object in the JSON value.
+        let payload = value["trace"].get("payload").ok_or_else(|| {
+            VoiceflousionError::VoiceflowBlockConvertationError(
+                "VoiceflowKnowledgeBase payload".to_string(),
+                value.clone(),
+            )
+        })?;
+
+        // Convert each chunk into a source document.
+        let sources: Vec<VoiceflowKnowledgeBaseSource> = payload.get("chunks")
+            .and_then(|chunks| chunks.as_array())
+            .map(|chunks| chunks.iter().map(VoiceflowKnowledgeBaseSource::from_chunk).collect())
+            .unwrap_or_default();
+
+        // Return None if there are no sources.
+        if sources.is_empty() {
+            return Ok(None);
+        }
+
+        // Return the constructed `VoiceflowKnowledgeBase` instance.
+        Ok(Some(Self::new(sources)))
+    }
+}
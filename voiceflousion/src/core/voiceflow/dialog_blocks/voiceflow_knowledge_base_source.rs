@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Represents a source document of a Voiceflow knowledge base answer.
+///
+/// `VoiceflowKnowledgeBaseSource` contains the optional name, URL and relevance score of the document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceflowKnowledgeBaseSource {
+    /// The optional name of the document.
+    name: Option<String>,
+
+    /// The optional URL of the document.
+    url: Option<String>,
+
+    /// The optional relevance score of the document.
+    score: Option<f64>,
+}
+
+impl VoiceflowKnowledgeBaseSource {
+    /// Creates a new `VoiceflowKnowledgeBaseSource` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The optional name of the document.
+    /// * `url` - The optional URL of the document.
+    /// * `score` - The optional relevance score of the document.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowKnowledgeBaseSource`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBaseSource;
+    ///
+    /// let source = VoiceflowKnowledgeBaseSource::new(Some("FAQ".to_string()), Some("https://example.com/faq".to_string()), Some(0.8));
+    /// ```
+    pub fn new(name: Option<String>, url: Option<String>, score: Option<f64>) -> Self {
+        Self {
+            name,
+            url,
+            score,
+        }
+    }
+
+    /// Returns a reference to the name of the document.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBaseSource;
+    ///
+    /// let source = VoiceflowKnowledgeBaseSource::new(Some("FAQ".to_string()), None, None);
+    /// let name = source.name();
+    /// ```
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// Returns a reference to the URL of the document.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional URL string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBaseSource;
+    ///
+    /// let source = VoiceflowKnowledgeBaseSource::new(None, Some("https://example.com/faq".to_string()), None);
+    /// let url = source.url();
+    /// ```
+    pub fn url(&self) -> &Option<String> {
+        &self.url
+    }
+
+    /// Returns the relevance score of the document.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the relevance score.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowKnowledgeBaseSource;
+    ///
+    /// let source = VoiceflowKnowledgeBaseSource::new(None, None, Some(0.8));
+    /// let score = source.score();
+    /// ```
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Creates a `VoiceflowKnowledgeBaseSource` from a chunk of the knowledge base trace.
+    ///
+    /// # Parameters
+    ///
+    /// * `chunk` - A reference to the JSON `Value` of the chunk.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowKnowledgeBaseSource`.
+    pub(crate) fn from_chunk(chunk: &Value) -> Self {
+        let document = &chunk["documentData"];
+        let name = document.get("name").and_then(|name| name.as_str()).map(|name| name.to_string());
+        let url = document.get("url").and_then(|url| url.as_str()).map(|url| url.to_string());
+        let score = chunk.get("score").and_then(|score| score.as_f64());
+        Self::new(name, url, score)
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::core::voiceflow::dialog_blocks::traits::FromValue;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents a no-reply timeout in a Voiceflow dialog.
+///
+/// `VoiceflowNoReply` contains the number of seconds Voiceflow waits for the user's reply
+/// before following the no-reply path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceflowNoReply {
+    /// The timeout in seconds.
+    timeout: u64,
+}
+
+impl VoiceflowNoReply {
+    /// Creates a new `VoiceflowNoReply` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `timeout` - The timeout in seconds.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowNoReply`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowNoReply;
+    ///
+    /// let no_reply = VoiceflowNoReply::new(10);
+    /// ```
+    pub fn new(timeout: u64) -> Self {
+        Self {
+            timeout
+        }
+    }
+
+    /// Returns the timeout in seconds.
+    ///
+    /// # Returns
+    ///
+    /// A `u64` representing the timeout in seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowNoReply;
+    ///
+    /// let no_reply = VoiceflowNoReply::new(10);
+    /// let timeout = no_reply.timeout();
+    /// ```
+    pub fn timeout(&self) -> u64 {
+        self.timeout
+    }
+}
+
+impl FromValue for VoiceflowNoReply {
+    /// Attempts to convert a JSON `Value` into a `VoiceflowNoReply` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - A reference to the JSON `Value` to convert from.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an `Option` with the `VoiceflowNoReply` instance if the conversion
+    /// succeeds, or a `VoiceflousionError` if the conversion fails.
+    fn from_value(value: &Value) -> VoiceflousionResult<Option<Self>> {
+        // Extract the "timeout" field from the "payload" within the "trace" object in the JSON value.
+        let timeout = value["trace"]["payload"].get("timeout")
+            .and_then(|timeout| timeout.as_u64())
+            .ok_or_else(|| VoiceflousionError::VoiceflowBlockConvertationError(
+                "VoiceflowNoReply timeout".to_string(),
+                value.clone()
+            ))?;
+
+        // Return the constructed `VoiceflowNoReply` instance.
+        Ok(Some(Self::new(timeout)))
+    }
+}
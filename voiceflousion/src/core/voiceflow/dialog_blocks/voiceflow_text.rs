@@ -1,14 +1,20 @@
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use crate::core::formatting::RichText;
 use crate::core::voiceflow::dialog_blocks::traits::FromValue;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
 /// Represents a text message block in a Voiceflow response.
 ///
-/// `VoiceflowText` contains the message string extracted from a Voiceflow response.
-#[derive(Debug, Clone)]
+/// `VoiceflowText` contains the message string extracted from a Voiceflow response
+/// and its optional formatting authored in the Voiceflow rich text editor or written in Markdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowText {
     /// The message string of the text block.
     message: String,
+    /// The optional formatted text of the text block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rich_text: Option<RichText>,
 }
 
 impl VoiceflowText {
@@ -30,7 +36,38 @@ impl VoiceflowText {
     /// let text_block = VoiceflowText::new("Hello, World!".to_string());
     /// ```
     pub fn new(message: String) -> Self {
-        Self { message }
+        Self {
+            message,
+            rich_text: None
+        }
+    }
+
+    /// Creates a new `VoiceflowText` instance with the formatted text.
+    ///
+    /// The message string of the text block is the plain text of the formatted text.
+    ///
+    /// # Parameters
+    ///
+    /// * `rich_text` - The formatted text of the text block.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowText`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    ///
+    /// let text_block = VoiceflowText::from_rich_text(RichText::from_markdown("Hello, **World**!"));
+    /// assert_eq!(text_block.message(), "Hello, World!");
+    /// ```
+    pub fn from_rich_text(rich_text: RichText) -> Self {
+        Self {
+            message: rich_text.plain_text(),
+            rich_text: Some(rich_text)
+        }
     }
 
     /// Creates a `VoiceflowText` instance with a default error message.
@@ -63,6 +100,24 @@ impl VoiceflowText {
     pub fn message(&self) -> &String {
         &self.message
     }
+
+    /// Returns a reference to the formatted text of the text block.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `RichText`, `None` if the text has no formatting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    ///
+    /// let text_block = VoiceflowText::new("Hello, World!".to_string());
+    /// assert!(text_block.rich_text().is_none());
+    /// ```
+    pub fn rich_text(&self) -> &Option<RichText> {
+        &self.rich_text
+    }
 }
 
 impl FromValue for VoiceflowText {
@@ -70,6 +125,8 @@ impl FromValue for VoiceflowText {
     ///
     /// This method extracts the "message" field from the JSON value, ensuring it is a string.
     /// If the message is empty or the conversion fails, it returns an error or `None`.
+    /// The formatting is taken from the "slate" document of the payload, or parsed from
+    /// the Markdown of the message if the payload has no slate document.
     ///
     /// # Parameters
     ///
@@ -95,7 +152,14 @@ impl FromValue for VoiceflowText {
             return Ok(None);
         }
 
+        // The texts of the AI steps have no slate document and are written in Markdown
+        let rich_text = RichText::from_slate(&value["trace"]["payload"]["slate"]["content"])
+            .unwrap_or_else(|| RichText::from_markdown(&message));
+
         // Return the constructed `VoiceflowText` instance.
-        Ok(Some(Self::new(message)))
+        Ok(Some(Self {
+            message,
+            rich_text: Some(rich_text).filter(|rich_text| !rich_text.is_plain())
+        }))
     }
 }
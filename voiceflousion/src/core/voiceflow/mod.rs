@@ -5,20 +5,27 @@ pub mod dialog_blocks;
 mod voiceflow_session;
 mod voiceflow_message;
 mod voiceflow_block;
+mod voiceflow_block_stream;
+mod voiceflow_user_state;
+mod voiceflow_transcript;
 
 #[cfg(feature = "advanced")]
 pub use self::{
     voiceflow_session::VoiceflowSession,
     voiceflow_message::VoiceflowMessage,
-    voiceflow_block::VoiceflowBlock
+    voiceflow_block::VoiceflowBlock,
+    voiceflow_block_stream::VoiceflowBlockStream
 };
 
 #[cfg(not(feature = "advanced"))]
 pub(crate) use self::{
     voiceflow_session::VoiceflowSession,
     voiceflow_message::VoiceflowMessage,
-    voiceflow_block::VoiceflowBlock
+    voiceflow_block::VoiceflowBlock,
+    voiceflow_block_stream::VoiceflowBlockStream
 };
 
 pub use self::voiceflow_client::VoiceflowClient;
-pub use self::request_structures::State;
\ No newline at end of file
+pub use self::voiceflow_user_state::VoiceflowUserState;
+pub use self::voiceflow_transcript::VoiceflowTranscript;
+pub use self::request_structures::{State, VoiceflowEntity, VoiceflowIntent, VoiceflowRequest, VoiceflowRequestConfig, VoiceflowRequestKind};
\ No newline at end of file
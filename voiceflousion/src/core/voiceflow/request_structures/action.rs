@@ -2,6 +2,7 @@ use serde::Serialize;
 use serde_json::Value;
 use crate::core::voiceflow::request_structures::ActionType;
 use crate::core::voiceflow::request_structures::payload::Payload;
+use crate::core::voiceflow::request_structures::VoiceflowIntent;
 
 /// Represents an action to be performed in the Voiceflow API.
 ///
@@ -61,22 +62,54 @@ impl ActionBuilder {
     ///
     /// # Parameters
     ///
-    /// * `name` - The name of the intent.
-    /// * `path` - The path associated with the intent.
+    /// * `intent` - The intent with its entities and confidence.
     ///
     /// # Returns
     ///
     /// The `ActionBuilder` with the intent payload set.
-    pub fn intent(mut self, name: String, path: String) -> Self {
-        let json_value: Value = serde_json::json!({
-            "query": name,
+    pub fn intent(mut self, intent: &VoiceflowIntent) -> Self {
+        let mut json_value: Value = serde_json::json!({
+            "query": intent.query().clone().unwrap_or_default(),
             "intent":{
-                "name": path
+                "name": intent.name()
+            },
+            "entities": intent.entities()
+        });
+        if let Some(confidence) = intent.confidence() {
+            json_value["confidence"] = Value::from(confidence);
+        }
+        self.payload = Some(Payload::Object(json_value));
+        self
+    }
+
+    /// Sets the event payload for the `Action`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the event.
+    /// * `payload` - The optional JSON object with the fields sent along with the event.
+    ///
+    /// # Returns
+    ///
+    /// The `ActionBuilder` with the event payload set.
+    pub fn event(mut self, name: String, payload: Option<Value>) -> Self {
+        let mut json_value: Value = serde_json::json!({
+            "event":{
+                "name": name
             }
         });
+        // The fields of the payload are placed next to the event, not inside it
+        if let Some(Value::Object(fields)) = payload {
+            for (key, value) in fields {
+                if key != "event" {
+                    json_value[key] = value;
+                }
+            }
+        }
         self.payload = Some(Payload::Object(json_value));
         self
     }
+
     /// Sets the path payload for the `Action`.
     ///
     /// # Parameters
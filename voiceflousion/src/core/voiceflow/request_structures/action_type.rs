@@ -13,6 +13,9 @@ pub(crate) enum ActionType {
     /// Intent action.
     Intent,
 
+    /// Event action.
+    Event,
+
     /// Path action with an associated path string.
     Path(String),
 }
@@ -34,6 +37,7 @@ impl Serialize for ActionType {
             ActionType::Launch => serializer.serialize_str("launch"),
             ActionType::Text => serializer.serialize_str("text"),
             ActionType::Intent => serializer.serialize_str("intent"),
+            ActionType::Event => serializer.serialize_str("event"),
             ActionType::Path(path) => serializer.serialize_str(path),
         }
     }
@@ -53,6 +57,7 @@ impl Display for ActionType{
             ActionType::Launch => "launch".to_string(),
             ActionType::Text => "text".to_string(),
             ActionType::Intent => "intent".to_string(),
+            ActionType::Event => "event".to_string(),
             ActionType::Path(path) => path.clone()
         };
         write!(f, "{}", str)
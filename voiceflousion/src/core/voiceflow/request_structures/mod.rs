@@ -3,8 +3,16 @@ mod state;
 mod voiceflow_request_body;
 mod action;
 mod payload;
+mod voiceflow_request;
+mod voiceflow_request_config;
+mod voiceflow_intent;
+mod voiceflow_entity;
 
 pub(crate) use self::voiceflow_request_body::{VoiceflowRequestBody, VoiceflowRequestBodyBuilder};
 pub(crate) use self::action_type::ActionType;
-pub(crate) use self::action::ActionBuilder;
-pub use self::state::State;
\ No newline at end of file
+pub(crate) use self::action::{Action, ActionBuilder};
+pub use self::state::State;
+pub use self::voiceflow_request::{VoiceflowRequest, VoiceflowRequestKind};
+pub use self::voiceflow_request_config::VoiceflowRequestConfig;
+pub use self::voiceflow_intent::VoiceflowIntent;
+pub use self::voiceflow_entity::VoiceflowEntity;
\ No newline at end of file
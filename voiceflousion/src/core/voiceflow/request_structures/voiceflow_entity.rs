@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+/// Represents an entity recognized in the user's input and sent with an intent to Voiceflow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VoiceflowEntity {
+    /// The name of the entity.
+    name: String,
+    /// The value of the entity.
+    value: String,
+}
+
+impl VoiceflowEntity {
+    /// Creates a new `VoiceflowEntity`.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the entity.
+    /// * `value` - The value of the entity.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowEntity`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowEntity;
+    ///
+    /// let entity = VoiceflowEntity::new("size".to_string(), "large".to_string());
+    /// ```
+    pub fn new(name: String, value: String) -> Self {
+        Self {
+            name,
+            value
+        }
+    }
+
+    /// Returns the name of the entity.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowEntity;
+    ///
+    /// let entity = VoiceflowEntity::new("size".to_string(), "large".to_string());
+    /// assert_eq!(entity.name(), "size");
+    /// ```
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns the value of the entity.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the value string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowEntity;
+    ///
+    /// let entity = VoiceflowEntity::new("size".to_string(), "large".to_string());
+    /// assert_eq!(entity.value(), "large");
+    /// ```
+    pub fn value(&self) -> &String {
+        &self.value
+    }
+}
@@ -0,0 +1,185 @@
+use crate::core::voiceflow::request_structures::VoiceflowEntity;
+
+/// Represents an intent recognized in the user's input and sent to Voiceflow instead of the text.
+///
+/// `VoiceflowIntent` lets an external NLU or the backend choose the intent, its entities
+/// and the confidence of the recognition, bypassing the NLU of the Voiceflow project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceflowIntent {
+    /// The name of the intent.
+    name: String,
+    /// The optional user's input the intent was recognized in.
+    query: Option<String>,
+    /// The entities of the intent.
+    entities: Vec<VoiceflowEntity>,
+    /// The optional confidence of the recognition from 0 to 1.
+    confidence: Option<f64>,
+}
+
+impl VoiceflowIntent {
+    /// Creates a new `VoiceflowIntent` without entities.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the intent.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowIntent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string());
+    /// ```
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            query: None,
+            entities: Vec::new(),
+            confidence: None
+        }
+    }
+
+    /// Sets the user's input the intent was recognized in.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - The user's input.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowIntent` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string()).set_query("I want a large pizza".to_string());
+    /// ```
+    pub fn set_query(mut self, query: String) -> Self {
+        self.query = Some(query);
+        self
+    }
+
+    /// Adds an entity to the intent.
+    ///
+    /// # Parameters
+    ///
+    /// * `entity` - The entity to add.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowIntent` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowEntity, VoiceflowIntent};
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string())
+    ///     .add_entity(VoiceflowEntity::new("size".to_string(), "large".to_string()));
+    /// ```
+    pub fn add_entity(mut self, entity: VoiceflowEntity) -> Self {
+        self.entities.push(entity);
+        self
+    }
+
+    /// Sets the confidence of the recognition.
+    ///
+    /// # Parameters
+    ///
+    /// * `confidence` - The confidence from 0 to 1, clamped to this range.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowIntent` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string()).set_confidence(0.92);
+    /// assert_eq!(intent.confidence(), Some(0.92));
+    /// ```
+    pub fn set_confidence(mut self, confidence: f64) -> Self {
+        self.confidence = Some(confidence.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Returns the name of the intent.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string());
+    /// assert_eq!(intent.name(), "order_pizza");
+    /// ```
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns the user's input the intent was recognized in.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional query string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string());
+    /// assert!(intent.query().is_none());
+    /// ```
+    pub fn query(&self) -> &Option<String> {
+        &self.query
+    }
+
+    /// Returns the entities of the intent.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of `VoiceflowEntity`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string());
+    /// assert!(intent.entities().is_empty());
+    /// ```
+    pub fn entities(&self) -> &Vec<VoiceflowEntity> {
+        &self.entities
+    }
+
+    /// Returns the confidence of the recognition.
+    ///
+    /// # Returns
+    ///
+    /// The optional confidence from 0 to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowIntent;
+    ///
+    /// let intent = VoiceflowIntent::new("order_pizza".to_string());
+    /// assert_eq!(intent.confidence(), None);
+    /// ```
+    pub fn confidence(&self) -> Option<f64> {
+        self.confidence
+    }
+}
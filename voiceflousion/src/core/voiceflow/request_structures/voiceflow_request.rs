@@ -0,0 +1,163 @@
+use serde_json::Value;
+use crate::core::voiceflow::{State, VoiceflowIntent};
+
+/// Represents the kind of a request to the Voiceflow dialog.
+#[derive(Debug, Clone)]
+pub enum VoiceflowRequestKind {
+    /// Launch of the dialog.
+    Launch,
+    /// Text message of the user.
+    Text(String),
+    /// Button press with the payload of the button.
+    Button(Value),
+    /// Event with its name and the optional payload.
+    Event(String, Option<Value>),
+    /// Intent recognized in the user's input.
+    Intent(VoiceflowIntent),
+    /// Custom path with its name and the optional payload.
+    Path(String, Option<Value>),
+}
+
+/// Represents a request to the Voiceflow dialog made with the user's input.
+///
+/// `VoiceflowRequest` is passed to the `Middleware` hooks before it is sent to Voiceflow,
+/// so the middlewares can rewrite the input or add variables to the state of the dialog.
+#[derive(Debug, Clone)]
+pub struct VoiceflowRequest {
+    /// The chat ID of the user.
+    chat_id: String,
+    /// The kind of the request.
+    kind: VoiceflowRequestKind,
+    /// The optional state for updating the variables of the dialog.
+    state: Option<State>,
+}
+
+impl VoiceflowRequest {
+    /// Creates a new `VoiceflowRequest`.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `kind` - The kind of the request.
+    /// * `state` - The optional state for updating the variables of the dialog.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Text("Hello".to_string()), None);
+    /// ```
+    pub fn new(chat_id: String, kind: VoiceflowRequestKind, state: Option<State>) -> Self {
+        Self {
+            chat_id,
+            kind,
+            state
+        }
+    }
+
+    /// Returns a reference to the chat ID of the user.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the chat ID string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Launch, None);
+    /// assert_eq!(request.chat_id(), "chat_id");
+    /// ```
+    pub fn chat_id(&self) -> &String {
+        &self.chat_id
+    }
+
+    /// Returns a reference to the kind of the request.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `VoiceflowRequestKind`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Launch, None);
+    /// let kind = request.kind();
+    /// ```
+    pub fn kind(&self) -> &VoiceflowRequestKind {
+        &self.kind
+    }
+
+    /// Replaces the kind of the request.
+    ///
+    /// # Parameters
+    ///
+    /// * `kind` - The new kind of the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let mut request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Text("Hola".to_string()), None);
+    /// request.set_kind(VoiceflowRequestKind::Text("Hello".to_string()));
+    /// ```
+    pub fn set_kind(&mut self, kind: VoiceflowRequestKind) {
+        self.kind = kind;
+    }
+
+    /// Returns a reference to the optional state of the request.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `State`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Launch, None);
+    /// assert!(request.state().is_none());
+    /// ```
+    pub fn state(&self) -> &Option<State> {
+        &self.state
+    }
+
+    /// Adds the variables to the state of the request.
+    ///
+    /// # Parameters
+    ///
+    /// * `variables` - The JSON object with the variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::voiceflow::{VoiceflowRequest, VoiceflowRequestKind};
+    ///
+    /// let mut request = VoiceflowRequest::new("chat_id".to_string(), VoiceflowRequestKind::Launch, None);
+    /// request.add_variables(json!({"user_name": "John"}));
+    /// assert_eq!(request.state().as_ref().unwrap().len(), 1);
+    /// ```
+    pub fn add_variables(&mut self, variables: Value) {
+        self.state.get_or_insert_with(State::default).push(variables);
+    }
+
+    /// Destructures the request into its kind and state.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the kind and the optional state.
+    pub(crate) fn into_parts(self) -> (VoiceflowRequestKind, Option<State>) {
+        (self.kind, self.state)
+    }
+}
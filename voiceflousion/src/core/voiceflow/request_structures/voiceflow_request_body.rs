@@ -1,12 +1,12 @@
 use serde::Serialize;
-use crate::core::voiceflow::request_structures::State;
+use crate::core::voiceflow::request_structures::{State, VoiceflowRequestConfig};
 use crate::core::voiceflow::request_structures::action::Action;
 use crate::core::voiceflow::VoiceflowSession;
 
 /// Represents the request body for a Voiceflow API call.
 ///
 /// `VoiceflowRequestBody` contains the action to be performed, the session information,
-/// and the optional state for the session.
+/// the optional state for the session and the optional config of the request.
 #[derive(Debug, Serialize)]
 pub(crate) struct VoiceflowRequestBody<'a> {
     /// The action to be performed in the Voiceflow API.
@@ -19,6 +19,10 @@ pub(crate) struct VoiceflowRequestBody<'a> {
     /// The optional state for the Voiceflow API.
     #[serde(skip_serializing_if = "Option::is_none")]
     state: Option<State>,
+
+    /// The optional config of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<&'a VoiceflowRequestConfig>,
 }
 
 impl<'a> VoiceflowRequestBody<'a> {
@@ -40,6 +44,7 @@ pub(crate) struct VoiceflowRequestBodyBuilder<'a> {
     action: Action,
     session: Option<&'a VoiceflowSession>,
     state: Option<State>,
+    config: Option<&'a VoiceflowRequestConfig>,
 }
 
 impl<'a> VoiceflowRequestBodyBuilder<'a> {
@@ -57,6 +62,7 @@ impl<'a> VoiceflowRequestBodyBuilder<'a> {
             action,
             session: None,
             state: None,
+            config: None,
         }
     }
 
@@ -88,6 +94,20 @@ impl<'a> VoiceflowRequestBodyBuilder<'a> {
         self
     }
 
+    /// Sets the config for the `VoiceflowRequestBody`.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - The optional config of the request.
+    ///
+    /// # Returns
+    ///
+    /// The `VoiceflowRequestBodyBuilder` with the config set.
+    pub fn config(mut self, config: Option<&'a VoiceflowRequestConfig>) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Builds the `VoiceflowRequestBody`.
     ///
     /// # Returns
@@ -98,6 +118,7 @@ impl<'a> VoiceflowRequestBodyBuilder<'a> {
             action: self.action,
             session: self.session,
             state: self.state,
+            config: self.config,
         }
     }
 }
\ No newline at end of file
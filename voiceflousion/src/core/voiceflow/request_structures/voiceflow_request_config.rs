@@ -0,0 +1,205 @@
+use serde::Serialize;
+
+/// Represents the `config` of the requests to the Voiceflow dialog.
+///
+/// `VoiceflowRequestConfig` controls the traces Voiceflow sends in the response. The options
+/// left unset are not sent, so Voiceflow applies its defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct VoiceflowRequestConfig {
+    /// Whether the speak traces contain the text-to-speech audio.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tts: Option<bool>,
+    /// Whether the SSML tags are removed from the texts.
+    #[serde(rename = "stripSSML", skip_serializing_if = "Option::is_none")]
+    strip_ssml: Option<bool>,
+    /// Whether all the stop types of the project end the turn.
+    #[serde(rename = "stopAll", skip_serializing_if = "Option::is_none")]
+    stop_all: Option<bool>,
+    /// The trace types excluded from the response.
+    #[serde(rename = "excludeTypes", skip_serializing_if = "Option::is_none")]
+    exclude_types: Option<Vec<String>>,
+}
+
+impl VoiceflowRequestConfig {
+    /// The trace types Voiceflousion does not deliver to the clients.
+    pub const IGNORED_TRACE_TYPES: [&'static str; 6] = ["block", "debug", "flow", "log", "path", "entity-filling"];
+
+    /// Creates a new `VoiceflowRequestConfig` excluding the trace types Voiceflousion does not deliver to the clients.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowRequestConfig`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::without_ignored_traces();
+    /// assert_eq!(config.exclude_types().as_ref().unwrap().len(), VoiceflowRequestConfig::IGNORED_TRACE_TYPES.len());
+    /// ```
+    pub fn without_ignored_traces() -> Self {
+        Self::default()
+            .set_exclude_types(Self::IGNORED_TRACE_TYPES.iter().map(|trace_type| trace_type.to_string()).collect())
+    }
+
+    /// Sets whether the speak traces contain the text-to-speech audio.
+    ///
+    /// # Parameters
+    ///
+    /// * `tts` - Whether the text-to-speech audio is generated.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowRequestConfig` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_tts(false);
+    /// ```
+    pub fn set_tts(mut self, tts: bool) -> Self {
+        self.tts = Some(tts);
+        self
+    }
+
+    /// Sets whether the SSML tags are removed from the texts.
+    ///
+    /// # Parameters
+    ///
+    /// * `strip_ssml` - Whether the SSML tags are removed.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowRequestConfig` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_strip_ssml(true);
+    /// ```
+    pub fn set_strip_ssml(mut self, strip_ssml: bool) -> Self {
+        self.strip_ssml = Some(strip_ssml);
+        self
+    }
+
+    /// Sets whether all the stop types of the project end the turn.
+    ///
+    /// # Parameters
+    ///
+    /// * `stop_all` - Whether all the stop types end the turn.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowRequestConfig` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_stop_all(true);
+    /// ```
+    pub fn set_stop_all(mut self, stop_all: bool) -> Self {
+        self.stop_all = Some(stop_all);
+        self
+    }
+
+    /// Sets the trace types excluded from the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `exclude_types` - The trace types to exclude.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowRequestConfig` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_exclude_types(vec!["debug".to_string(), "speak".to_string()]);
+    /// ```
+    pub fn set_exclude_types(mut self, exclude_types: Vec<String>) -> Self {
+        self.exclude_types = Some(exclude_types);
+        self
+    }
+
+    /// Returns whether the speak traces contain the text-to-speech audio.
+    ///
+    /// # Returns
+    ///
+    /// The optional flag, `None` if Voiceflow applies its default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_tts(false);
+    /// assert_eq!(config.tts(), Some(false));
+    /// ```
+    pub fn tts(&self) -> Option<bool> {
+        self.tts
+    }
+
+    /// Returns whether the SSML tags are removed from the texts.
+    ///
+    /// # Returns
+    ///
+    /// The optional flag, `None` if Voiceflow applies its default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default();
+    /// assert_eq!(config.strip_ssml(), None);
+    /// ```
+    pub fn strip_ssml(&self) -> Option<bool> {
+        self.strip_ssml
+    }
+
+    /// Returns whether all the stop types of the project end the turn.
+    ///
+    /// # Returns
+    ///
+    /// The optional flag, `None` if Voiceflow applies its default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default().set_stop_all(true);
+    /// assert_eq!(config.stop_all(), Some(true));
+    /// ```
+    pub fn stop_all(&self) -> Option<bool> {
+        self.stop_all
+    }
+
+    /// Returns the trace types excluded from the response.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional vector of the trace types, `None` if no types are excluded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowRequestConfig;
+    ///
+    /// let config = VoiceflowRequestConfig::default();
+    /// assert!(config.exclude_types().is_none());
+    /// ```
+    pub fn exclude_types(&self) -> &Option<Vec<String>> {
+        &self.exclude_types
+    }
+}
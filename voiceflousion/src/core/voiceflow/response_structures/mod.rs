@@ -2,6 +2,7 @@ mod voiceflow_response_block_type;
 mod voiceflow_response;
 mod voiceflow_response_block;
 mod voiceflow_response_block_processor;
+mod sse_event_parser;
 
 pub(crate) use self::voiceflow_response::VoiceflowResponse;
 pub(crate) use self::voiceflow_response_block::VoiceflowResponseBlock;
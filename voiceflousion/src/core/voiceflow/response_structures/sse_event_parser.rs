@@ -0,0 +1,107 @@
+/// Incremental parser of Server-Sent Events (SSE).
+///
+/// `SseEventParser` accepts chunks of the response body as they arrive and returns
+/// the data of every event that was completed by the chunk. Chunks may split lines and
+/// even UTF-8 characters, incomplete parts are kept until the next chunk.
+#[derive(Debug, Default)]
+pub(crate) struct SseEventParser {
+    /// Bytes of the incomplete line.
+    buffer: Vec<u8>,
+    /// The name of the current event.
+    current_event: Option<String>,
+    /// The data of the current event.
+    current_data: String,
+}
+
+impl SseEventParser {
+    /// Creates a new `SseEventParser`.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SseEventParser`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a chunk of the response body into the parser.
+    ///
+    /// # Parameters
+    ///
+    /// * `chunk` - The chunk of bytes received from the response.
+    ///
+    /// # Returns
+    ///
+    /// A vector of strings, each representing the data of a completed trace event.
+    pub(crate) fn push_chunk(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(position) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line_bytes: Vec<u8> = self.buffer.drain(..=position).collect();
+            let line = String::from_utf8_lossy(&line_bytes);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if let Some(event) = self.process_line(line) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// Finishes parsing and returns the data of the last event, if the stream ended without a blank line.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the data of the last trace event.
+    pub(crate) fn finish(mut self) -> Option<String> {
+        if !self.buffer.is_empty() {
+            let line_bytes = std::mem::take(&mut self.buffer);
+            let line = String::from_utf8_lossy(&line_bytes).to_string();
+            if let Some(event) = self.process_line(line.trim_end_matches('\r')) {
+                return Some(event);
+            }
+        }
+        self.complete_event()
+    }
+
+    /// Processes a single line of the event stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `line` - The line without the line break.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the data of the event completed by this line.
+    fn process_line(&mut self, line: &str) -> Option<String> {
+        if let Some(data) = line.strip_prefix("data:") {
+            if !self.current_data.is_empty() {
+                self.current_data.push('\n');
+            }
+            self.current_data.push_str(data.trim());
+        } else if let Some(event) = line.strip_prefix("event:") {
+            self.current_event = Some(event.trim().to_string());
+        } else if line.is_empty() {
+            return self.complete_event();
+        }
+        None
+    }
+
+    /// Completes the current event.
+    ///
+    /// Only events without a name or with the `trace` name are returned.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the data of the completed trace event.
+    fn complete_event(&mut self) -> Option<String> {
+        let event = self.current_event.take();
+        if self.current_data.is_empty() {
+            return None;
+        }
+        let data = std::mem::take(&mut self.current_data);
+        match event.as_deref() {
+            None | Some("trace") => Some(data),
+            _ => None,
+        }
+    }
+}
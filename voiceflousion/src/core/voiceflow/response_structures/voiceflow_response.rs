@@ -1,12 +1,19 @@
 use std::str::Lines;
 use reqwest::Response;
 use serde_json::Value;
+use tokio::sync::mpsc;
+use tracing::error;
+use crate::core::voiceflow::dialog_blocks::VoiceflowText;
+use crate::core::voiceflow::response_structures::sse_event_parser::SseEventParser;
 use crate::core::voiceflow::response_structures::voiceflow_response_block::VoiceflowResponseBlock;
 use crate::core::voiceflow::response_structures::voiceflow_response_block_type::VoiceflowResponseBlockType;
-use crate::core::voiceflow::VoiceflowMessage;
-use crate::core::voiceflow::voiceflow_message::VoiceflowMessageBuilder;
+use crate::core::voiceflow::{VoiceflowBlock, VoiceflowBlockStream, VoiceflowMessage};
+use crate::core::voiceflow::voiceflow_message::{VoiceflowMessageBuilder, VoiceflowMessageStreamBuilder};
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
+/// The number of parsed blocks buffered in the stream before reading of the response is paused.
+const STREAM_BUFFER_SIZE: usize = 16;
+
 /// Represents a response from the Voiceflow API.
 ///
 /// `VoiceflowResponse` wraps the HTTP response and provides methods to process it.
@@ -38,25 +45,68 @@ impl VoiceflowResponse{
         let text = self.response.text().await.map_err(|error| VoiceflousionError::VoiceflowResponseReadingError(error.to_string()))?;
         let events = parse_sse(text.lines());
 
-        let mut blocks = Vec::new();
-        for data in events {
-            if let Ok(json) = serde_json::from_str::<Value>(&data) {
-                let response_type = get_response_type(&json);
-                match response_type {
-                    VoiceflowResponseBlockType::Text
-                    | VoiceflowResponseBlockType::Choice
-                    | VoiceflowResponseBlockType::CardV2
-                    | VoiceflowResponseBlockType::Visual
-                    | VoiceflowResponseBlockType::Carousel
-                    | VoiceflowResponseBlockType::End => {
-                        let block = VoiceflowResponseBlock::new(response_type, json);
-                        blocks.push(block);
-                    },
-                    _ => {}
+        let blocks = events.iter().filter_map(|data| to_response_block(data)).collect();
+        Ok(blocks)
+    }
+
+    /// Converts the response into a `VoiceflowBlockStream`.
+    ///
+    /// The response body is read chunk by chunk in a separate task, and every `VoiceflowBlock`
+    /// is sent to the stream as soon as it is complete.
+    ///
+    /// # Parameters
+    ///
+    /// * `invalid_response_message` - The text sent to the stream if the response cannot be read.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` with the blocks of the response.
+    pub(crate) fn to_stream(self, invalid_response_message: String) -> VoiceflowBlockStream {
+        let (sender, receiver) = mpsc::channel::<VoiceflowBlock>(STREAM_BUFFER_SIZE);
+        let mut response = self.response;
+
+        tokio::spawn(async move {
+            let mut parser = SseEventParser::new();
+            let mut message_builder = VoiceflowMessageStreamBuilder::new();
+
+            loop {
+                let events = match response.chunk().await {
+                    Ok(Some(chunk)) => parser.push_chunk(&chunk),
+                    Ok(None) => break,
+                    Err(error) => {
+                        let error = VoiceflousionError::VoiceflowResponseReadingError(error.to_string());
+                        error!(%error, "Failed to read the Voiceflow stream");
+                        let _ = sender.send(VoiceflowBlock::Text(VoiceflowText::new(invalid_response_message))).await;
+                        return;
+                    }
+                };
+
+                // Send every completed block, stop reading if the stream was dropped
+                for block in events.iter().filter_map(|data| to_response_block(data)) {
+                    for voiceflow_block in message_builder.push_block(block) {
+                        if sender.send(voiceflow_block).await.is_err() {
+                            return;
+                        }
+                    }
                 }
             }
-        }
-        Ok(blocks)
+
+            // Process the last event and the held back blocks
+            if let Some(block) = parser.finish().as_deref().and_then(to_response_block) {
+                for voiceflow_block in message_builder.push_block(block) {
+                    if sender.send(voiceflow_block).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            for voiceflow_block in message_builder.finish() {
+                if sender.send(voiceflow_block).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        VoiceflowBlockStream::new(receiver)
     }
 
     /// Converts the response into a `VoiceflowMessage`.
@@ -110,6 +160,35 @@ fn parse_sse(lines: Lines) -> Vec<String> {
     events
 }
 
+/// Converts the data of an SSE event into a `VoiceflowResponseBlock`.
+///
+/// # Parameters
+///
+/// * `data` - The data of the SSE event.
+///
+/// # Returns
+///
+/// An `Option` containing the `VoiceflowResponseBlock` if the event is a supported trace.
+fn to_response_block(data: &str) -> Option<VoiceflowResponseBlock> {
+    let json = serde_json::from_str::<Value>(data).ok()?;
+    let response_type = get_response_type(&json);
+    match response_type {
+        VoiceflowResponseBlockType::Text
+        | VoiceflowResponseBlockType::Choice
+        | VoiceflowResponseBlockType::CardV2
+        | VoiceflowResponseBlockType::Visual
+        | VoiceflowResponseBlockType::Carousel
+        | VoiceflowResponseBlockType::End
+        | VoiceflowResponseBlockType::Speak
+        | VoiceflowResponseBlockType::NoReply
+        | VoiceflowResponseBlockType::KnowledgeBase
+        | VoiceflowResponseBlockType::Completion
+        | VoiceflowResponseBlockType::CustomAction => Some(VoiceflowResponseBlock::new(response_type, json)),
+        // Path, flow, debug and entity filling traces carry no content for the user
+        _ => None
+    }
+}
+
 /// Determines the type of Voiceflow response block from JSON data.
 ///
 /// # Parameters
@@ -1,4 +1,5 @@
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use tracing::warn;
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowCustomAction, VoiceflowImage, VoiceflowKnowledgeBase, VoiceflowNoReply, VoiceflowText};
 use crate::core::voiceflow::response_structures::{VoiceflowResponseBlock, VoiceflowResponseBlockType};
 use crate::core::voiceflow::{VoiceflowBlock, VoiceflowMessage};
 use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
@@ -52,6 +53,27 @@ impl VoiceflowResponseBlockProcessor {
                 message.push(VoiceflowBlock::End);
                 VoiceflowButtonsOption::Empty
             },
+            VoiceflowResponseBlockType::Speak => {
+                // A speak block without an audio source is just a text
+                if block.json()["trace"]["payload"].get("src").is_some() {
+                    self.extract_audio(message, block);
+                    VoiceflowButtonsOption::Empty
+                } else {
+                    self.extract_text(block)
+                }
+            },
+            VoiceflowResponseBlockType::NoReply => {
+                self.extract_no_reply(message, block);
+                VoiceflowButtonsOption::Empty
+            },
+            VoiceflowResponseBlockType::KnowledgeBase => {
+                self.extract_knowledge_base(message, block);
+                VoiceflowButtonsOption::Empty
+            },
+            VoiceflowResponseBlockType::CustomAction => {
+                self.extract_custom_action(message, block);
+                VoiceflowButtonsOption::Empty
+            },
             _ => VoiceflowButtonsOption::Empty,
         }
     }
@@ -86,7 +108,7 @@ impl VoiceflowResponseBlockProcessor {
     /// An updated VoiceflowButtonsOption.
     fn extract_text(&self, block: VoiceflowResponseBlock) -> VoiceflowButtonsOption {
         let optional_text = VoiceflowText::from_value(block.json()).unwrap_or_else(|error| {
-            println!("{:?}", error);
+            warn!(%error, "Failed to parse the Voiceflow trace");
             Some(VoiceflowText::error_default("Invalid voiceflow text format"))
         });
         if let Some(text) = optional_text {
@@ -110,7 +132,7 @@ impl VoiceflowResponseBlockProcessor {
                 }
             }
             Err(error) => {
-                println!("{:?}", error);
+                warn!(%error, "Failed to parse the Voiceflow trace");
                 self.add_error_text_to_message(message, "Invalid voiceflow buttons format");
             }
         }
@@ -130,7 +152,7 @@ impl VoiceflowResponseBlockProcessor {
                 }
             }
             Err(error) => {
-                println!("{:?}", error);
+                warn!(%error, "Failed to parse the Voiceflow trace");
                 self.add_error_text_to_message(message, "Invalid voiceflow card format");
             }
         }
@@ -150,7 +172,7 @@ impl VoiceflowResponseBlockProcessor {
                 }
             }
             Err(error) => {
-                println!("{:?}", error);
+                warn!(%error, "Failed to parse the Voiceflow trace");
                 self.add_error_text_to_message(message, "Invalid voiceflow image format");
             }
         }
@@ -170,12 +192,92 @@ impl VoiceflowResponseBlockProcessor {
                 }
             }
             Err(error) => {
-                println!("{:?}", error);
+                warn!(%error, "Failed to parse the Voiceflow trace");
                 self.add_error_text_to_message(message, "Invalid voiceflow carousel format");
             }
         }
     }
 
+    /// Extracts an audio from a block and adds it to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The message to update.
+    /// * `block` - The block to extract the audio from.
+    fn extract_audio(&self, message: &mut VoiceflowMessage, block: VoiceflowResponseBlock) {
+        match VoiceflowAudio::from_value(block.json()) {
+            Ok(optional_audio) => {
+                if let Some(audio) = optional_audio {
+                    message.push(VoiceflowBlock::Audio(audio));
+                }
+            }
+            Err(error) => {
+                warn!(%error, "Failed to parse the Voiceflow trace");
+                self.add_error_text_to_message(message, "Invalid voiceflow audio format");
+            }
+        }
+    }
+
+    /// Extracts a no-reply timeout from a block and adds it to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The message to update.
+    /// * `block` - The block to extract the no-reply timeout from.
+    fn extract_no_reply(&self, message: &mut VoiceflowMessage, block: VoiceflowResponseBlock) {
+        match VoiceflowNoReply::from_value(block.json()) {
+            Ok(optional_no_reply) => {
+                if let Some(no_reply) = optional_no_reply {
+                    message.push(VoiceflowBlock::NoReply(no_reply));
+                }
+            }
+            Err(error) => {
+                // The no-reply block is not shown to the user, so the error text is not added
+                warn!(%error, "Failed to parse the Voiceflow trace");
+            }
+        }
+    }
+
+    /// Extracts knowledge base sources from a block and adds them to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The message to update.
+    /// * `block` - The block to extract the knowledge base sources from.
+    fn extract_knowledge_base(&self, message: &mut VoiceflowMessage, block: VoiceflowResponseBlock) {
+        match VoiceflowKnowledgeBase::from_value(block.json()) {
+            Ok(optional_knowledge_base) => {
+                if let Some(knowledge_base) = optional_knowledge_base {
+                    message.push(VoiceflowBlock::KnowledgeBase(knowledge_base));
+                }
+            }
+            Err(error) => {
+                // The knowledge base sources are not shown to the user, so the error text is not added
+                warn!(%error, "Failed to parse the Voiceflow trace");
+            }
+        }
+    }
+
+    /// Extracts a custom action from a block and adds it to the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The message to update.
+    /// * `block` - The block to extract the custom action from.
+    fn extract_custom_action(&self, message: &mut VoiceflowMessage, block: VoiceflowResponseBlock) {
+        match VoiceflowCustomAction::from_value(block.json()) {
+            Ok(optional_custom_action) => {
+                if let Some(custom_action) = optional_custom_action {
+                    message.push(VoiceflowBlock::CustomAction(custom_action));
+                }
+            }
+            Err(error) => {
+                warn!(%error, "Failed to parse the Voiceflow trace");
+                self.add_error_text_to_message(message, "Invalid voiceflow custom action format");
+            }
+        }
+    }
+
     /// Adds buttons with options to the message.
     ///
     /// # Parameters
@@ -193,7 +295,7 @@ impl VoiceflowResponseBlockProcessor {
                 }
             }
             Err(error) => {
-                println!("{:?}", error);
+                warn!(%error, "Failed to parse the Voiceflow trace");
                 self.add_error_text_to_message(message, "Invalid voiceflow buttons format");
             }
         }
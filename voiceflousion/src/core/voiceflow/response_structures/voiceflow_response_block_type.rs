@@ -22,6 +22,33 @@ pub(crate) enum VoiceflowResponseBlockType {
     /// An end block.
     End,
 
+    /// A speak block with an audio or a message.
+    Speak,
+
+    /// A path block.
+    Path,
+
+    /// A flow block.
+    Flow,
+
+    /// A debug block.
+    Debug,
+
+    /// A no-reply block.
+    NoReply,
+
+    /// A knowledge base block.
+    KnowledgeBase,
+
+    /// An entity filling block.
+    EntityFilling,
+
+    /// A completion block with a chunk of the streamed text.
+    Completion,
+
+    /// A custom action or extension block.
+    CustomAction,
+
     /// A block type that is not recognized.
     None,
 }
@@ -43,7 +70,18 @@ impl VoiceflowResponseBlockType {
             "visual" => VoiceflowResponseBlockType::Visual,
             "carousel" => VoiceflowResponseBlockType::Carousel,
             "end" => VoiceflowResponseBlockType::End,
-            _ => VoiceflowResponseBlockType::None
+            "speak" => VoiceflowResponseBlockType::Speak,
+            "path" => VoiceflowResponseBlockType::Path,
+            "flow" => VoiceflowResponseBlockType::Flow,
+            "debug" => VoiceflowResponseBlockType::Debug,
+            "no-reply" => VoiceflowResponseBlockType::NoReply,
+            "knowledgeBase" => VoiceflowResponseBlockType::KnowledgeBase,
+            "entity-filling" => VoiceflowResponseBlockType::EntityFilling,
+            "completion" => VoiceflowResponseBlockType::Completion,
+            // Internal trace types that are not a part of the dialog
+            "" | "block" | "log" => VoiceflowResponseBlockType::None,
+            // Any other trace type is a custom action or an extension
+            _ => VoiceflowResponseBlockType::CustomAction
         }
     }
 }
\ No newline at end of file
@@ -1,10 +1,15 @@
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use serde::{Deserialize, Serialize};
+use crate::core::traits::Versioned;
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowCustomAction, VoiceflowImage, VoiceflowKnowledgeBase, VoiceflowNoReply, VoiceflowText};
 
 /// Represents a block in a Voiceflow dialog.
 ///
 /// `VoiceflowBlock` is an enum that can represent various types of blocks
-/// such as text, image, buttons, card, carousel, or an end block.
-#[derive(Debug, Clone)]
+/// such as text, image, buttons, card, carousel, audio, custom action, no-reply timeout,
+/// knowledge base sources, or an end block.
+/// It is serialized as an object with the `type` of the block and the `block` content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "block", rename_all = "snake_case")]
 pub enum VoiceflowBlock {
     /// A text block containing a message.
     Text(VoiceflowText),
@@ -21,6 +26,20 @@ pub enum VoiceflowBlock {
     /// A carousel block containing multiple cards.
     Carousel(VoiceflowCarousel),
 
+    /// An audio block from a speak trace.
+    Audio(VoiceflowAudio),
+
+    /// A custom action block with an arbitrary payload.
+    CustomAction(VoiceflowCustomAction),
+
+    /// A no-reply block containing the timeout for the user's reply.
+    NoReply(VoiceflowNoReply),
+
+    /// A knowledge base block containing the sources of an answer.
+    KnowledgeBase(VoiceflowKnowledgeBase),
+
     /// An end block indicating the end of the dialog.
     End,
-}
\ No newline at end of file
+}
+
+impl Versioned for VoiceflowBlock {}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_core::Stream;
+use tokio::sync::mpsc::Receiver;
+use crate::core::voiceflow::VoiceflowBlock;
+
+/// Represents a stream of blocks from a Voiceflow response.
+///
+/// `VoiceflowBlockStream` yields every `VoiceflowBlock` as soon as it is parsed from the
+/// Voiceflow `/stream` endpoint, without waiting for the whole dialog turn to finish.
+/// Dropping the stream stops reading of the response.
+#[derive(Debug)]
+pub struct VoiceflowBlockStream {
+    /// The receiver of the parsed blocks.
+    receiver: Receiver<VoiceflowBlock>,
+}
+
+impl VoiceflowBlockStream {
+    /// Creates a new `VoiceflowBlockStream`.
+    ///
+    /// # Parameters
+    ///
+    /// * `receiver` - The receiver of the parsed blocks.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowBlockStream`.
+    pub(crate) fn new(receiver: Receiver<VoiceflowBlock>) -> Self {
+        Self { receiver }
+    }
+
+    /// Waits for the next block of the response.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the next `VoiceflowBlock`, or `None` if the response is finished.
+    pub async fn next_block(&mut self) -> Option<VoiceflowBlock> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for VoiceflowBlockStream {
+    type Item = VoiceflowBlock;
+
+    /// Polls the next block of the response.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
@@ -1,15 +1,26 @@
+use std::time::Instant;
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, ACCEPT};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 use crate::core::subtypes::HttpClient;
-use crate::core::voiceflow::request_structures::{ActionBuilder, ActionType, VoiceflowRequestBody, VoiceflowRequestBodyBuilder};
+use reqwest::{RequestBuilder, Response};
+use tokio::sync::mpsc;
+use tracing::{error, info_span, Instrument};
+use crate::core::metrics::{record_histogram, VOICEFLOW_REQUEST_DURATION_SECONDS};
+use crate::core::voiceflow::request_structures::{Action, ActionBuilder, ActionType, VoiceflowIntent, VoiceflowRequestBody, VoiceflowRequestBodyBuilder, VoiceflowRequestConfig};
 use crate::core::voiceflow::response_structures::VoiceflowResponse;
-use crate::core::voiceflow::{State, VoiceflowBlock, VoiceflowMessage, VoiceflowSession};
+use crate::core::voiceflow::{State, VoiceflowBlock, VoiceflowBlockStream, VoiceflowMessage, VoiceflowSession, VoiceflowTranscript, VoiceflowUserState};
 use crate::core::voiceflow::dialog_blocks::VoiceflowText;
-use crate::errors::VoiceflousionError;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
 /// Voiceflow API runtime interaction URL.
 static VOICEFLOW_API_URL: &str = "https://general-runtime.voiceflow.com/v2beta1/interact";
 
+/// Voiceflow Dialog Manager API user state URL.
+static VOICEFLOW_STATE_URL: &str = "https://general-runtime.voiceflow.com/state/user";
+
+/// Voiceflow Transcript API URL.
+static VOICEFLOW_TRANSCRIPTS_URL: &str = "https://api.voiceflow.com/v2/transcripts";
+
 /// Represents a client for the Voiceflow API.
 ///
 /// `VoiceflowClient` is used to interact with the Voiceflow API using the provided API key,
@@ -26,7 +37,9 @@ pub struct VoiceflowClient {
     /// The message to return when an unexpected error occurs.
     unavailable_message: String,
     /// The message to return when the bot sends invalid response.
-    invalid_response_message: String
+    invalid_response_message: String,
+    /// The optional config sent with the dialog requests.
+    request_config: Option<VoiceflowRequestConfig>
 }
 
 impl VoiceflowClient {
@@ -59,7 +72,8 @@ impl VoiceflowClient {
             project_id,
             client: HttpClient::new(max_sessions_per_moment, connection_duration),
             unavailable_message: "Bot is temporary unavailable".to_string(),
-            invalid_response_message: "Can't read response from bot".to_string()
+            invalid_response_message: "Can't read response from bot".to_string(),
+            request_config: None
         }
     }
 
@@ -108,6 +122,32 @@ impl VoiceflowClient {
         self
     }
 
+    /// Sets the config sent with the dialog requests.
+    ///
+    /// The config controls the text-to-speech, the SSML stripping and the trace types
+    /// Voiceflow sends in the responses.
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - The config of the dialog requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflowClient` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::{VoiceflowClient, VoiceflowRequestConfig};
+    ///
+    /// let vf_client = VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None)
+    /// .set_request_config(VoiceflowRequestConfig::without_ignored_traces().set_tts(false));
+    /// ```
+    pub fn set_request_config(mut self, config: VoiceflowRequestConfig) -> Self {
+        self.request_config = Some(config);
+        self
+    }
+
     /// Returns the version ID.
     ///
     /// # Returns
@@ -165,6 +205,25 @@ impl VoiceflowClient {
         &self.voiceflow_api_key
     }
 
+    /// Returns the config sent with the dialog requests.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional `VoiceflowRequestConfig`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    ///
+    /// let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    /// let request_config = vf_client.request_config();
+    /// ```
+    pub fn request_config(&self) -> &Option<VoiceflowRequestConfig> {
+        &self.request_config
+    }
+
     /// Returns the maximum number of idle connections per host.
     ///
     /// # Returns
@@ -257,7 +316,7 @@ impl VoiceflowClient {
     /// ```
     pub async fn launch_dialog(&self, session: &VoiceflowSession, state: State) -> VoiceflowMessage {
         let action = ActionBuilder::new(ActionType::Launch).build();
-        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(Some(state)).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(Some(state)).config(self.request_config.as_ref()).build();
         self.send_stream_request(body).await
     }
 
@@ -297,7 +356,7 @@ impl VoiceflowClient {
     /// ```
     pub async fn send_message(&self, session: &VoiceflowSession, state: Option<State>, text: &String) -> VoiceflowMessage {
         let action = ActionBuilder::new(ActionType::Text).text(text.clone()).build();
-        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
         self.send_stream_request(body).await
     }
 
@@ -338,50 +397,795 @@ impl VoiceflowClient {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn choose_button(&self, session: &VoiceflowSession, state: Option<State>, mut payload: Value) -> VoiceflowMessage {
-        let path = payload.as_object_mut().unwrap().remove("path").expect("Button has no path!").as_str().unwrap().to_string();
-        let action = ActionBuilder::new(ActionType::Path(path.to_string())).path(payload).build();
-        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).build();
+    pub async fn choose_button(&self, session: &VoiceflowSession, state: Option<State>, payload: Value) -> VoiceflowMessage {
+        let action = build_button_action(payload);
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
         self.send_stream_request(body).await
     }
 
-    /// Sends a request to the Voiceflow API and returns the response.
+    /// Launches a dialog with the Voiceflow Bot chosen session and streams the response.
     ///
     /// # Parameters
     ///
-    /// * `body` - The request body to send.
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The state for variables in the bot for the session.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let state = State::default();
+    ///
+    ///     let mut stream = vf_client.launch_dialog_stream(&session, state).await;
+    ///     while let Some(block) = stream.next_block().await {
+    ///         println!("{:?}", block);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn launch_dialog_stream(&self, session: &VoiceflowSession, state: State) -> VoiceflowBlockStream {
+        let action = ActionBuilder::new(ActionType::Launch).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(Some(state)).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Sends a text message to the Voiceflow Bot's chosen session and streams the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `text` - The text message to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let state = State::default();
+    ///
+    ///     let stream = vf_client.send_message_stream(session, Some(state), &"Hello".to_string()).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_message_stream(&self, session: &VoiceflowSession, state: Option<State>, text: &String) -> VoiceflowBlockStream {
+        let action = ActionBuilder::new(ActionType::Text).text(text.clone()).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Sends a button selection to the Voiceflow Bot's chosen session and streams the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `payload` - The payload associated with the button.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let state = State::default();
+    ///
+    ///     let stream = vf_client.choose_button_stream(&session, Some(state), json!({"path": "path"})).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn choose_button_stream(&self, session: &VoiceflowSession, state: Option<State>, payload: Value) -> VoiceflowBlockStream {
+        let action = build_button_action(payload);
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Sends a path selection to the Voiceflow Bot's chosen session.
+    ///
+    /// It is used for resuming the dialog stopped on a custom action.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `path` - The name of the path.
+    /// * `payload` - The optional payload associated with the path.
     ///
     /// # Returns
     ///
     /// A `VoiceflowMessage` containing the response from the Voiceflow API.
-    async fn send_stream_request<'a>(&self, body: VoiceflowRequestBody<'a>) -> VoiceflowMessage{
-        let general_runtime_url = format!("{}/{}/{}/stream", VOICEFLOW_API_URL, &self.project_id, &self.version_id);
-        let response = self.client.post(general_runtime_url)
-            .header(AUTHORIZATION, &self.voiceflow_api_key)
-            .header(CONTENT_TYPE, "application/json")
-            .header(ACCEPT, "text/event-stream")
-            .body(body.to_json()).send().await;
-        let result_message = match response{
-            Ok(valid_response) => {
-                let voiceflow_response = VoiceflowResponse::new(valid_response);
-                voiceflow_response.to_message().await
-            },
-            Err(e) => {
-                let error = VoiceflousionError::VoiceflowRequestError(self.project_id.clone(), self.version_id.clone(), e.to_string());
-                println!("{:?}", error);
-                let mut message = VoiceflowMessage::default();
-                message.add_block(VoiceflowBlock::Text(VoiceflowText::new(self.unavailable_message.clone())));
-                Ok(message)
-            }
-        };
-        match result_message{
-            Ok(message) => message,
-            Err(error) =>{
-                println!("{:?}", error);
-                let mut message = VoiceflowMessage::default();
-                message.add_block(VoiceflowBlock::Text(VoiceflowText::new(self.invalid_response_message.clone())));
-                message
-            }
-        }
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let state = State::default();
+    ///
+    ///     let response = vf_client.choose_path(&session, Some(state), &"success".to_string(), Some(json!({"order": 1}))).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn choose_path(&self, session: &VoiceflowSession, state: Option<State>, path: &String, payload: Option<Value>) -> VoiceflowMessage {
+        let action = build_path_action(path, payload);
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_stream_request(body).await
+    }
+
+    /// Sends a path selection to the Voiceflow Bot's chosen session and streams the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `path` - The name of the path.
+    /// * `payload` - The optional payload associated with the path.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let stream = vf_client.choose_path_stream(&session, None, &"success".to_string(), None).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn choose_path_stream(&self, session: &VoiceflowSession, state: Option<State>, path: &String, payload: Option<Value>) -> VoiceflowBlockStream {
+        let action = build_path_action(path, payload);
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Sends an event to the Voiceflow Bot's chosen session.
+    ///
+    /// Events let the backend drive the dialog with structured notifications, like "order_shipped",
+    /// caught by the event triggers of the project.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `event` - The name of the event.
+    /// * `payload` - The optional JSON object with the fields sent along with the event.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowMessage` containing the response from the Voiceflow API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let response = vf_client.send_event(&session, None, &"order_shipped".to_string(), Some(json!({"order_id": 42}))).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_event(&self, session: &VoiceflowSession, state: Option<State>, event: &String, payload: Option<Value>) -> VoiceflowMessage {
+        let action = ActionBuilder::new(ActionType::Event).event(event.clone(), payload).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_stream_request(body).await
     }
-}
\ No newline at end of file
+
+    /// Sends an event to the Voiceflow Bot's chosen session and streams the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `event` - The name of the event.
+    /// * `payload` - The optional JSON object with the fields sent along with the event.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let stream = vf_client.send_event_stream(&session, None, &"order_shipped".to_string(), None).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_event_stream(&self, session: &VoiceflowSession, state: Option<State>, event: &String, payload: Option<Value>) -> VoiceflowBlockStream {
+        let action = ActionBuilder::new(ActionType::Event).event(event.clone(), payload).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Sends an intent to the Voiceflow Bot's chosen session.
+    ///
+    /// The intent bypasses the NLU of the Voiceflow project, so the intents recognized
+    /// by an external NLU can be sent with their entities and confidence.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `intent` - The intent to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowMessage` containing the response from the Voiceflow API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowEntity, VoiceflowIntent, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let intent = VoiceflowIntent::new("order_pizza".to_string())
+    ///         .add_entity(VoiceflowEntity::new("size".to_string(), "large".to_string()))
+    ///         .set_confidence(0.9);
+    ///
+    ///     let response = vf_client.send_intent(&session, None, &intent).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_intent(&self, session: &VoiceflowSession, state: Option<State>, intent: &VoiceflowIntent) -> VoiceflowMessage {
+        let action = ActionBuilder::new(ActionType::Intent).intent(intent).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_stream_request(body).await
+    }
+
+    /// Sends an intent to the Voiceflow Bot's chosen session and streams the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The optional state for variables in the bot for the session.
+    /// * `intent` - The intent to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response as soon as they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::{LockedSession, Session};
+    /// use voiceflousion::core::voiceflow::{State, VoiceflowClient, VoiceflowIntent, VoiceflowSession};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let locked_session = LockedSession::try_from_session(&session)?;
+    ///     let session = locked_session.voiceflow_session();
+    ///
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let intent = VoiceflowIntent::new("order_pizza".to_string()).set_query("A large pizza".to_string());
+    ///
+    ///     let stream = vf_client.send_intent_stream(&session, None, &intent).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn send_intent_stream(&self, session: &VoiceflowSession, state: Option<State>, intent: &VoiceflowIntent) -> VoiceflowBlockStream {
+        let action = ActionBuilder::new(ActionType::Intent).intent(intent).build();
+        let body = VoiceflowRequestBodyBuilder::new(action).session(Some(session)).state(state).config(self.request_config.as_ref()).build();
+        self.send_incremental_stream_request(body).await
+    }
+
+    /// Fetches the state of the session's user from the Voiceflow Dialog Manager API.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the optional `VoiceflowUserState`, `None` if Voiceflow has no state
+    /// of the user, or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     if let Ok(Some(state)) = vf_client.fetch_state(session.voiceflow_session()).await {
+    ///         println!("{:?}", state.variable("user_name"));
+    ///     }
+    /// }
+    /// ```
+    pub async fn fetch_state(&self, session: &VoiceflowSession) -> VoiceflousionResult<Option<VoiceflowUserState>> {
+        let request = self.client.get(self.state_url(session, ""));
+        let state = self.send_api_request(request, "fetch_state").await?;
+
+        // Voiceflow answers with an empty object for the users without state
+        if state.as_object().map_or(true, |state| state.is_empty()) {
+            return Ok(None);
+        }
+        Self::parse_user_state(state).map(Some)
+    }
+
+    /// Replaces the state of the session's user with the Voiceflow Dialog Manager API.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `state` - The new state of the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the updated `VoiceflowUserState` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::json;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::{VoiceflowClient, VoiceflowUserState};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let mut state = VoiceflowUserState::default();
+    ///     state.set_variable("user_name", json!("John"));
+    ///     let result = vf_client.update_state(session.voiceflow_session(), &state).await;
+    /// }
+    /// ```
+    pub async fn update_state(&self, session: &VoiceflowSession, state: &VoiceflowUserState) -> VoiceflousionResult<VoiceflowUserState> {
+        let request = self.client.put(self.state_url(session, "")).json(state);
+        let state = self.send_api_request(request, "update_state").await?;
+        Self::parse_user_state(state)
+    }
+
+    /// Deletes the state of the session's user with the Voiceflow Dialog Manager API.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating the success or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let result = vf_client.delete_state(session.voiceflow_session()).await;
+    /// }
+    /// ```
+    pub async fn delete_state(&self, session: &VoiceflowSession) -> VoiceflousionResult<()> {
+        let request = self.client.delete(self.state_url(session, ""));
+        self.send_api_request(request, "delete_state").await.map(|_| ())
+    }
+
+    /// Updates the variables of the session's user with the Voiceflow Dialog Manager API,
+    /// keeping the rest of the state.
+    ///
+    /// It can be used for pre-seeding the variables before launching the dialog.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `variables` - The variables to update.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the updated `VoiceflowUserState` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use serde_json::{json, Map};
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let mut variables = Map::new();
+    ///     variables.insert("plan".to_string(), json!("premium"));
+    ///     let result = vf_client.update_variables(session.voiceflow_session(), variables).await;
+    /// }
+    /// ```
+    pub async fn update_variables(&self, session: &VoiceflowSession, variables: Map<String, Value>) -> VoiceflousionResult<VoiceflowUserState> {
+        let request = self.client.patch(self.state_url(session, "/variables")).json(&variables);
+        let state = self.send_api_request(request, "update_variables").await?;
+        Self::parse_user_state(state)
+    }
+
+    /// Resets the dialog of the session's user, so the next interaction starts the dialog from the beginning.
+    ///
+    /// The state of the user is deleted and, if the variables are provided, replaced with a new state
+    /// containing only these variables.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `variables` - The optional variables of the new state.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating the success or a `VoiceflousionError` if a request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     let result = vf_client.reset_session(session.voiceflow_session(), None).await;
+    /// }
+    /// ```
+    pub async fn reset_session(&self, session: &VoiceflowSession, variables: Option<Map<String, Value>>) -> VoiceflousionResult<()> {
+        self.delete_state(session).await?;
+
+        if let Some(variables) = variables {
+            self.update_state(session, &VoiceflowUserState::new(variables)).await?;
+        }
+        Ok(())
+    }
+
+    /// Saves the transcript of the session's conversation with the Voiceflow Transcript API.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the saved `VoiceflowTranscript` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::session_wrappers::Session;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let session = Arc::new(Session::new("chat_id".to_string(), None, true));
+    ///     let vf_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///
+    ///     if let Ok(transcript) = vf_client.save_transcript(session.voiceflow_session()).await {
+    ///         println!("Saved transcript {}", transcript.id());
+    ///     }
+    /// }
+    /// ```
+    pub async fn save_transcript(&self, session: &VoiceflowSession) -> VoiceflousionResult<VoiceflowTranscript> {
+        let request = self.client.put(VOICEFLOW_TRANSCRIPTS_URL).json(&json!({
+            "projectID": self.project_id,
+            "versionID": self.version_id,
+            "sessionID": session.user_id(),
+        }));
+        let transcript = self.send_api_request(request, "save_transcript").await?;
+        serde_json::from_value(transcript)
+            .map_err(|e| VoiceflousionError::VoiceflowResponseReadingError(e.to_string()))
+    }
+
+    /// Builds the URL of the Dialog Manager API user state endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// * `session` - The Voiceflow session.
+    /// * `path` - The path following the user ID.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the URL.
+    fn state_url(&self, session: &VoiceflowSession, path: &str) -> String {
+        format!("{}/{}{}", VOICEFLOW_STATE_URL, session.user_id(), path)
+    }
+
+    /// Sends a request to the Voiceflow REST API and reads its JSON response.
+    ///
+    /// The request is traced in the `voiceflow_request` span and its round-trip time is recorded into the metrics.
+    ///
+    /// # Parameters
+    ///
+    /// * `request` - The request to send.
+    /// * `operation` - The name of the operation for tracing.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the JSON response, `Value::Null` for an empty response,
+    /// or a `VoiceflousionError` if the request fails or Voiceflow answers with an error status.
+    async fn send_api_request(&self, request: RequestBuilder, operation: &'static str) -> VoiceflousionResult<Value> {
+        let span = info_span!("voiceflow_request", project_id = %self.project_id, version_id = %self.version_id, operation);
+        let started = Instant::now();
+
+        let response = request
+            .header(AUTHORIZATION, &self.voiceflow_api_key)
+            .header("versionID", &self.version_id)
+            .send().instrument(span).await;
+
+        // Record the round-trip time of the request
+        record_histogram(VOICEFLOW_REQUEST_DURATION_SECONDS, &[("project_id", self.project_id.clone())], started.elapsed().as_secs_f64()).await;
+
+        let response = response
+            .map_err(|e| VoiceflousionError::VoiceflowRequestError(self.project_id.clone(), self.version_id.clone(), e.to_string()))?;
+        let status = response.status();
+        let text = response.text().await
+            .map_err(|e| VoiceflousionError::VoiceflowResponseReadingError(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(VoiceflousionError::VoiceflowRequestError(self.project_id.clone(), self.version_id.clone(), format!("{}: {}", status, text)));
+        }
+        if text.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        serde_json::from_str(&text)
+            .map_err(|e| VoiceflousionError::VoiceflowResponseReadingError(e.to_string()))
+    }
+
+    /// Parses the user state answered by the Dialog Manager API.
+    ///
+    /// # Parameters
+    ///
+    /// * `state` - The JSON of the state.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `VoiceflowUserState` or a `VoiceflousionError` if the JSON is invalid.
+    fn parse_user_state(state: Value) -> VoiceflousionResult<VoiceflowUserState> {
+        serde_json::from_value(state)
+            .map_err(|e| VoiceflousionError::VoiceflowResponseReadingError(e.to_string()))
+    }
+
+    /// Posts a request to the Voiceflow API `/stream` endpoint.
+    ///
+    /// The request is traced in the `voiceflow_request` span and its round-trip time is recorded into the metrics.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The request body to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the HTTP response or a `VoiceflousionError` if the request fails.
+    async fn post_stream_request<'a>(&self, body: VoiceflowRequestBody<'a>) -> VoiceflousionResult<Response> {
+        let general_runtime_url = format!("{}/{}/{}/stream", VOICEFLOW_API_URL, &self.project_id, &self.version_id);
+        let span = info_span!("voiceflow_request", project_id = %self.project_id, version_id = %self.version_id);
+        let started = Instant::now();
+
+        let response = self.client.post(general_runtime_url)
+            .header(AUTHORIZATION, &self.voiceflow_api_key)
+            .header(CONTENT_TYPE, "application/json")
+            .header(ACCEPT, "text/event-stream")
+            .body(body.to_json()).send().instrument(span).await
+            .map_err(|e| VoiceflousionError::VoiceflowRequestError(self.project_id.clone(), self.version_id.clone(), e.to_string()));
+
+        // Record the round-trip time of the request
+        record_histogram(VOICEFLOW_REQUEST_DURATION_SECONDS, &[("project_id", self.project_id.clone())], started.elapsed().as_secs_f64()).await;
+        response
+    }
+
+    /// Sends a request to the Voiceflow API and returns the response as a stream of blocks.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The request body to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowBlockStream` yielding the blocks of the response.
+    async fn send_incremental_stream_request<'a>(&self, body: VoiceflowRequestBody<'a>) -> VoiceflowBlockStream {
+        match self.post_stream_request(body).await {
+            Ok(valid_response) => {
+                VoiceflowResponse::new(valid_response).to_stream(self.invalid_response_message.clone())
+            },
+            Err(error) => {
+                error!(%error, "Voiceflow request failed");
+                let (sender, receiver) = mpsc::channel(1);
+                let _ = sender.try_send(VoiceflowBlock::Text(VoiceflowText::new(self.unavailable_message.clone())));
+                VoiceflowBlockStream::new(receiver)
+            }
+        }
+    }
+
+    /// Sends a request to the Voiceflow API and returns the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The request body to send.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflowMessage` containing the response from the Voiceflow API.
+    async fn send_stream_request<'a>(&self, body: VoiceflowRequestBody<'a>) -> VoiceflowMessage{
+        let response = self.post_stream_request(body).await;
+        let result_message = match response{
+            Ok(valid_response) => {
+                let voiceflow_response = VoiceflowResponse::new(valid_response);
+                voiceflow_response.to_message().await
+            },
+            Err(error) => {
+                error!(%error, "Voiceflow request failed");
+                let mut message = VoiceflowMessage::default();
+                message.add_block(VoiceflowBlock::Text(VoiceflowText::new(self.unavailable_message.clone())));
+                Ok(message)
+            }
+        };
+        match result_message{
+            Ok(message) => message,
+            Err(error) =>{
+                error!(%error, "Failed to read the Voiceflow response");
+                let mut message = VoiceflowMessage::default();
+                message.add_block(VoiceflowBlock::Text(VoiceflowText::new(self.invalid_response_message.clone())));
+                message
+            }
+        }
+    }
+}
+
+/// Builds the path action for the button payload.
+///
+/// # Parameters
+///
+/// * `payload` - The payload associated with the button.
+///
+/// # Returns
+///
+/// An `Action` with the path of the button and the rest of the payload.
+fn build_button_action(mut payload: Value) -> Action {
+    let path = payload.as_object_mut().unwrap().remove("path").expect("Button has no path!").as_str().unwrap().to_string();
+    ActionBuilder::new(ActionType::Path(path)).path(payload).build()
+}
+
+/// Builds the `Action` for choosing a path.
+///
+/// # Parameters
+///
+/// * `path` - The name of the path.
+/// * `payload` - The optional payload associated with the path.
+///
+/// # Returns
+///
+/// The `Action` with the path type.
+fn build_path_action(path: &str, payload: Option<Value>) -> Action {
+    let builder = ActionBuilder::new(ActionType::Path(path.to_string()));
+    match payload {
+        Some(payload) => builder.path(payload).build(),
+        None => builder.build()
+    }
+}
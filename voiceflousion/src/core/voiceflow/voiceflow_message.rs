@@ -1,13 +1,17 @@
 use std::fmt::Debug;
 use std::vec::IntoIter;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
-use crate::core::voiceflow::response_structures::{VoiceflowResponseBlock, VoiceflowResponseBlockProcessor};
+use crate::core::voiceflow::response_structures::{VoiceflowResponseBlock, VoiceflowResponseBlockProcessor, VoiceflowResponseBlockType};
+use crate::core::traits::Versioned;
 use crate::core::voiceflow::VoiceflowBlock;
+use crate::core::voiceflow::dialog_blocks::VoiceflowCustomAction;
 
 /// Represents a message from a Voiceflow response.
 ///
 /// `VoiceflowMessage` contains a list of `VoiceflowBlock` instances that make up the content of the message.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceflowMessage {
     /// The content of the message as a list of `VoiceflowBlock` instances.
     content: Vec<VoiceflowBlock>,
@@ -37,6 +41,47 @@ impl VoiceflowMessage {
         }
     }
 
+    /// Returns the custom action the message ends with, if any.
+    ///
+    /// The Voiceflow dialog stops on a custom action, so a custom action awaiting for the path
+    /// is always the last block of the message.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the last `VoiceflowCustomAction`.
+    pub fn last_custom_action(&self) -> Option<&VoiceflowCustomAction> {
+        if let Some(VoiceflowBlock::CustomAction(custom_action)) = self.content.last() {
+            Some(custom_action)
+        } else {
+            None
+        }
+    }
+
+    /// Trims the `CustomAction` block from the message content if it exists.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the trimmed `VoiceflowCustomAction`.
+    pub fn trim_custom_action_block(&mut self) -> Option<VoiceflowCustomAction> {
+        match self.content.pop() {
+            Some(VoiceflowBlock::CustomAction(custom_action)) => Some(custom_action),
+            Some(block) => {
+                self.content.push(block);
+                None
+            },
+            None => None
+        }
+    }
+
+    /// Appends all blocks of another message to the end of the message content.
+    ///
+    /// # Parameters
+    ///
+    /// * `message` - The `VoiceflowMessage` to append.
+    pub fn append(&mut self, mut message: VoiceflowMessage) {
+        self.content.append(&mut message.content);
+    }
+
     /// Inserts a block at the beginning of the message content.
     ///
     /// # Parameters
@@ -55,6 +100,24 @@ impl VoiceflowMessage {
         self.content.push(block);
     }
 
+    /// Returns a reference to the blocks of the message content.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of `VoiceflowBlock`.
+    pub fn blocks(&self) -> &Vec<VoiceflowBlock> {
+        &self.content
+    }
+
+    /// Returns a mutable reference to the blocks of the message content.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the vector of `VoiceflowBlock`.
+    pub fn blocks_mut(&mut self) -> &mut Vec<VoiceflowBlock> {
+        &mut self.content
+    }
+
     /// Returns the number of blocks in the message content.
     ///
     /// # Returns
@@ -84,6 +147,8 @@ impl IntoIterator for VoiceflowMessage {
     }
 }
 
+impl Versioned for VoiceflowMessage {}
+
 /// A builder for creating a `VoiceflowMessage`.
 ///
 /// `VoiceflowMessageBuilder` allows for the incremental construction of a `VoiceflowMessage`
@@ -112,21 +177,112 @@ impl VoiceflowMessageBuilder {
     ///
     /// A `VoiceflowMessage` containing the processed blocks.
     pub fn build_message(self, blocks: Vec<VoiceflowResponseBlock>) -> VoiceflowMessage {
-        let block_processor = VoiceflowResponseBlockProcessor::new();
+        let mut message_stream_builder = VoiceflowMessageStreamBuilder::new();
 
         let mut message = VoiceflowMessage {
             content: Vec::with_capacity(blocks.len()),
         };
-        let mut buttons_options = VoiceflowButtonsOption::Empty;
 
         for block in blocks {
-            if let VoiceflowButtonsOption::Empty = buttons_options {
-                buttons_options = block_processor.process_block(&mut message, block);
-            } else {
-                block_processor.process_buttons_options(&mut message, &mut buttons_options, block);
-            }
+            message.content.extend(message_stream_builder.push_block(block));
         }
-        block_processor.add_buttons_options_to_message(&mut message, buttons_options);
+        message.content.extend(message_stream_builder.finish());
         message
     }
+}
+
+/// A builder for converting Voiceflow response blocks into `VoiceflowBlock` instances one by one.
+///
+/// `VoiceflowMessageStreamBuilder` returns blocks as soon as they are complete. A text block
+/// is held back until the next block arrives, because it may become the text of the following buttons.
+pub(crate) struct VoiceflowMessageStreamBuilder {
+    /// The processor of the response blocks.
+    block_processor: VoiceflowResponseBlockProcessor,
+    /// The message for the blocks processed but not returned yet.
+    pending: VoiceflowMessage,
+    /// The text that may become the option of the following buttons.
+    buttons_options: VoiceflowButtonsOption,
+    /// The text of the completion being streamed.
+    completion: Option<String>,
+}
+
+impl VoiceflowMessageStreamBuilder {
+    /// Creates a new `VoiceflowMessageStreamBuilder`.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowMessageStreamBuilder`.
+    pub(crate) fn new() -> Self {
+        Self {
+            block_processor: VoiceflowResponseBlockProcessor::new(),
+            pending: VoiceflowMessage::default(),
+            buttons_options: VoiceflowButtonsOption::Empty,
+            completion: None,
+        }
+    }
+
+    /// Processes a response block.
+    ///
+    /// # Parameters
+    ///
+    /// * `block` - The `VoiceflowResponseBlock` to process.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `VoiceflowBlock` instances completed by this block.
+    pub(crate) fn push_block(&mut self, block: VoiceflowResponseBlock) -> Vec<VoiceflowBlock> {
+        if let VoiceflowResponseBlockType::Completion = block.block_type() {
+            return match self.push_completion(&block) {
+                Some(text_block) => self.push_block(text_block),
+                None => Vec::new(),
+            };
+        }
+        if let VoiceflowButtonsOption::Empty = self.buttons_options {
+            self.buttons_options = self.block_processor.process_block(&mut self.pending, block);
+        } else {
+            self.block_processor.process_buttons_options(&mut self.pending, &mut self.buttons_options, block);
+        }
+        std::mem::take(&mut self.pending.content)
+    }
+
+    /// Accumulates a completion chunk.
+    ///
+    /// # Parameters
+    ///
+    /// * `block` - The completion `VoiceflowResponseBlock`.
+    ///
+    /// # Returns
+    ///
+    /// A text `VoiceflowResponseBlock` with the full completion text when the completion ends, otherwise `None`.
+    fn push_completion(&mut self, block: &VoiceflowResponseBlock) -> Option<VoiceflowResponseBlock> {
+        let payload = &block.json()["trace"]["payload"];
+        match payload["state"].as_str() {
+            Some("start") => {
+                self.completion = Some(String::new());
+                None
+            },
+            Some("content") => {
+                if let Some(content) = payload["content"].as_str() {
+                    self.completion.get_or_insert_with(String::new).push_str(content);
+                }
+                None
+            },
+            Some("end") => {
+                let text = self.completion.take()?;
+                let json = json!({"trace": {"type": "text", "payload": {"message": text}}});
+                Some(VoiceflowResponseBlock::new(VoiceflowResponseBlockType::Text, json))
+            },
+            _ => None
+        }
+    }
+
+    /// Finishes processing and returns the held back blocks.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the remaining `VoiceflowBlock` instances.
+    pub(crate) fn finish(mut self) -> Vec<VoiceflowBlock> {
+        self.block_processor.add_buttons_options_to_message(&mut self.pending, self.buttons_options);
+        self.pending.content
+    }
 }
\ No newline at end of file
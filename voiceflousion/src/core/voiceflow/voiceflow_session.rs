@@ -28,7 +28,7 @@ impl VoiceflowSession {
     /// # Returns
     ///
     /// A new instance of `VoiceflowSession`.
-    fn new(session_id: String, user_id: String) -> Self{
+    pub(crate) fn new(session_id: String, user_id: String) -> Self{
         Self{
           session_id,
           user_id
@@ -62,4 +62,22 @@ impl VoiceflowSession {
 
         Self::new(session_id, user_id)
     }
+
+    /// Returns the session ID used in Voiceflow.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the session ID string.
+    pub fn session_id(&self) -> &String {
+        &self.session_id
+    }
+
+    /// Returns the user ID used in Voiceflow.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the user ID string.
+    pub fn user_id(&self) -> &String {
+        &self.user_id
+    }
 }
\ No newline at end of file
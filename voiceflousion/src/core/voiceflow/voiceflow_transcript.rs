@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a transcript of a conversation saved with the Voiceflow Transcript API.
+///
+/// The saved transcripts are listed in the Transcripts section of the Voiceflow project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoiceflowTranscript {
+    /// The ID of the transcript.
+    #[serde(rename = "_id")]
+    id: String,
+    /// The ID of the Voiceflow project.
+    #[serde(rename = "projectID", default)]
+    project_id: String,
+    /// The ID of the session of the conversation.
+    #[serde(rename = "sessionID", default)]
+    session_id: String,
+    /// The optional time the transcript was created at, in the ISO 8601 format.
+    #[serde(rename = "createdAt", default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+}
+
+impl VoiceflowTranscript {
+    /// Returns the ID of the transcript.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the transcript ID string.
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    /// Returns the ID of the Voiceflow project.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the project ID string.
+    pub fn project_id(&self) -> &String {
+        &self.project_id
+    }
+
+    /// Returns the ID of the session of the conversation.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the session ID string.
+    pub fn session_id(&self) -> &String {
+        &self.session_id
+    }
+
+    /// Returns the time the transcript was created at.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the optional time in the ISO 8601 format.
+    pub fn created_at(&self) -> &Option<String> {
+        &self.created_at
+    }
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Represents the state of a user stored by the Voiceflow Dialog Manager API.
+///
+/// `VoiceflowUserState` contains the stack of the dialog flows, the internal storage
+/// of the runtime and the variables of the user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VoiceflowUserState {
+    /// The stack of the dialog flows.
+    #[serde(default)]
+    stack: Vec<Value>,
+    /// The internal storage of the Voiceflow runtime.
+    #[serde(default)]
+    storage: Map<String, Value>,
+    /// The variables of the user.
+    #[serde(default)]
+    variables: Map<String, Value>,
+}
+
+impl VoiceflowUserState {
+    /// Creates a new `VoiceflowUserState` with the variables and an empty dialog stack.
+    ///
+    /// # Parameters
+    ///
+    /// * `variables` - The variables of the user.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflowUserState`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::{json, Map};
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let mut variables = Map::new();
+    /// variables.insert("user_name".to_string(), json!("John"));
+    /// let state = VoiceflowUserState::new(variables);
+    /// ```
+    pub fn new(variables: Map<String, Value>) -> Self {
+        Self {
+            stack: Vec::new(),
+            storage: Map::new(),
+            variables,
+        }
+    }
+
+    /// Returns the stack of the dialog flows.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of the stack frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let state = VoiceflowUserState::default();
+    /// assert!(state.stack().is_empty());
+    /// ```
+    pub fn stack(&self) -> &Vec<Value> {
+        &self.stack
+    }
+
+    /// Returns the internal storage of the Voiceflow runtime.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the map of the storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let state = VoiceflowUserState::default();
+    /// let storage = state.storage();
+    /// ```
+    pub fn storage(&self) -> &Map<String, Value> {
+        &self.storage
+    }
+
+    /// Returns the variables of the user.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the map of the variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let state = VoiceflowUserState::default();
+    /// assert!(state.variables().is_empty());
+    /// ```
+    pub fn variables(&self) -> &Map<String, Value> {
+        &self.variables
+    }
+
+    /// Returns the variable of the user.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the variable.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing a reference to the value of the variable, or `None` if there is no such variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let mut state = VoiceflowUserState::default();
+    /// state.set_variable("user_name", json!("John"));
+    /// assert_eq!(state.variable("user_name"), Some(&json!("John")));
+    /// ```
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    /// Sets the variable of the user.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the variable.
+    /// * `value` - The value of the variable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::voiceflow::VoiceflowUserState;
+    ///
+    /// let mut state = VoiceflowUserState::default();
+    /// state.set_variable("order_id", json!(42));
+    /// ```
+    pub fn set_variable(&mut self, name: &str, value: Value) {
+        self.variables.insert(name.to_string(), value);
+    }
+}
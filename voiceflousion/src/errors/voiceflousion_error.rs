@@ -56,7 +56,12 @@ pub enum VoiceflousionError {
     /// Error occurred due to invalid update.
     ///
     /// Contains the update and error message.
-    ValidationError(String, String)
+    ValidationError(String, String),
+
+    /// Error occurred while accessing a session store.
+    ///
+    /// Contains a string description and the error message.
+    SessionStoreError(String, String)
 }
 
 /// Type alias for `Result` with a `VoiceflousionError` error type.
@@ -66,6 +71,40 @@ pub enum VoiceflousionError {
 /// crate to simplify function signatures and error handling.
 pub type VoiceflousionResult<T> = Result<T, VoiceflousionError>;
 
+impl VoiceflousionError {
+    /// Returns the name of the error variant.
+    ///
+    /// The name is used as the `error` label of the error metrics.
+    ///
+    /// # Returns
+    ///
+    /// A static string with the name of the variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::errors::VoiceflousionError;
+    ///
+    /// let error = VoiceflousionError::SessionLockError("chat_id".to_string());
+    /// assert_eq!(error.kind(), "SessionLockError");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            VoiceflousionError::VoiceflowBlockConvertationError(..) => "VoiceflowBlockConvertationError",
+            VoiceflousionError::VoiceflowRequestError(..) => "VoiceflowRequestError",
+            VoiceflousionError::VoiceflowResponseReadingError(..) => "VoiceflowResponseReadingError",
+            VoiceflousionError::ClientUpdateConvertationError(..) => "ClientUpdateConvertationError",
+            VoiceflousionError::ClientRequestError(..) => "ClientRequestError",
+            VoiceflousionError::ClientRequestInvalidBodyError(..) => "ClientRequestInvalidBodyError",
+            VoiceflousionError::ClientResponseReadingError(..) => "ClientResponseReadingError",
+            VoiceflousionError::SessionLockError(..) => "SessionLockError",
+            VoiceflousionError::DeprecatedError(..) => "DeprecatedError",
+            VoiceflousionError::ValidationError(..) => "ValidationError",
+            VoiceflousionError::SessionStoreError(..) => "SessionStoreError"
+        }
+    }
+}
+
 impl Display for VoiceflousionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error>{
         match self {
@@ -98,6 +137,9 @@ impl Display for VoiceflousionError {
             },
             VoiceflousionError::ValidationError(validated, error) =>{
                 write!(f, "Validation failure of {}. {}", validated, error)
+            },
+            VoiceflousionError::SessionStoreError(place, error) =>{
+                write!(f, "Session store failure: {}\n{}", place, error)
             }
         }
     }
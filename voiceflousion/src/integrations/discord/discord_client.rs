@@ -1,26 +1,35 @@
+use std::ops::Deref;
 use async_trait::async_trait;
 use crate::core::base_structs::ClientBase;
 use crate::core::ClientBuilder;
 use crate::core::session_wrappers::LockedSession;
 use crate::core::traits::{Client, Sender};
-use crate::errors::VoiceflousionResult;
-use crate::integrations::discord::discord_sender::DiscordSender;
-use crate::integrations::discord::discord_update::DiscordUpdate;
+use crate::core::voiceflow::dialog_blocks::VoiceflowCarousel;
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::discord::{DiscordResponder, DiscordSender, DiscordUpdate};
 
+/// Represents a client for Discord integration with Voiceflow.
+///
+/// `DiscordClient` manages sessions and interactions with the Voiceflow API and Discord.
+/// The client ID must be the ID of the Discord application.
 pub struct DiscordClient{
+    /// The base structure that provides core functionalities.
     client_base: ClientBase<DiscordSender>,
+    /// The public key of the Discord application, used for verifying the interactions.
     public_key: String
 }
 
 impl DiscordClient{
     /// Creates a new `DiscordClient`.
     ///
-    /// This method initializes a new `TelegramClient` using the provided `ClientBuilder`.
+    /// This method initializes a new `DiscordClient` using the provided `ClientBuilder`.
     /// It configures the client with the necessary parameters and returns an instance of `DiscordClient`.
     ///
     /// # Parameters
     ///
     /// * `builder` - The `ClientBuilder` containing the necessary configurations.
+    /// * `public_key` - The public key of the Discord application.
     ///
     /// # Returns
     ///
@@ -44,6 +53,12 @@ impl DiscordClient{
         let max_connections_per_moment = builder.max_connections_per_moment();
         let connection_duration = builder.connection_duration();
         let sender = DiscordSender::new(max_connections_per_moment, api_key, connection_duration);
+        let sender = if let Some(delivery_policy) = builder.delivery_policy(){
+            sender.set_delivery_policy(delivery_policy.clone())
+        }
+        else {
+            sender
+        };
 
         Self {
             client_base: ClientBase::new(builder, sender),
@@ -51,9 +66,50 @@ impl DiscordClient{
         }
     }
 
+    /// Returns the public key of the Discord application.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the public key string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::discord::DiscordClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let client = DiscordClient::new(builder, "public_key".to_string());
+    /// let public_key = client.get_public_key();
+    /// ```
     pub fn get_public_key(&self) -> &String{
         &self.public_key
     }
+
+    /// Switches the carousel card in the client's message.
+    ///
+    /// This method is used to switch between carousel cards in a Discord session.
+    /// It updates the session with the new carousel state and edits the carousel message.
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction.
+    /// * `carousel` - The `VoiceflowCarousel` block containing the cards.
+    /// * `direction` - The direction to switch the carousel (`true` for next, `false` for previous).
+    /// * `interaction_time` - The interaction time as a Unix timestamp.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `DiscordResponder` if successful, or a `VoiceflousionError` if the request fails.
+    async fn switch_carousel_card(&self, locked_session: &LockedSession<'_>, carousel: &VoiceflowCarousel, direction: bool, interaction_time: i64) -> VoiceflousionResult<DiscordResponder> {
+        // Update the last interaction time in the session
+        locked_session.set_last_interaction(Some(interaction_time));
+        // Update the carousel with the new card and send the response
+        self.client_base.sender().update_carousel(carousel, direction, self.client_base.client_id(), locked_session.get_chat_id()).await
+    }
 }
 
 #[async_trait]
@@ -61,11 +117,58 @@ impl Client for DiscordClient{
     type ClientUpdate<'async_trait> = DiscordUpdate;
     type ClientSender<'async_trait> = DiscordSender;
 
+    /// Returns a reference to the `ClientBase`.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `ClientBase` instance.
     fn client_base(&self) -> &ClientBase<Self::ClientSender<'_>> {
         &self.client_base
     }
 
+    /// Acknowledges the Discord interaction with a deferred response,
+    /// so the answer can be sent with follow-up messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `update` - The update from the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the request fails.
+    async fn acknowledge_update(&self, update: &Self::ClientUpdate<'_>) -> VoiceflousionResult<()> {
+        self.client_base.sender().acknowledge_interaction(update.chat_id(), update.update_id(), update.interaction_token(), update.is_component()).await
+    }
+
+    /// Handles carousel switch interactions in a Discord session.
+    ///
+    /// This method checks if the previous message contains a carousel block and, if so,
+    /// switches the carousel card according to the specified direction. If the previous
+    /// message is not a carousel, it returns an error indicating that there is no carousel to switch.
+    ///
+    /// # Parameters
+    ///
+    /// * `locked_session` - The locked session for the interaction, ensuring thread-safe access.
+    /// * `interaction_time` - The time of the interaction in seconds since the Unix epoch.
+    /// * `switch_direction` - The direction to switch the carousel (`true` for next, `false` for previous).
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a vector of `SenderResponder` if the switch was successful,
+    /// or a `VoiceflousionError` if the operation fails (e.g., no carousel to switch).
     async fn handle_carousel_switch(&self, locked_session: &LockedSession<'_>, interaction_time: i64, switch_direction: bool) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
-        unimplemented!()
+        let binding = locked_session.previous_message().await;
+        let previous_message = binding.deref().as_ref().ok_or_else(|| {
+            VoiceflousionError::ClientRequestError("DiscordClient".to_string(), "Carousel cannot be switched at the start of the conversation".to_string(), )
+        })?;
+
+        if let VoiceflowBlock::Carousel(carousel) = previous_message.block() {
+            Ok(vec![self.switch_carousel_card(locked_session, carousel, switch_direction, interaction_time).await?])
+        } else {
+            Err(VoiceflousionError::ValidationError(
+                "DiscordClient".to_string(),
+                "There is no carousel to switch".to_string(),
+            ))
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,18 @@
 use std::fmt::Debug;
 use std::ops::Deref;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reqwest::Response;
+use serde_json::Value;
 use crate::core::base_structs::ResponderBase;
 use crate::core::traits::Responder;
 use crate::core::voiceflow::VoiceflowBlock;
-use crate::errors::VoiceflousionResult;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
 
+/// Represents a responder for Discord interactions.
+///
+/// `DiscordResponder` contains details of the message sent to Discord, including
+/// the channel ID, message ID, date, and the content of the message.
 #[derive(Debug)]
 pub struct DiscordResponder{
     /// The base structure that provides core functionalities.
@@ -23,7 +29,51 @@ impl Deref for DiscordResponder {
 
 #[async_trait]
 impl Responder for DiscordResponder{
+
+    /// Creates an instance of the `DiscordResponder` from HTTP response.
+    ///
+    /// This method processes the HTTP response to extract relevant details and
+    /// create an instance of the `DiscordResponder`.
+    ///
+    /// # Parameters
+    ///
+    /// * `response` - The HTTP response to process.
+    /// * `content` - The content of the message as a `VoiceflowBlock`.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `DiscordResponder` instance or a `VoiceflousionError` if the process fails.
     async fn from_response(response: Response, content: VoiceflowBlock) -> VoiceflousionResult<Self> {
-        unimplemented!()
+        // Parse the response JSON.
+        let json: Value = response.json().await.map_err(|e| VoiceflousionError::ClientResponseReadingError("DiscordResponder".to_string(), e.to_string()))?;
+
+        // Extract the channel ID from the response.
+        let channel_id = json.get("channel_id")
+            .and_then(|channel_id| channel_id.as_str())
+            .map(|channel_id| channel_id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("DiscordResponder channel_id".to_string(), json.to_string()))?;
+
+        // Extract the message ID from the response.
+        let message_id = json.get("id")
+            .and_then(|message_id| message_id.as_str())
+            .map(|message_id| message_id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("DiscordResponder message_id".to_string(), json.to_string()))?;
+
+        // Determine the timestamp based on the content type, the buttons are marked with the timestamp of their creation.
+        let timestamp = match &content{
+            VoiceflowBlock::Buttons(buttons) => Some(buttons.mark()),
+            VoiceflowBlock::Card(card) => card.buttons().as_ref().map(|buttons| buttons.mark()),
+            VoiceflowBlock::Carousel(carousel) => Some(carousel.get_selected_mark()),
+            _ => None
+        }.or_else(|| json.get("timestamp")
+            .and_then(|timestamp| timestamp.as_str())
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|date| date.timestamp())
+        ).unwrap_or_else(|| Utc::now().timestamp());
+
+        // Construct the DiscordResponder with the extracted data.
+        Ok(Self {
+            responder_base: ResponderBase::new(channel_id, message_id, content, timestamp)
+        })
     }
-}
\ No newline at end of file
+}
@@ -1,25 +1,42 @@
+use std::collections::HashMap;
 use std::ops::Deref;
 use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Response;
+use serde_json::Value;
+use tokio::sync::RwLock;
 use crate::core::base_structs::SenderBase;
-use crate::core::traits::Sender;
+use crate::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+use crate::core::traits::{Responder, Sender};
 use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
-use crate::errors::VoiceflousionResult;
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
 use crate::integrations::discord::discord_responder::DiscordResponder;
+use crate::integrations::discord::utils::DiscordSerializer;
 
+/// Represents a sender for Discord integration.
+///
+/// `DiscordSender` handles sending various types of messages (text, image, buttons, etc.)
+/// to a Discord client as follow-up messages of the interactions received from the users.
 pub struct DiscordSender{
     /// The base structure that provides core functionalities.
-    sender_base: SenderBase
+    sender_base: SenderBase,
+    /// The tokens of the last acknowledged interactions mapped by the chat IDs.
+    interaction_tokens: RwLock<HashMap<String, String>>
 }
 
 impl Deref for DiscordSender {
     type Target = SenderBase;
 
     fn deref(&self) -> &Self::Target {
-        todo!()
+        &self.sender_base
     }
 }
 
 impl DiscordSender{
+    /// The base URL for Discord API.
+    const DISCORD_API_URL: &'static str = "https://discord.com/api/v10/";
+
     /// Creates a new `DiscordSender`.
     ///
     /// # Parameters
@@ -43,6 +60,241 @@ impl DiscordSender{
     pub fn new(max_sessions_per_moment: usize, api_key: String, connection_duration: Option<u64>) -> Self {
         Self {
             sender_base: SenderBase::new(max_sessions_per_moment, api_key, connection_duration)
+                .set_delivery_policy(Self::default_delivery_policy()),
+            interaction_tokens: RwLock::new(HashMap::new())
+        }
+    }
+
+    /// Returns the default policy of delivering the messages to Discord.
+    ///
+    /// The policy follows the Discord global limit of 50 requests per second for the bot
+    /// and the limit of 5 messages per 5 seconds for a channel.
+    ///
+    /// # Returns
+    ///
+    /// The default `DeliveryPolicy` of Discord.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    ///
+    /// let policy = DiscordSender::default_delivery_policy();
+    /// assert_eq!(policy.chat_rate_limit(), &Some(RateLimit::new(5, 5000)));
+    /// ```
+    pub fn default_delivery_policy() -> DeliveryPolicy {
+        DeliveryPolicy::new(Some(RateLimit::new(50, 1000)), Some(RateLimit::new(5, 5000)), RetryPolicy::default())
+    }
+
+    /// Sets the policy of delivering the messages to Discord.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The rate limits and the retry policy of the requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `DiscordSender` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RetryPolicy};
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    ///
+    /// let policy = DeliveryPolicy::new(None, None, RetryPolicy::new(0, 0, 0));
+    /// let sender = DiscordSender::new(10, "api_key".to_string(), None).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.sender_base = self.sender_base.set_delivery_policy(delivery_policy);
+        self
+    }
+
+    /// Acknowledges an interaction with a deferred response and remembers its token
+    /// for sending the follow-up messages to the chat.
+    ///
+    /// Application commands show a loading state until the first follow-up message,
+    /// message components keep their message until it is updated.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `interaction_id` - The ID of the interaction.
+    /// * `interaction_token` - The token of the interaction.
+    /// * `is_component` - Whether the interaction is a message component interaction.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` indicating success or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let result = sender.acknowledge_interaction("chat_id", "interaction_id", "interaction_token", false).await;
+    ///     println!("{:?}", result);
+    /// }
+    /// ```
+    pub async fn acknowledge_interaction(&self, chat_id: &str, interaction_id: &str, interaction_token: &str, is_component: bool) -> VoiceflousionResult<()> {
+        let api_url = format!("{}interactions/{}/{}/callback", Self::DISCORD_API_URL, interaction_id, interaction_token);
+
+        let body = DiscordSerializer::build_deferred_response_body(is_component);
+
+        // The interaction must be acknowledged in time, so it is not delayed by the rate limit of the chat
+        let discord_response = self.send_message(&api_url, None, body).await?;
+
+        if !discord_response.status().is_success() {
+            let error_text = discord_response.text().await.unwrap_or_default();
+            return Err(VoiceflousionError::ClientRequestError("DiscordSender acknowledge_interaction".to_string(), error_text));
+        }
+
+        let mut write = self.interaction_tokens.write().await;
+        write.insert(chat_id.to_string(), interaction_token.to_string());
+        Ok(())
+    }
+
+    /// Sends a message to the Discord API within the rate limits, retrying it while it is throttled.
+    ///
+    /// # Parameters
+    ///
+    /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The optional chat ID of the recipient.
+    /// * `body` - The JSON body of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails.
+    async fn send_message(&self, api_url: &str, chat_id: Option<&str>, body: Value) -> VoiceflousionResult<Response> {
+        let request = self.http_client()
+            .post(api_url)
+            .json(&body);
+
+        self.http_client()
+            .send_with_retry(request, chat_id, |_| false)
+            .await
+            .map_err(|e| VoiceflousionError::ClientRequestError("DiscordSender send_message".to_string(), e.to_string()))
+    }
+
+    /// Edits a message with the Discord API within the rate limits, retrying it while it is throttled.
+    ///
+    /// # Parameters
+    ///
+    /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The optional chat ID of the recipient.
+    /// * `body` - The JSON body of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails.
+    async fn edit_message(&self, api_url: &str, chat_id: Option<&str>, body: Value) -> VoiceflousionResult<Response> {
+        let request = self.http_client()
+            .patch(api_url)
+            .json(&body);
+
+        self.http_client()
+            .send_with_retry(request, chat_id, |_| false)
+            .await
+            .map_err(|e| VoiceflousionError::ClientRequestError("DiscordSender edit_message".to_string(), e.to_string()))
+    }
+
+    /// Updates a carousel message in a chat.
+    ///
+    /// The carousel message is the message of the last acknowledged component interaction in the chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `carousel` - The carousel to update.
+    /// * `direction` - The direction to navigate within the carousel (true for next, false for previous).
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let cards = vec![VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None)];
+    ///     let carousel = VoiceflowCarousel::new(cards, true);
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let client_id = String::new();
+    ///     let chat_id = String::new();
+    ///     let response = sender.update_carousel(&carousel, true, &client_id, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    pub async fn update_carousel(&self, carousel: &VoiceflowCarousel, direction: bool, client_id: &String, chat_id: &String) -> VoiceflousionResult<DiscordResponder> {
+        let api_url = format!("{}/messages/@original", self.prepare_api_url(client_id, chat_id).await?);
+        let timestamp = Utc::now().timestamp();
+        let (card, index) = carousel.get_next_card(direction)?;
+
+        let body = DiscordSerializer::build_carousel_card_body(card, timestamp, index, carousel.len());
+
+        let discord_response = self.edit_message(&api_url, Some(chat_id), body).await?;
+
+        if discord_response.status().is_success() {
+            carousel.set_selected_card(index, timestamp);
+            DiscordResponder::from_response(discord_response, VoiceflowBlock::Carousel(carousel.clone())).await
+        } else {
+            let error_text = discord_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError("DiscordSender update_carousel".to_string(), error_text))
+        }
+    }
+
+    /// Prepares the follow-up webhook URL of the last acknowledged interaction in the chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the webhook URL or a `VoiceflousionError` if there is no interaction in the chat.
+    async fn prepare_api_url(&self, client_id: &str, chat_id: &str) -> VoiceflousionResult<String> {
+        let read = self.interaction_tokens.read().await;
+        let interaction_token = read.get(chat_id).ok_or_else(|| {
+            VoiceflousionError::ClientRequestError("DiscordSender".to_string(), format!("There is no interaction to respond to in chat {}", chat_id))
+        })?;
+        Ok(format!("{}webhooks/{}/{}", Self::DISCORD_API_URL, client_id, interaction_token))
+    }
+
+    /// Sends a follow-up message of the block to a chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `body` - The JSON body of the message.
+    /// * `content` - The content of the message as a `VoiceflowBlock`.
+    /// * `action` - The name of the sending action used in the errors.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` or a `VoiceflousionError` if the request fails.
+    async fn send_follow_up(&self, client_id: &str, chat_id: &str, body: Value, content: VoiceflowBlock, action: &str) -> VoiceflousionResult<DiscordResponder> {
+        let api_url = self.prepare_api_url(client_id, chat_id).await?;
+
+        let discord_response = self.send_message(&api_url, Some(chat_id), body).await?;
+
+        if discord_response.status().is_success() {
+            DiscordResponder::from_response(discord_response, content).await
+        } else {
+            let error_text = discord_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError(format!("DiscordSender {}", action), error_text))
         }
     }
 }
@@ -51,23 +303,191 @@ impl DiscordSender{
 impl Sender for DiscordSender{
     type SenderResponder = DiscordResponder;
 
+    /// Sends a text message to a chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `text` - The text message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let text = VoiceflowText::new("Hello, World!".to_string());
+    ///     let response = sender.send_text(&client_id, text, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
     async fn send_text(&self, client_id: &String, text: VoiceflowText, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
-        unimplemented!()
+        let body = DiscordSerializer::build_text_body(text.message());
+        self.send_follow_up(client_id, chat_id, body, VoiceflowBlock::Text(text), "send_text").await
     }
 
+    /// Sends an image message to a chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `image` - The image message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowImage;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let image = VoiceflowImage::new("https://example.com/image.jpg".to_string(), Some(100), Some(200));
+    ///     let response = sender.send_image(&client_id, image, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
     async fn send_image(&self, client_id: &String, image: VoiceflowImage, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
-        unimplemented!()
+        let body = DiscordSerializer::build_image_body(image.url());
+        self.send_follow_up(client_id, chat_id, body, VoiceflowBlock::Image(image), "send_image").await
     }
 
+    /// Sends a buttons message to a chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `buttons` - The buttons message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowButton, VoiceflowButtons};
+    /// use voiceflousion::core::traits::Sender;
+    /// use serde_json::Value;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let buttons = vec![VoiceflowButton::new("Click me".to_string(), Value::Null, None)];
+    ///     let voiceflow_buttons = VoiceflowButtons::new(buttons);
+    ///     let response = sender.send_buttons(&client_id, voiceflow_buttons, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
     async fn send_buttons(&self, client_id: &String, buttons: VoiceflowButtons, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
-        unimplemented!()
+        let body = DiscordSerializer::build_buttons_body(&buttons);
+        self.send_follow_up(client_id, chat_id, body, VoiceflowBlock::Buttons(buttons), "send_buttons").await
     }
 
+    /// Sends a card message to a chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `card` - The card message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCard;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let card = VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None);
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let response = sender.send_card(&client_id, card, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
     async fn send_card(&self, client_id: &String, card: VoiceflowCard, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
-        unimplemented!()
+        let body = DiscordSerializer::build_card_body(&card);
+        self.send_follow_up(client_id, chat_id, body, VoiceflowBlock::Card(card), "send_card").await
     }
 
+    /// Sends a carousel message to a chat.
+    ///
+    /// The selected card of the carousel is sent as an embed with the navigation buttons.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID (the ID of the Discord application).
+    /// * `carousel` - The carousel message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `DiscordResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::discord::DiscordSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let cards = vec![VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None)];
+    ///     let carousel = VoiceflowCarousel::new(cards, true);
+    ///     let sender = DiscordSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let response = sender.send_carousel(&client_id, carousel, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
     async fn send_carousel(&self, client_id: &String, carousel: VoiceflowCarousel, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
-        unimplemented!()
+        let (card, index) = carousel.get_selected_card()?;
+        let mark = carousel.get_selected_mark();
+
+        let body = DiscordSerializer::build_carousel_card_body(card, mark, index, carousel.len());
+        self.send_follow_up(client_id, chat_id, body, VoiceflowBlock::Carousel(carousel.clone()), "send_carousel").await
     }
-}
\ No newline at end of file
+}
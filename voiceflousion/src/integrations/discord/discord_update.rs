@@ -1,12 +1,100 @@
 use std::ops::Deref;
 use serde_json::Value;
 use crate::core::base_structs::UpdateBase;
+use crate::core::subtypes::InteractionType;
 use crate::core::traits::Update;
-use crate::errors::VoiceflousionResult;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::utils::ButtonCallbackData;
 
+/// Represents an update received from Discord.
+///
+/// `DiscordUpdate` holds the details of a Discord interaction, such as update base,
+/// interaction ID and token, and whether the interaction is a message component interaction.
 #[derive(Debug)]
 pub struct DiscordUpdate{
-    update_base: UpdateBase
+    /// The base structure that provides core functionalities.
+    update_base: UpdateBase,
+    /// The token of the interaction, used for responding to it.
+    interaction_token: String,
+    /// Whether the interaction is a message component interaction.
+    is_component: bool,
+}
+
+impl DiscordUpdate {
+    /// The Discord epoch (the first second of 2015) in milliseconds.
+    const DISCORD_EPOCH: i64 = 1420070400000;
+
+    /// Creates a new `DiscordUpdate`.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID associated with the update.
+    /// * `interaction_time` - The interaction time of the update.
+    /// * `interaction_type` - The type of interaction.
+    /// * `update_id` - The update ID (the ID of the interaction).
+    /// * `interaction_token` - The token of the interaction.
+    /// * `is_component` - Whether the interaction is a message component interaction.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `DiscordUpdate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InteractionType;
+    /// use voiceflousion::integrations::discord::DiscordUpdate;
+    ///
+    /// let interaction_type = InteractionType::new("message".to_string(), None, None);
+    /// let update = DiscordUpdate::new("chat_id".to_string(), 1627554661, interaction_type, "update_id".to_string(), "token".to_string(), false);
+    /// ```
+    pub fn new(chat_id: String, interaction_time: i64, interaction_type: InteractionType, update_id: String, interaction_token: String, is_component: bool) -> Self {
+        Self {
+            update_base: UpdateBase::new(chat_id, interaction_time, interaction_type, update_id),
+            interaction_token,
+            is_component,
+        }
+    }
+
+    /// Returns the token of the interaction.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the interaction token string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InteractionType;
+    /// use voiceflousion::integrations::discord::DiscordUpdate;
+    ///
+    /// let interaction_type = InteractionType::new("message".to_string(), None, None);
+    /// let update = DiscordUpdate::new("chat_id".to_string(), 1627554661, interaction_type, "update_id".to_string(), "token".to_string(), false);
+    /// let token = update.interaction_token();
+    /// ```
+    pub fn interaction_token(&self) -> &String {
+        &self.interaction_token
+    }
+
+    /// Returns whether the interaction is a message component interaction.
+    ///
+    /// # Returns
+    ///
+    /// A boolean indicating whether the interaction was triggered by a message component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InteractionType;
+    /// use voiceflousion::integrations::discord::DiscordUpdate;
+    ///
+    /// let interaction_type = InteractionType::new("".to_string(), Some(0), None);
+    /// let update = DiscordUpdate::new("chat_id".to_string(), 1627554661, interaction_type, "update_id".to_string(), "token".to_string(), true);
+    /// let is_component = update.is_component();
+    /// ```
+    pub fn is_component(&self) -> bool {
+        self.is_component
+    }
 }
 
 impl Deref for DiscordUpdate {
@@ -18,7 +106,86 @@ impl Deref for DiscordUpdate {
 }
 
 impl Update for DiscordUpdate{
+
+    /// Creates an update from a JSON request body.
+    ///
+    /// Application commands are handled as text messages with the value of their first string option
+    /// (or the name of the command if it has no options), message components are handled as button presses
+    /// and carousel switches.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - A JSON `Value` representing the request body.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `DiscordUpdate` or a `VoiceflousionError` if the conversion fails.
     fn from_request_body(body: Value) -> VoiceflousionResult<Self> {
-        unimplemented!()
+        // Extract the interaction ID
+        let update_id = body.get("id")
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate interaction id".to_string(), body.clone()))?;
+
+        // Extract the interaction token
+        let interaction_token = body.get("token")
+            .and_then(|token| token.as_str())
+            .map(|token| token.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate interaction token".to_string(), body.clone()))?;
+
+        // Extract the ID of the user, which is nested in the member object in guilds
+        let chat_id = body["member"].get("user")
+            .or_else(|| body.get("user"))
+            .and_then(|user| user.get("id"))
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate user id (chat id)".to_string(), body.clone()))?;
+
+        // Extract the interaction time from the snowflake ID of the interaction
+        let mut interaction_time = update_id.parse::<i64>()
+            .map(|snowflake| ((snowflake >> 22) + Self::DISCORD_EPOCH) / 1000)
+            .map_err(|_error| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate interaction id must be a snowflake".to_string(), body.clone()))?;
+
+        let data = &body["data"];
+        let mut text = String::new();
+        let mut carousel_direction = None;
+        let mut button_index = None;
+
+        // Determine the type of the interaction
+        let is_component = match body.get("type").and_then(|type_value| type_value.as_u64()) {
+            // Application command
+            Some(2) => {
+                text = data.get("options")
+                    .and_then(|options| options.as_array())
+                    .and_then(|options| options.iter().find_map(|option| option.get("value").and_then(|value| value.as_str())))
+                    .or_else(|| data.get("name").and_then(|name| name.as_str()))
+                    .map(|text_str| text_str.to_string())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate command text".to_string(), body.clone()))?;
+                false
+            },
+            // Message component
+            Some(3) => {
+                let custom_id = data.get("custom_id")
+                    .and_then(|custom_id| custom_id.as_str())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate component custom id".to_string(), body.clone()))?;
+
+                let callback_data: ButtonCallbackData = serde_json::from_str(custom_id)
+                    .map_err(|_error| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate custom id must be a valid JSON string".to_string(), body.clone()))?;
+
+                // Update interaction time, carousel direction, and button index based on the callback data
+                interaction_time = callback_data.timestamp_mark()
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate button timestamp mark".to_string(), body.clone()))?;
+                carousel_direction = callback_data.direction();
+                button_index = callback_data.index();
+                true
+            },
+            _ => return Err(VoiceflousionError::ClientUpdateConvertationError("DiscordUpdate interaction type".to_string(), body.clone()))
+        };
+
+        // Create interaction type
+        let interaction_type = InteractionType::new(text, button_index, carousel_direction);
+
+        // Return the constructed DiscordUpdate instance
+        Ok(Self::new(chat_id, interaction_time, interaction_type, update_id, interaction_token, is_component))
     }
-}
\ No newline at end of file
+}
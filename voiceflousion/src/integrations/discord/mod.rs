@@ -2,6 +2,7 @@ mod discord_client;
 mod discord_responder;
 mod discord_update;
 mod discord_sender;
+mod utils;
 
 #[cfg(feature = "advanced")]
 pub use self::{
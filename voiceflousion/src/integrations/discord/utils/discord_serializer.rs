@@ -0,0 +1,245 @@
+use serde_json::{json, Value};
+use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
+use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard};
+use crate::integrations::utils::ButtonCallbackDataBuilder;
+
+/// `DiscordSerializer` provides methods for serializing various types of messages
+/// into JSON format compatible with the Discord API. These include interaction responses,
+/// text, image, button and card messages, as well as specialized carousel messages.
+pub(crate) struct DiscordSerializer;
+
+impl DiscordSerializer {
+    /// The maximum allowed length of a button label in Discord.
+    const ALLOWED_BUTTON_LABEL: usize = 80;
+    /// The maximum number of buttons in an action row in Discord.
+    const BUTTONS_PER_ROW: usize = 5;
+    /// The maximum number of action rows in a Discord message.
+    const MAX_ROWS: usize = 5;
+
+    /// Builds the JSON body for acknowledging an interaction via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_component` - Whether the interaction is a message component interaction.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_deferred_response_body(is_component: bool) -> Value {
+        // Components update their message later, commands show a loading state until the first follow-up message
+        let response_type = if is_component { 6 } else { 5 };
+        json!({
+            "type": response_type,
+        })
+    }
+
+    /// Builds the JSON body for sending a text message via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text message to send.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_text_body(text: &str) -> Value {
+        json!({
+            "content": text,
+        })
+    }
+
+    /// Builds the JSON body for sending an image message via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `image_url` - The URL of the image to send.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_image_body(image_url: &str) -> Value {
+        json!({
+            "embeds": [
+                {
+                    "image": {
+                        "url": image_url,
+                    }
+                }
+            ]
+        })
+    }
+
+    /// Builds the JSON body for sending a message with buttons via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `buttons` - The `VoiceflowButtons` to send.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_buttons_body(buttons: &VoiceflowButtons) -> Value {
+        let text = match buttons.option() {
+            VoiceflowButtonsOption::Text(text) => text.message().clone(),
+            VoiceflowButtonsOption::Empty => String::from("Invalid behavior. Please fix errors in DiscordSender usage")
+        };
+
+        let components = Self::build_action_rows(Self::build_buttons_vec(buttons, buttons.mark()));
+
+        json!({
+            "content": text,
+            "components": components,
+        })
+    }
+
+    /// Builds the JSON body for sending a card message via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - The `VoiceflowCard` to send.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_card_body(card: &VoiceflowCard) -> Value {
+        let buttons: Vec<Value> = card.buttons().as_ref()
+            .map(|b| Self::build_buttons_vec(b, b.mark()))
+            .unwrap_or_default();
+
+        json!({
+            "embeds": [Self::build_card_embed(card, None)],
+            "components": Self::build_action_rows(buttons),
+        })
+    }
+
+    /// Builds the JSON body for sending or updating a carousel card message via the Discord API.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - The `VoiceflowCard` to send.
+    /// * `mark` - A mark (i64) associated with the buttons, used in the custom ID of the components.
+    /// * `index` - The current position of the card within the carousel (0-based index).
+    /// * `carousel_length` - The total number of cards in the carousel.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_carousel_card_body(card: &VoiceflowCard, mark: i64, index: usize, carousel_length: usize) -> Value {
+        let footer = format!("{}/{}", index + 1, carousel_length);
+
+        json!({
+            "embeds": [Self::build_card_embed(card, Some(footer))],
+            "components": Self::build_carousel_card_rows(card, mark, index, carousel_length),
+        })
+    }
+
+    /// Builds the embed of a card.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - The `VoiceflowCard` to convert.
+    /// * `footer` - The optional footer text of the embed.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON embed object.
+    fn build_card_embed(card: &VoiceflowCard, footer: Option<String>) -> Value {
+        let mut embed = json!({});
+
+        if let Some(title) = card.title() {
+            embed["title"] = json!(title);
+        }
+        if let Some(description) = card.description() {
+            embed["description"] = json!(description);
+        }
+        if let Some(url) = card.image_url() {
+            embed["image"] = json!({ "url": url });
+        }
+        if let Some(text) = footer {
+            embed["footer"] = json!({ "text": text });
+        }
+
+        embed
+    }
+
+    /// Converts `VoiceflowButtons` to Discord button components.
+    ///
+    /// # Parameters
+    ///
+    /// * `buttons` - The `VoiceflowButtons` to convert.
+    /// * `buttons_mark` - A mark (i64) associated with the buttons, used in the custom ID of the components.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` representing the button components.
+    fn build_buttons_vec(buttons: &VoiceflowButtons, buttons_mark: i64) -> Vec<Value> {
+        buttons.iter().enumerate().map(|(index, b)| {
+            let label: String = b.name().chars().take(Self::ALLOWED_BUTTON_LABEL).collect();
+
+            let custom_id = ButtonCallbackDataBuilder::new().index(index).timestamp_mark(buttons_mark).build().to_json_string();
+
+            json!({
+                "type": 2,
+                "style": 1,
+                "label": label,
+                "custom_id": custom_id,
+            })
+        }).collect()
+    }
+
+    /// Converts the buttons of a `VoiceflowCard` into Discord action rows,
+    /// adding a row with navigation buttons for carousel movement.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - A reference to the `VoiceflowCard` whose buttons will be converted.
+    /// * `mark` - A mark (i64) associated with the buttons, used in the custom ID of the components.
+    /// * `index` - The current position of the card within the carousel (0-based index).
+    /// * `carousel_len` - The total number of cards in the carousel.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` representing the action rows, including the navigation row.
+    fn build_carousel_card_rows(card: &VoiceflowCard, mark: i64, index: usize, carousel_len: usize) -> Vec<Value> {
+        let buttons: Vec<Value> = card.buttons().as_ref()
+            .map(|b| Self::build_buttons_vec(b, mark))
+            .unwrap_or_default();
+
+        // Leave a place for the navigation row
+        let mut rows: Vec<Value> = Self::build_action_rows(buttons);
+        rows.truncate(Self::MAX_ROWS - 1);
+
+        let mut switch_buttons: Vec<Value> = Vec::new();
+        // Add a previous button if this is not the first card
+        if index > 0 {
+            let carousel_prev = ButtonCallbackDataBuilder::new().direction(false).timestamp_mark(mark).build().to_json_string();
+            switch_buttons.push(json!({ "type": 2, "style": 2, "label": "<--", "custom_id": carousel_prev }));
+        }
+        // Add a next button if this is not the last card
+        if index < carousel_len - 1 {
+            let carousel_next = ButtonCallbackDataBuilder::new().direction(true).timestamp_mark(mark).build().to_json_string();
+            switch_buttons.push(json!({ "type": 2, "style": 2, "label": "-->", "custom_id": carousel_next }));
+        }
+        if !switch_buttons.is_empty() {
+            rows.push(json!({ "type": 1, "components": switch_buttons }));
+        }
+
+        rows
+    }
+
+    /// Groups button components into Discord action rows.
+    ///
+    /// # Parameters
+    ///
+    /// * `buttons` - The button components to group.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` representing the action rows. Buttons exceeding the limits of Discord are dropped.
+    fn build_action_rows(buttons: Vec<Value>) -> Vec<Value> {
+        buttons.chunks(Self::BUTTONS_PER_ROW)
+            .take(Self::MAX_ROWS)
+            .map(|row| json!({ "type": 1, "components": row }))
+            .collect()
+    }
+}
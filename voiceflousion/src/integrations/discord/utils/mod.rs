@@ -0,0 +1,3 @@
+mod discord_serializer;
+
+pub(super) use self::discord_serializer::DiscordSerializer;
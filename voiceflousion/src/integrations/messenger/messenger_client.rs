@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use crate::core::base_structs::ClientBase;
+use crate::core::ClientBuilder;
+use crate::core::session_wrappers::LockedSession;
+use crate::core::subtypes::InputMedia;
+use crate::core::traits::{Client, Sender};
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::messenger::{MessengerSender, MessengerUpdate};
+
+/// Represents a client for Facebook Messenger and Instagram integration with Voiceflow.
+///
+/// `MessengerClient` manages sessions and interactions with the Voiceflow API and the Messenger Platform.
+/// The client ID must be the ID of the Facebook page (or Instagram account) and the API key must be its access token.
+pub struct MessengerClient{
+    /// The base structure that provides core functionalities.
+    client_base: ClientBase<MessengerSender>,
+    /// The secret of the Meta application, used for verifying the webhook signatures.
+    app_secret: String
+}
+
+impl MessengerClient{
+    /// Creates a new `MessengerClient`.
+    ///
+    /// This method initializes a new `MessengerClient` using the provided `ClientBuilder`.
+    /// It configures the client with the necessary parameters and returns an instance of `MessengerClient`.
+    ///
+    /// # Parameters
+    ///
+    /// * `builder` - The `ClientBuilder` containing the necessary configurations.
+    /// * `app_secret` - The secret of the Meta application.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `MessengerClient`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::messenger::MessengerClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("page_id".to_string(), "page_access_token".to_string(), voiceflow_client, 10);
+    /// let client = MessengerClient::new(builder, "app_secret".to_string());
+    /// ```
+    pub fn new(builder: ClientBuilder, app_secret: String) -> Self {
+        let api_key = builder.api_key().clone();
+        let max_connections_per_moment = builder.max_connections_per_moment();
+        let connection_duration = builder.connection_duration();
+        let sender = MessengerSender::new(max_connections_per_moment, api_key, connection_duration);
+        let sender = if let Some(delivery_policy) = builder.delivery_policy(){
+            sender.set_delivery_policy(delivery_policy.clone())
+        }
+        else {
+            sender
+        };
+
+        Self {
+            client_base: ClientBase::new(builder, sender),
+            app_secret
+        }
+    }
+
+    /// Returns the secret of the Meta application.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the application secret string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::messenger::MessengerClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("page_id".to_string(), "page_access_token".to_string(), voiceflow_client, 10);
+    /// let client = MessengerClient::new(builder, "app_secret".to_string());
+    /// let app_secret = client.get_app_secret();
+    /// ```
+    pub fn get_app_secret(&self) -> &String{
+        &self.app_secret
+    }
+}
+
+#[async_trait]
+impl Client for MessengerClient{
+    type ClientUpdate<'async_trait> = MessengerUpdate;
+    type ClientSender<'async_trait> = MessengerSender;
+
+    /// Returns a reference to the `ClientBase`.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `ClientBase` instance.
+    fn client_base(&self) -> &ClientBase<Self::ClientSender<'_>> {
+        &self.client_base
+    }
+
+    /// Handles carousel switch interactions in a Messenger session.
+    ///
+    /// Messenger displays all the cards of the carousel at once as a generic template,
+    /// so there is nothing to switch and this method always returns an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `_locked_session` - The locked session for the interaction.
+    /// * `_interaction_time` - The time of the interaction in seconds since the Unix epoch.
+    /// * `_switch_direction` - The direction to switch the carousel (`true` for next, `false` for previous).
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionError` indicating that carousels can't be switched in Messenger.
+    async fn handle_carousel_switch(&self, _locked_session: &LockedSession<'_>, _interaction_time: i64, _switch_direction: bool) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        Err(VoiceflousionError::ValidationError(
+            "MessengerClient".to_string(),
+            "Carousels are displayed entirely in Messenger and can't be switched".to_string(),
+        ))
+    }
+
+    /// Resolves the download URL of a media sent via Messenger.
+    ///
+    /// Messenger attachments are referenced by their URLs, so the file ID of the media is the URL itself.
+    ///
+    /// # Parameters
+    ///
+    /// * `media` - The media sent by the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the download URL.
+    async fn resolve_media_url(&self, media: &InputMedia) -> VoiceflousionResult<Option<String>> {
+        Ok(Some(media.file_id().clone()))
+    }
+}
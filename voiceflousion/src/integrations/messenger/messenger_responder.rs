@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Response;
+use serde_json::Value;
+use crate::core::base_structs::ResponderBase;
+use crate::core::traits::Responder;
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents a responder for Messenger interactions.
+///
+/// `MessengerResponder` contains details of the message sent to Messenger or Instagram, including
+/// the recipient ID, message ID, date, and the content of the message.
+#[derive(Debug)]
+pub struct MessengerResponder{
+    /// The base structure that provides core functionalities.
+    responder_base: ResponderBase
+}
+
+impl Deref for MessengerResponder {
+    type Target = ResponderBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.responder_base
+    }
+}
+
+#[async_trait]
+impl Responder for MessengerResponder{
+
+    /// Creates a new `MessengerResponder` instance from an HTTP response and the associated `VoiceflowBlock`.
+    ///
+    /// This method extracts the `recipient_id` and `message_id` from the response
+    /// to create an instance of `MessengerResponder`.
+    ///
+    /// # Parameters
+    ///
+    /// * `response` - The HTTP response from the Messenger API.
+    /// * `content` - The `VoiceflowBlock` associated with the response, used to extract the relevant timestamp.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `MessengerResponder` instance or a `VoiceflousionError` if the process fails.
+    async fn from_response(response: Response, content: VoiceflowBlock) -> VoiceflousionResult<Self> {
+        // Determine the timestamp based on the content type, the buttons are marked with the timestamp of their creation.
+        let timestamp = match &content{
+            VoiceflowBlock::Buttons(buttons) => Some(buttons.mark()),
+            VoiceflowBlock::Card(card) => card.buttons().as_ref().map(|buttons| buttons.mark()),
+            VoiceflowBlock::Carousel(carousel) => Some(carousel.get_selected_mark()),
+            _ => None
+        }.unwrap_or_else(|| Utc::now().timestamp());
+
+        // Parse the response JSON.
+        let json: Value = response.json().await.map_err(|e| VoiceflousionError::ClientResponseReadingError("MessengerResponder".to_string(), e.to_string()))?;
+
+        // Extract the recipient ID from the response.
+        let recipient_id = json.get("recipient_id")
+            .and_then(|recipient_id| recipient_id.as_str())
+            .map(|recipient_id| recipient_id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("MessengerResponder recipient_id".to_string(), json.to_string()))?;
+
+        // Extract the message ID from the response.
+        let message_id = json.get("message_id")
+            .and_then(|message_id| message_id.as_str())
+            .map(|message_id| message_id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("MessengerResponder message_id".to_string(), json.to_string()))?;
+
+        // Construct the MessengerResponder with the extracted data.
+        Ok(Self {
+            responder_base: ResponderBase::new(recipient_id, message_id, content, timestamp)
+        })
+    }
+}
@@ -0,0 +1,415 @@
+use std::ops::Deref;
+use async_trait::async_trait;
+use reqwest::Response;
+use serde_json::Value;
+use crate::core::base_structs::SenderBase;
+use crate::core::subtypes::{DeliveryPolicy, RetryPolicy};
+use crate::core::traits::{Responder, Sender};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::messenger::messenger_responder::MessengerResponder;
+use crate::integrations::messenger::utils::MessengerSerializer;
+
+/// Represents a sender for Messenger integration.
+///
+/// `MessengerSender` handles sending various types of messages (text, image, buttons, etc.)
+/// to a Facebook Messenger or Instagram client using the Messenger Platform Send API.
+pub struct MessengerSender{
+    /// The base structure that provides core functionalities.
+    sender_base: SenderBase
+}
+
+impl Deref for MessengerSender {
+    type Target = SenderBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sender_base
+    }
+}
+
+impl MessengerSender {
+
+    /// The base URL for Messenger Platform API.
+    const MESSENGER_API_URL: &'static str = "https://graph.facebook.com/v20.0/";
+    /// The codes of the Messenger API errors reporting the throttling of the messages.
+    const THROTTLING_ERROR_CODES: [i64; 4] = [4, 32, 613, 80006];
+
+    /// Creates a new instance of `MessengerSender`.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_sessions_per_moment` - The maximum number of sessions per moment.
+    /// * `api_key` - The page access token for authenticating requests.
+    /// * `connection_duration` - Optional connection duration.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `MessengerSender`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    ///
+    /// let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    /// ```
+    pub fn new(max_sessions_per_moment: usize, api_key: String, connection_duration: Option<u64>) -> Self {
+        Self {
+            sender_base: SenderBase::new(max_sessions_per_moment, api_key, connection_duration)
+                .set_delivery_policy(Self::default_delivery_policy())
+        }
+    }
+
+    /// Returns the default policy of delivering the messages to Messenger.
+    ///
+    /// The rate limit of the Messenger Send API depends on the number of the people the page can message,
+    /// so the messages are not limited and the throttled messages are retried.
+    ///
+    /// # Returns
+    ///
+    /// The default `DeliveryPolicy` of Messenger.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    ///
+    /// let policy = MessengerSender::default_delivery_policy();
+    /// assert_eq!(policy.global_rate_limit(), &None);
+    /// ```
+    pub fn default_delivery_policy() -> DeliveryPolicy {
+        DeliveryPolicy::new(None, None, RetryPolicy::default())
+    }
+
+    /// Sets the policy of delivering the messages to Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The rate limits and the retry policy of the requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `MessengerSender` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(100, 1000)), None, RetryPolicy::default());
+    /// let sender = MessengerSender::new(10, "page_access_token".to_string(), None).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.sender_base = self.sender_base.set_delivery_policy(delivery_policy);
+        self
+    }
+
+    /// Sends a message to the Messenger API within the rate limits, retrying it while it is throttled,
+    /// and checks the status of the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `body` - The JSON body of the message.
+    /// * `origin` - The name of the sending method, used in the error.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a successful `Response` or a `VoiceflousionError` if the request fails.
+    async fn send_message(&self, api_url: &str, chat_id: &str, body: Value, origin: &str) -> VoiceflousionResult<Response> {
+        let request = self.http_client()
+            .post(api_url)
+            .json(&body)
+            .header("Authorization", format!("Bearer {}", self.api_key()));
+
+        let messenger_response = self.http_client()
+            .send_with_retry(request, Some(chat_id), Self::is_throttled)
+            .await
+            .map_err(|e| VoiceflousionError::ClientRequestError(format!("MessengerSender {}", origin), e.to_string()))?;
+
+        if messenger_response.status().is_success() {
+            Ok(messenger_response)
+        } else {
+            let error_text = messenger_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError(format!("MessengerSender {}", origin), error_text))
+        }
+    }
+
+    /// Checks whether the Messenger API error reports the throttling of the messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The JSON body of the failed response.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error code is one of the throttling error codes.
+    fn is_throttled(body: &Value) -> bool {
+        body["error"]["code"].as_i64()
+            .map(|code| Self::THROTTLING_ERROR_CODES.contains(&code))
+            .unwrap_or(false)
+    }
+
+    /// Prepares the API URL for sending messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    ///
+    /// # Returns
+    ///
+    /// A `String` representing the full API URL.
+    fn prepare_api_url(client_id: &str) -> String {
+        format!("{}{}/messages", Self::MESSENGER_API_URL, client_id)
+    }
+}
+
+#[async_trait]
+impl Sender for MessengerSender {
+    type SenderResponder = MessengerResponder;
+
+    /// Sends a text message via Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `text` - The `VoiceflowText` object containing the message.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let text = VoiceflowText::new("Hello, World!".to_string());
+    ///     let response = sender.send_text(&client_id, text, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_text(&self, client_id: &String, text: VoiceflowText, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = MessengerSerializer::build_text_body(chat_id, text.message());
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_text").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Text(text)).await
+    }
+
+    /// Sends an image message via Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `image` - The `VoiceflowImage` object containing the image URL.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowImage;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let image = VoiceflowImage::new("https://example.com/image.jpg".to_string(), Some(100), Some(200));
+    ///     let response = sender.send_image(&client_id, image, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_image(&self, client_id: &String, image: VoiceflowImage, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = MessengerSerializer::build_attachment_body(chat_id, "image", image.url());
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_image").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Image(image)).await
+    }
+
+    /// Sends a buttons message via Messenger as a text message with quick replies.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' configuration.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowButton, VoiceflowButtons};
+    /// use serde_json::Value;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let buttons = vec![VoiceflowButton::new("Click me".to_string(), Value::Null, None)];
+    ///     let voiceflow_buttons = VoiceflowButtons::new(buttons);
+    ///     let response = sender.send_buttons(&client_id, voiceflow_buttons, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_buttons(&self, client_id: &String, buttons: VoiceflowButtons, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = MessengerSerializer::build_buttons_body(chat_id, &buttons);
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_buttons").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Buttons(buttons)).await
+    }
+
+    /// Sends a card message via Messenger as a generic template.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `card` - The `VoiceflowCard` object containing the card details.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCard;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let card = VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None);
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let response = sender.send_card(&client_id, card, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_card(&self, client_id: &String, card: VoiceflowCard, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = MessengerSerializer::build_card_body(chat_id, &card);
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_card").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Card(card)).await
+    }
+
+    /// Sends a carousel message via Messenger as a generic template with all the cards of the carousel.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `carousel` - The `VoiceflowCarousel` object containing the carousel details.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let cards = vec![VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None)];
+    ///     let carousel = VoiceflowCarousel::new(cards, true);
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let response = sender.send_carousel(&client_id, carousel, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_carousel(&self, client_id: &String, carousel: VoiceflowCarousel, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = MessengerSerializer::build_carousel_body(chat_id, &carousel, carousel.get_selected_mark());
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_carousel").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Carousel(carousel)).await
+    }
+
+    /// Sends an audio message via Messenger.
+    ///
+    /// If the audio source is not a URL, the message of the audio or nothing is sent instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The ID of the Facebook page or Instagram account.
+    /// * `audio` - The `VoiceflowAudio` object containing the audio source.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `MessengerResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::messenger::MessengerSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = MessengerSender::new(10, "page_access_token".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    ///     let response = sender.send_audio(&client_id, audio, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_audio(&self, client_id: &String, audio: VoiceflowAudio, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        let body = match audio.url() {
+            Some(url) => MessengerSerializer::build_attachment_body(chat_id, "audio", url),
+            None => {
+                return match audio.fallback_text() {
+                    Some(text) => self.send_text(client_id, text, chat_id).await.map(Some),
+                    None => Ok(None)
+                }
+            }
+        };
+
+        let api_url = Self::prepare_api_url(client_id);
+        let messenger_response = self.send_message(&api_url, chat_id, body, "send_audio").await?;
+
+        Self::SenderResponder::from_response(messenger_response, VoiceflowBlock::Audio(audio)).await.map(Some)
+    }
+}
@@ -0,0 +1,255 @@
+use std::ops::Deref;
+use serde_json::Value;
+use crate::core::base_structs::UpdateBase;
+use crate::core::subtypes::{InputLocation, InputMedia, InputMediaType, InputReaction, InteractionType};
+use crate::core::traits::Update;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::utils::ButtonCallbackData;
+
+/// Represents an update from a Facebook Messenger or Instagram messaging event.
+///
+/// `MessengerUpdate` processes the incoming messaging event and extracts relevant information,
+/// such as chat ID, interaction time, interaction type, and update ID.
+#[derive(Debug)]
+pub struct MessengerUpdate {
+    /// The base structure that provides core functionalities.
+    update_base: UpdateBase,
+}
+
+impl Deref for MessengerUpdate {
+    type Target = UpdateBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.update_base
+    }
+}
+
+impl MessengerUpdate{
+
+    /// Creates a new `MessengerUpdate` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The ID of the user (page-scoped or Instagram-scoped) who triggered the event.
+    /// * `interaction_time` - The timestamp of the interaction.
+    /// * `interaction_type` - The type of interaction (e.g., text message, button click).
+    /// * `update_id` - The unique ID of the update.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `MessengerUpdate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InteractionType;
+    /// use voiceflousion::integrations::messenger::MessengerUpdate;
+    ///
+    /// let interaction_type = InteractionType::new("message".to_string(), None, None);
+    /// let update = MessengerUpdate::new("chat_id".to_string(), 1627554661, interaction_type, "update_id".to_string());
+    /// ```
+    pub fn new(chat_id: String, interaction_time: i64, interaction_type: InteractionType, update_id: String) -> Self {
+        Self {
+            update_base: UpdateBase::new(chat_id, interaction_time, interaction_type, update_id)
+        }
+    }
+}
+
+impl Update for MessengerUpdate{
+    /// Constructs a `MessengerUpdate` from the request body.
+    ///
+    /// This method parses the first messaging event of an incoming Messenger or Instagram webhook
+    /// to extract information such as the chat ID, interaction time, and the type of interaction.
+    /// Quick replies and postbacks are handled as button presses (or as text messages if their
+    /// payload wasn't created by Voiceflousion), reactions and attachments as inputs.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The JSON body of the webhook.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `MessengerUpdate` instance or an error if the parsing fails.
+    ///
+    /// # Errors
+    ///
+    /// This function returns a `VoiceflousionError` if the necessary fields cannot be extracted from the JSON body.
+    fn from_request_body(body: Value) -> VoiceflousionResult<Self> {
+        // Extract the first messaging event from the body
+        let event = body["entry"][0].get("messaging")
+            .and_then(|messaging_value| messaging_value.as_array())
+            .and_then(|messaging_array| messaging_array.first())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate messaging".to_string(), body.clone()))?;
+
+        // Extract chat ID
+        let chat_id = event["sender"].get("id")
+            .and_then(|id| id.as_str())
+            .map(|id| id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate sender id (chat id)".to_string(), event.clone()))?;
+
+        // Extract interaction time, the timestamp of the event is in milliseconds
+        let interaction_time = event.get("timestamp")
+            .and_then(|timestamp| timestamp.as_i64())
+            .map(|timestamp| timestamp / 1000)
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate interaction timestamp".to_string(), event.clone()))?;
+
+        // Handle a postback of the generic template button
+        if let Some(postback) = event.get("postback") {
+            let update_id = extract_update_id(postback, interaction_time);
+            let payload = postback.get("payload")
+                .and_then(|payload| payload.as_str())
+                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate postback payload".to_string(), postback.clone()))?;
+
+            if let Some((interaction_type, button_mark)) = extract_button(payload) {
+                return Ok(Self::new(chat_id, button_mark, interaction_type, update_id))
+            }
+
+            // Postbacks of the buttons created outside of Voiceflousion (e.g. "Get Started") are handled by their title
+            let title = postback.get("title")
+                .and_then(|title| title.as_str())
+                .map(|title| title.to_string())
+                .unwrap_or_else(|| payload.to_string());
+
+            return Ok(Self::new(chat_id, interaction_time, InteractionType::Text(title), update_id))
+        }
+
+        // Handle a reaction to the message
+        if let Some(reaction) = event.get("reaction") {
+            let message_id = reaction.get("mid")
+                .and_then(|mid| mid.as_str())
+                .map(|mid| mid.to_string())
+                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate reaction message id".to_string(), reaction.clone()))?;
+
+            // The emoji is absent if the reaction was removed
+            let emoji = reaction.get("emoji").and_then(|emoji| emoji.as_str()).unwrap_or_default().to_string();
+            let update_id = format!("{}_{}", message_id, interaction_time);
+
+            return Ok(Self::new(chat_id, interaction_time, InteractionType::Reaction(InputReaction::new(message_id, emoji)), update_id))
+        }
+
+        // Extract the message from the event
+        let message = event.get("message")
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate message".to_string(), event.clone()))?;
+
+        let update_id = extract_update_id(message, interaction_time);
+
+        // Extract the text of the message
+        let text = message.get("text")
+            .and_then(|text| text.as_str())
+            .map(|text| text.to_string());
+
+        // Handle a quick reply
+        if let Some(payload) = message["quick_reply"].get("payload").and_then(|payload| payload.as_str()) {
+            if let Some((interaction_type, button_mark)) = extract_button(payload) {
+                return Ok(Self::new(chat_id, button_mark, interaction_type, update_id))
+            }
+        }
+
+        if let Some(text) = text {
+            return Ok(Self::new(chat_id, interaction_time, InteractionType::Text(text), update_id))
+        }
+
+        // Extract the media, file or location from the first attachment of the message
+        let attachment = message.get("attachments")
+            .and_then(|attachments| attachments.as_array())
+            .and_then(|attachments| attachments.first())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("MessengerUpdate message content".to_string(), message.clone()))?;
+
+        let interaction_type = extract_input(attachment)
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError(format!("MessengerUpdate unsupported attachment type {}", attachment["type"]), attachment.clone()))?;
+
+        Ok(Self::new(chat_id, interaction_time, interaction_type, update_id))
+    }
+}
+
+/// Extracts the ID of the message or postback, falling back to the interaction time.
+///
+/// # Parameters
+///
+/// * `value` - The JSON `Value` of the message or postback.
+/// * `interaction_time` - The interaction time of the event.
+///
+/// # Returns
+///
+/// A `String` with the update ID.
+fn extract_update_id(value: &Value, interaction_time: i64) -> String {
+    value.get("mid")
+        .and_then(|mid| mid.as_str())
+        .map(|mid| mid.to_string())
+        .unwrap_or_else(|| interaction_time.to_string())
+}
+
+/// Extracts the button interaction from the payload of a quick reply or postback.
+///
+/// # Parameters
+///
+/// * `payload` - The payload string.
+///
+/// # Returns
+///
+/// An `Option` containing the `InteractionType` and the timestamp mark of the button,
+/// or `None` if the payload isn't a valid button callback data.
+fn extract_button(payload: &str) -> Option<(InteractionType, i64)> {
+    let callback_data: ButtonCallbackData = serde_json::from_str(payload).ok()?;
+    let button_mark = callback_data.timestamp_mark()?;
+    let button_index = callback_data.index()?;
+
+    let interaction_type = match callback_data.card() {
+        Some(card_index) => InteractionType::CarouselButton(card_index, button_index),
+        None => InteractionType::Button(button_index)
+    };
+
+    Some((interaction_type, button_mark))
+}
+
+/// Extracts a media, file or location sent by the user from a Messenger attachment.
+///
+/// # Parameters
+///
+/// * `attachment` - The JSON `Value` of the attachment.
+///
+/// # Returns
+///
+/// An `Option` containing the `InteractionType` of the input, or `None` if the attachment type is not supported.
+fn extract_input(attachment: &Value) -> Option<InteractionType> {
+    let payload = attachment.get("payload")?;
+    match attachment.get("type").and_then(|attachment_type| attachment_type.as_str())? {
+        "image" => {
+            // Stickers are sent as images with the sticker ID
+            let media_type = if payload.get("sticker_id").is_some() {
+                InputMediaType::Sticker
+            } else {
+                InputMediaType::Image
+            };
+            extract_media(payload, media_type).map(InteractionType::Media)
+        },
+        "video" => extract_media(payload, InputMediaType::Video).map(InteractionType::Media),
+        "audio" => extract_media(payload, InputMediaType::Audio).map(InteractionType::Media),
+        "file" => extract_media(payload, InputMediaType::Document).map(InteractionType::File),
+        "location" => {
+            let coordinates = payload.get("coordinates")?;
+            let latitude = coordinates.get("lat").and_then(|latitude| latitude.as_f64())?;
+            let longitude = coordinates.get("long").and_then(|longitude| longitude.as_f64())?;
+            let name = attachment.get("title").and_then(|name| name.as_str()).map(|name| name.to_string());
+            Some(InteractionType::Location(InputLocation::new(latitude, longitude, name, None)))
+        },
+        _ => None
+    }
+}
+
+/// Extracts a media from a Messenger attachment payload.
+///
+/// Messenger attachments have no file IDs, so the URL of the attachment is used instead.
+///
+/// # Parameters
+///
+/// * `payload` - The JSON `Value` of the attachment payload.
+/// * `media_type` - The type of the media.
+///
+/// # Returns
+///
+/// An `Option` containing the `InputMedia`, or `None` if the payload has no URL.
+fn extract_media(payload: &Value, media_type: InputMediaType) -> Option<InputMedia> {
+    let url = payload.get("url").and_then(|url| url.as_str())?.to_string();
+    Some(InputMedia::new(media_type, url, None, None, None))
+}
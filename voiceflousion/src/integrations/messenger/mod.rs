@@ -0,0 +1,19 @@
+mod messenger_client;
+mod messenger_responder;
+mod messenger_sender;
+mod messenger_update;
+mod utils;
+
+#[cfg(feature = "advanced")]
+pub use self::{
+    messenger_sender::MessengerSender
+};
+
+#[cfg(not(feature = "advanced"))]
+pub(super) use self::{
+    messenger_sender::MessengerSender
+};
+
+pub use self::messenger_client::MessengerClient;
+pub use self::messenger_update::MessengerUpdate;
+pub use self::messenger_responder::MessengerResponder;
\ No newline at end of file
@@ -0,0 +1,267 @@
+use serde_json::{json, Value};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel};
+use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
+use crate::integrations::utils::ButtonCallbackDataBuilder;
+
+/// Serializer for constructing Messenger message bodies.
+///
+/// `MessengerSerializer` provides methods to create JSON payloads for different types of Messenger
+/// and Instagram messages, including text, attachments, quick replies and generic templates.
+pub(crate) struct MessengerSerializer {}
+
+impl MessengerSerializer {
+
+    /// Maximum allowed length for quick reply and button titles.
+    pub const ALLOWED_BUTTON_TITLE: usize = 20;
+
+    /// Maximum allowed length for titles and subtitles of generic template elements.
+    pub const ALLOWED_ELEMENT_TEXT: usize = 80;
+
+    /// Maximum number of quick replies in a message.
+    pub const MAX_QUICK_REPLIES: usize = 13;
+
+    /// Maximum number of buttons in a generic template element.
+    pub const MAX_ELEMENT_BUTTONS: usize = 3;
+
+    /// Maximum number of elements in a generic template.
+    pub const MAX_ELEMENTS: usize = 10;
+
+    /// Builds a JSON body for a text message to be sent via Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID (page-scoped or Instagram-scoped user ID).
+    /// * `text` - The text content of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_text_body(chat_id: &str, text: &str) -> Value {
+        Self::build_base_body(chat_id, json!({
+            "text": text
+        }))
+    }
+
+    /// Builds a JSON body for an attachment message to be sent via Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `attachment_type` - The type of the attachment (`image`, `audio`, `video` or `file`).
+    /// * `url` - The URL of the attachment to be sent.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_attachment_body(chat_id: &str, attachment_type: &str, url: &str) -> Value {
+        Self::build_base_body(chat_id, json!({
+            "attachment": {
+                "type": attachment_type,
+                "payload": {
+                    "url": url,
+                    "is_reusable": true
+                }
+            }
+        }))
+    }
+
+    /// Builds a JSON body for a text message with quick replies to be sent via Messenger.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' data.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_buttons_body(chat_id: &str, buttons: &VoiceflowButtons) -> Value {
+        let text = match buttons.option() {
+            VoiceflowButtonsOption::Text(text) => text.message().clone(),
+            VoiceflowButtonsOption::Empty => String::from("Invalid behavior. Please fix errors in MessengerSender usage")
+        };
+
+        let quick_replies: Vec<Value> = buttons.iter().enumerate()
+            .take(Self::MAX_QUICK_REPLIES)
+            .map(|(index, b)| {
+                let payload = ButtonCallbackDataBuilder::new().index(index).timestamp_mark(buttons.mark()).build().to_json_string();
+
+                json!({
+                    "content_type": "text",
+                    "title": Self::truncate(b.name(), Self::ALLOWED_BUTTON_TITLE),
+                    "payload": payload
+                })
+            }).collect();
+
+        Self::build_base_body(chat_id, json!({
+            "text": text,
+            "quick_replies": quick_replies
+        }))
+    }
+
+    /// Builds a JSON body for a card to be sent via Messenger as a generic template with one element.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `card` - The `VoiceflowCard` object containing the card data.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_card_body(chat_id: &str, card: &VoiceflowCard) -> Value {
+        let buttons = card.buttons().as_ref()
+            .map(|buttons| Self::build_postback_buttons_vec(buttons, buttons.mark(), None))
+            .unwrap_or_default();
+
+        let element = Self::build_element(card, buttons);
+        Self::build_generic_template_body(chat_id, vec![element])
+    }
+
+    /// Builds a JSON body for a carousel to be sent via Messenger as a generic template,
+    /// where every card of the carousel is an element of the template.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `carousel` - The `VoiceflowCarousel` object containing the cards.
+    /// * `mark` - A mark (i64) associated with the buttons, used in the postback payloads.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_carousel_body(chat_id: &str, carousel: &VoiceflowCarousel, mark: i64) -> Value {
+        let elements: Vec<Value> = carousel.cards().iter().enumerate()
+            .take(Self::MAX_ELEMENTS)
+            .map(|(card_index, card)| {
+                let buttons = card.buttons().as_ref()
+                    .map(|buttons| Self::build_postback_buttons_vec(buttons, mark, Some(card_index)))
+                    .unwrap_or_default();
+
+                Self::build_element(card, buttons)
+            }).collect();
+
+        Self::build_generic_template_body(chat_id, elements)
+    }
+
+    /// Builds the base structure for a Messenger message.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `message` - The JSON `Value` of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    fn build_base_body(chat_id: &str, message: Value) -> Value {
+        json!({
+            "recipient": {
+                "id": chat_id
+            },
+            "messaging_type": "RESPONSE",
+            "message": message
+        })
+    }
+
+    /// Builds the structure of a Messenger generic template message.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `elements` - A vector of `Value` representing the template elements.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    fn build_generic_template_body(chat_id: &str, elements: Vec<Value>) -> Value {
+        Self::build_base_body(chat_id, json!({
+            "attachment": {
+                "type": "template",
+                "payload": {
+                    "template_type": "generic",
+                    "elements": elements
+                }
+            }
+        }))
+    }
+
+    /// Builds a generic template element from a `VoiceflowCard`.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - A reference to the `VoiceflowCard` object.
+    /// * `buttons` - A vector of `Value` representing the postback buttons of the element.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` representing the element.
+    fn build_element(card: &VoiceflowCard, buttons: Vec<Value>) -> Value {
+        // The title is required by Messenger, so the description is used if the card has no title
+        let title = card.title().clone()
+            .or_else(|| card.description().clone())
+            .unwrap_or_else(|| String::from(" "));
+
+        let mut element = json!({
+            "title": Self::truncate(&title, Self::ALLOWED_ELEMENT_TEXT),
+        });
+
+        if card.title().is_some() {
+            if let Some(description) = card.description() {
+                element["subtitle"] = json!(Self::truncate(description, Self::ALLOWED_ELEMENT_TEXT));
+            }
+        }
+
+        if let Some(url) = card.image_url() {
+            element["image_url"] = json!(url);
+        }
+
+        if !buttons.is_empty() {
+            element["buttons"] = json!(buttons);
+        }
+
+        element
+    }
+
+    /// Builds a list of `VoiceflowButtons` into a vector of postback buttons for a generic template element.
+    ///
+    /// # Parameters
+    ///
+    /// * `buttons` - A reference to the `VoiceflowButtons` object.
+    /// * `buttons_mark` - A mark (i64) associated with the buttons, used in the postback payloads.
+    /// * `card_index` - The optional index of the carousel card the buttons belong to.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` representing the postback buttons.
+    fn build_postback_buttons_vec(buttons: &VoiceflowButtons, buttons_mark: i64, card_index: Option<usize>) -> Vec<Value> {
+        buttons.iter().enumerate()
+            .take(Self::MAX_ELEMENT_BUTTONS)
+            .map(|(index, b)| {
+                let mut builder = ButtonCallbackDataBuilder::new().index(index).timestamp_mark(buttons_mark);
+                if let Some(card_index) = card_index {
+                    builder = builder.card(card_index);
+                }
+
+                json!({
+                    "type": "postback",
+                    "title": Self::truncate(b.name(), Self::ALLOWED_BUTTON_TITLE),
+                    "payload": builder.build().to_json_string()
+                })
+            }).collect()
+    }
+
+    /// Truncates the text to the maximum number of characters.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to truncate.
+    /// * `max_len` - The maximum number of characters.
+    ///
+    /// # Returns
+    ///
+    /// The truncated `String`.
+    fn truncate(text: &str, max_len: usize) -> String {
+        text.chars().take(max_len).collect()
+    }
+}
@@ -0,0 +1,3 @@
+mod messenger_serializer;
+
+pub(super) use self::messenger_serializer::MessengerSerializer;
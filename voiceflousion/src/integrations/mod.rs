@@ -2,10 +2,19 @@
 pub mod telegram;
 #[cfg(feature = "whatsapp")]
 pub mod whatsapp;
-#[cfg(feature = "discord_unimplemented")]
+#[cfg(feature = "discord")]
 pub mod discord;
+#[cfg(feature = "messenger")]
+pub mod messenger;
+#[cfg(feature = "web")]
+pub mod web;
 
-#[cfg(not(feature = "advanced"))]
+#[cfg(all(not(feature = "advanced"), any(
+    feature = "telegram",
+    feature = "whatsapp",
+    feature = "discord",
+    feature = "messenger"
+)))]
 pub(crate) mod utils;
 
 #[cfg(feature = "advanced")]
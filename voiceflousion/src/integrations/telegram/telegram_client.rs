@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use crate::core::base_structs::ClientBase;
 use crate::core::ClientBuilder;
 use crate::core::session_wrappers::LockedSession;
+use crate::core::subtypes::InputMedia;
 use crate::core::traits::{Client, Sender};
 use crate::core::voiceflow::VoiceflowBlock;
 use crate::core::voiceflow::dialog_blocks::VoiceflowCarousel;
@@ -48,6 +49,12 @@ impl TelegramClient {
         let max_connections_per_moment = builder.max_connections_per_moment();
         let connection_duration = builder.connection_duration();
         let sender = TelegramSender::new(max_connections_per_moment, api_key, connection_duration);
+        let sender = if let Some(delivery_policy) = builder.delivery_policy(){
+            sender.set_delivery_policy(delivery_policy.clone())
+        }
+        else {
+            sender
+        };
 
         Self {
             client_base: ClientBase::new(builder, sender),
@@ -123,4 +130,17 @@ impl Client for TelegramClient {
             ))
         }
     }
+
+    /// Resolves the download URL of a media with the Telegram `getFile` API.
+    ///
+    /// # Parameters
+    ///
+    /// * `media` - The media sent by the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the download URL or a `VoiceflousionError` if the request fails.
+    async fn resolve_media_url(&self, media: &InputMedia) -> VoiceflousionResult<Option<String>> {
+        self.client_base.sender().get_file_url(media.file_id()).await.map(Some)
+    }
 }
\ No newline at end of file
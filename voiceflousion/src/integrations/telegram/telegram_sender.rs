@@ -1,13 +1,15 @@
 use std::ops::Deref;
 use async_trait::async_trait;
 use chrono::Utc;
-use reqwest::Response;
+use reqwest::{Response, StatusCode};
 use serde_json::Value;
+use tracing::warn;
 use crate::core::base_structs::SenderBase;
+use crate::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
 use crate::integrations::telegram::TelegramResponder;
 use crate::core::traits::{Responder, Sender};
 use crate::core::voiceflow::VoiceflowBlock;
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 use crate::integrations::telegram::utils::TelegramSerializer;
 
@@ -25,6 +27,12 @@ impl TelegramSender {
     /// The base URL for the Telegram API.
     const TELEGRAM_API_URL: &'static str = "https://api.telegram.org/bot";
 
+    /// The base URL for downloading files from the Telegram API.
+    const TELEGRAM_FILE_URL: &'static str = "https://api.telegram.org/file/bot";
+
+    /// The start of the error description Telegram returns for the message with invalid markup.
+    const PARSE_ERROR_DESCRIPTION: &'static str = "can't parse entities";
+
     /// Creates a new `TelegramSender`.
     ///
     /// # Parameters
@@ -48,29 +56,187 @@ impl TelegramSender {
     pub fn new(max_sessions_per_moment: usize, api_key: String, connection_duration: Option<u64>) -> Self {
         Self {
             sender_base: SenderBase::new(max_sessions_per_moment, api_key, connection_duration)
+                .set_delivery_policy(Self::default_delivery_policy())
         }
     }
 
-    /// Sends a message to the Telegram API.
+    /// Returns the default policy of delivering the messages to Telegram.
+    ///
+    /// The policy follows the Telegram Bot API limits of 30 messages per second for the bot
+    /// and about one message per second for a chat, allowing short bursts.
+    ///
+    /// # Returns
+    ///
+    /// The default `DeliveryPolicy` of Telegram.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    /// use voiceflousion::integrations::telegram::TelegramSender;
+    ///
+    /// let policy = TelegramSender::default_delivery_policy();
+    /// assert_eq!(policy.global_rate_limit(), &Some(RateLimit::new(30, 1000)));
+    /// ```
+    pub fn default_delivery_policy() -> DeliveryPolicy {
+        DeliveryPolicy::new(Some(RateLimit::new(30, 1000)), Some(RateLimit::new(3, 3000)), RetryPolicy::default())
+    }
+
+    /// Sets the policy of delivering the messages to Telegram.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The rate limits and the retry policy of the requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `TelegramSender` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RetryPolicy};
+    /// use voiceflousion::integrations::telegram::TelegramSender;
+    ///
+    /// let policy = DeliveryPolicy::new(None, None, RetryPolicy::new(5, 200, 10000));
+    /// let sender = TelegramSender::new(10, "api_key".to_string(), None).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.sender_base = self.sender_base.set_delivery_policy(delivery_policy);
+        self
+    }
+
+    /// Sends a message to the Telegram API within the rate limits, retrying it while it is throttled.
     ///
     /// # Parameters
     ///
     /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The optional ID of the chat the message is sent to.
     /// * `body` - The JSON body of the message.
     ///
     /// # Returns
     ///
     /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails.
-    async fn send_message(&self, api_url: &str, body: Value) -> VoiceflousionResult<Response> {
-        self.http_client()
+    async fn send_message(&self, api_url: &str, chat_id: Option<&str>, body: Value) -> VoiceflousionResult<Response> {
+        let request = self.http_client()
             .post(api_url)
-            .json(&body)
-            .send()
+            .json(&body);
+
+        // Telegram reports the throttling with `429 Too Many Requests` and `retry_after` handled by the HTTP client
+        self.http_client()
+            .send_with_retry(request, chat_id, |_| false)
             .await
             .map_err(|e| VoiceflousionError::ClientRequestError("TelegramSender send_message".to_string(), e.to_string()))
     }
 
 
+    /// Sends a formatted message to the Telegram API, resending it as plain text if Telegram rejects its markup.
+    ///
+    /// # Parameters
+    ///
+    /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `body` - The JSON body of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails
+    /// or the formatted message is rejected for another reason.
+    async fn send_formatted_message(&self, api_url: &str, chat_id: &str, body: Value) -> VoiceflousionResult<Response> {
+        let plain_body = match TelegramSerializer::build_plain_body(&body) {
+            Some(plain_body) => plain_body,
+            None => return self.send_message(api_url, Some(chat_id), body).await
+        };
+
+        let response = self.send_message(api_url, Some(chat_id), body).await?;
+        if response.status() != StatusCode::BAD_REQUEST {
+            return Ok(response);
+        }
+
+        let error_text = response.text().await.unwrap_or_default();
+        if !error_text.contains(Self::PARSE_ERROR_DESCRIPTION) {
+            return Err(VoiceflousionError::ClientRequestError("TelegramSender send_formatted_message".to_string(), error_text));
+        }
+
+        warn!(chat_id, error = %error_text, "Telegram rejected the message markup, resending it as plain text");
+        self.send_message(api_url, Some(chat_id), plain_body).await
+    }
+
+    /// Sends the parts of a message split to fit the Telegram limits one by one.
+    ///
+    /// # Parameters
+    ///
+    /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `parts` - The JSON bodies of the parts in the sending order.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `Response` to the last part or a `VoiceflousionError`
+    /// if the request fails or any of the leading parts is not sent.
+    async fn send_parts(&self, api_url: &str, chat_id: &str, parts: Vec<Value>) -> VoiceflousionResult<Response> {
+        let parts_count = parts.len();
+        let mut last_response = None;
+        for (index, body) in parts.into_iter().enumerate() {
+            let response = self.send_formatted_message(api_url, chat_id, body).await?;
+            // The response to the last part is checked by the caller
+            if index < parts_count - 1 && !response.status().is_success() {
+                let error_text = response.text().await.unwrap_or_default();
+                return Err(VoiceflousionError::ClientRequestError("TelegramSender send_parts".to_string(), error_text));
+            }
+            last_response = Some(response);
+        }
+
+        last_response.ok_or_else(|| VoiceflousionError::ClientRequestError("TelegramSender send_parts".to_string(), "There are no parts to send".to_string()))
+    }
+
+    /// Resolves the download URL of a file with the Telegram `getFile` API.
+    ///
+    /// Note that the download URL contains the API key of the bot.
+    ///
+    /// # Parameters
+    ///
+    /// * `file_id` - The ID of the file.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the download URL or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::telegram::TelegramSender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = TelegramSender::new(10, "api_key".to_string(), None);
+    ///     let url = sender.get_file_url("file_id").await;
+    ///     println!("{:?}", url);
+    /// }
+    /// ```
+    pub async fn get_file_url(&self, file_id: &str) -> VoiceflousionResult<String> {
+        let api_url = format!("{}{}/getFile", TelegramSender::TELEGRAM_API_URL, self.api_key());
+
+        let body = TelegramSerializer::build_get_file_body(file_id);
+
+        let telegram_response = self.send_message(&api_url, None, body).await?;
+
+        if !telegram_response.status().is_success() {
+            let error_text = telegram_response.text().await.unwrap_or_default();
+            return Err(VoiceflousionError::ClientRequestError("TelegramSender get_file_url".to_string(), error_text));
+        }
+
+        let json: Value = telegram_response.json().await
+            .map_err(|e| VoiceflousionError::ClientResponseReadingError("TelegramSender get_file_url".to_string(), e.to_string()))?;
+
+        let file_path = json["result"].get("file_path")
+            .and_then(|file_path| file_path.as_str())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("TelegramSender get_file_url file path".to_string(), json.to_string()))?;
+
+        Ok(format!("{}{}/{}", TelegramSender::TELEGRAM_FILE_URL, self.api_key(), file_path))
+    }
+
     /// Updates a carousel message in a chat.
     ///
     /// # Parameters
@@ -112,7 +278,7 @@ impl TelegramSender {
 
         let body = TelegramSerializer::build_carousel_update_card_body(chat_id, message_id, card, carousel.has_images(), index, carousel.len());
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_message(&api_url, Some(chat_id), body).await?;
 
         if telegram_response.status().is_success() {
             carousel.set_selected_card(index, timestamp);
@@ -195,9 +361,9 @@ impl Sender for TelegramSender{
     async fn send_text(&self, _client_id: &String, text: VoiceflowText, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
         let api_url = self.prepare_api_url(false, "send");
 
-        let body = TelegramSerializer::build_text_body(chat_id, text.message());
+        let parts = TelegramSerializer::build_text_parts(chat_id, &text);
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_parts(&api_url, chat_id, parts).await?;
 
         if telegram_response.status().is_success() {
             Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Text(text)).await
@@ -243,7 +409,7 @@ impl Sender for TelegramSender{
 
         let body = TelegramSerializer::build_image_body(chat_id, image.url());
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_message(&api_url, Some(chat_id), body).await?;
 
         if telegram_response.status().is_success() {
             Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Image(image)).await
@@ -289,9 +455,9 @@ impl Sender for TelegramSender{
     async fn send_buttons(&self, _client_id: &String, buttons: VoiceflowButtons, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
         let api_url = self.prepare_api_url(false, "send");
 
-        let body = TelegramSerializer::build_buttons_body(chat_id, &buttons);
+        let parts = TelegramSerializer::build_buttons_parts(chat_id, &buttons);
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_parts(&api_url, chat_id, parts).await?;
 
         if telegram_response.status().is_success() {
             Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Buttons(buttons)).await
@@ -338,7 +504,7 @@ impl Sender for TelegramSender{
 
         let body = TelegramSerializer::build_card_body(chat_id, &card);
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_message(&api_url, Some(chat_id), body).await?;
 
         if telegram_response.status().is_success() {
             Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Card(card)).await
@@ -387,7 +553,7 @@ impl Sender for TelegramSender{
 
         let body = TelegramSerializer::build_carousel_card_body(chat_id, card, index, carousel.len());
 
-        let telegram_response = self.send_message(&api_url, body).await?;
+        let telegram_response = self.send_message(&api_url, Some(chat_id), body).await?;
 
         if telegram_response.status().is_success() {
             Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Carousel(carousel)).await
@@ -396,4 +562,60 @@ impl Sender for TelegramSender{
             Err(VoiceflousionError::ClientRequestError("TelegramSender send_carousel".to_string(), error_text))
         }
     }
+
+    /// Sends an audio message to a chat.
+    ///
+    /// If the audio source is not a URL, the message of the audio or nothing is sent instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID.
+    /// * `audio` - The audio message to send.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `TelegramResponder` if the request succeeds,
+    /// or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::telegram::TelegramSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sender = TelegramSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::from("chat_id");
+    ///     let client_id = String::from("client_id");
+    ///     let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    ///     let response = sender.send_audio(&client_id, audio, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_audio(&self, client_id: &String, audio: VoiceflowAudio, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        let body = match audio.url() {
+            Some(url) => TelegramSerializer::build_audio_body(chat_id, url),
+            None => {
+                return match audio.fallback_text() {
+                    Some(text) => self.send_text(client_id, text, chat_id).await.map(Some),
+                    None => Ok(None)
+                }
+            }
+        };
+
+        let api_url = format!("{}{}/sendAudio", TelegramSender::TELEGRAM_API_URL, self.api_key());
+
+        let telegram_response = self.send_message(&api_url, Some(chat_id), body).await?;
+
+        if telegram_response.status().is_success() {
+            Self::SenderResponder::from_response(telegram_response, VoiceflowBlock::Audio(audio)).await.map(Some)
+        } else {
+            let error_text = telegram_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError("TelegramSender send_audio".to_string(), error_text))
+        }
+    }
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use std::ops::Deref;
 use serde_json::Value;
 use crate::core::base_structs::UpdateBase;
-use crate::core::subtypes::InteractionType;
+use crate::core::subtypes::{InputContact, InputLocation, InputMedia, InputMediaType, InteractionType};
 use crate::core::traits::Update;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 use crate::integrations::utils::ButtonCallbackData;
@@ -176,8 +176,12 @@ impl Update for TelegramUpdate {
             button_index = callback_data.index();
         }
 
-        // Create an InteractionType from the text, path and button index
-        let interaction_type = InteractionType::new(text, button_index, carousel_direction);
+        // Extract the media, file, location or contact from the message, otherwise create an InteractionType from the text, path and button index
+        let interaction_type = if is_message {
+            extract_input(update_data)
+        } else {
+            None
+        }.unwrap_or_else(|| InteractionType::new(text, button_index, carousel_direction));
 
         // Return the constructed TelegramUpdate
         Ok(TelegramUpdate::new(
@@ -190,3 +194,81 @@ impl Update for TelegramUpdate {
         ))
     }
 }
+
+/// Extracts a media, file, location or contact sent by the user from a Telegram message.
+///
+/// # Parameters
+///
+/// * `message` - The JSON `Value` of the message.
+///
+/// # Returns
+///
+/// An `Option` containing the `InteractionType` of the input, or `None` if the message contains no such input.
+fn extract_input(message: &Value) -> Option<InteractionType> {
+    let caption = message.get("caption").and_then(|caption| caption.as_str()).map(|caption| caption.to_string());
+
+    // Photos are sent in several sizes, the last one is the largest
+    if let Some(photo) = message.get("photo").and_then(|photo| photo.as_array()).and_then(|sizes| sizes.last()) {
+        return extract_media(photo, InputMediaType::Image, caption).map(InteractionType::Media);
+    }
+
+    // Animation messages also contain a document, so they are checked first
+    let media_types = [
+        ("animation", InputMediaType::Animation),
+        ("video", InputMediaType::Video),
+        ("video_note", InputMediaType::Video),
+        ("audio", InputMediaType::Audio),
+        ("voice", InputMediaType::Voice),
+        ("sticker", InputMediaType::Sticker),
+    ];
+    for (field, media_type) in media_types {
+        if let Some(media) = message.get(field) {
+            return extract_media(media, media_type, caption).map(InteractionType::Media);
+        }
+    }
+
+    if let Some(document) = message.get("document") {
+        return extract_media(document, InputMediaType::Document, caption).map(InteractionType::File);
+    }
+
+    if let Some(location) = message.get("location") {
+        let latitude = location.get("latitude").and_then(|latitude| latitude.as_f64())?;
+        let longitude = location.get("longitude").and_then(|longitude| longitude.as_f64())?;
+        // Venues contain the location with its title and address
+        let venue = &message["venue"];
+        let name = venue.get("title").and_then(|title| title.as_str()).map(|title| title.to_string());
+        let address = venue.get("address").and_then(|address| address.as_str()).map(|address| address.to_string());
+        return Some(InteractionType::Location(InputLocation::new(latitude, longitude, name, address)));
+    }
+
+    if let Some(contact) = message.get("contact") {
+        let phone_number = contact.get("phone_number").and_then(|phone_number| phone_number.as_str())?.to_string();
+        let name = [contact.get("first_name"), contact.get("last_name")].iter()
+            .filter_map(|name| name.and_then(|name| name.as_str()))
+            .collect::<Vec<&str>>()
+            .join(" ");
+        let name = if name.is_empty() { None } else { Some(name) };
+        let user_id = contact.get("user_id").and_then(|user_id| user_id.as_i64()).map(|user_id| user_id.to_string());
+        return Some(InteractionType::Contact(InputContact::new(phone_number, name, user_id)));
+    }
+
+    None
+}
+
+/// Extracts a media from a Telegram file object.
+///
+/// # Parameters
+///
+/// * `media` - The JSON `Value` of the file object.
+/// * `media_type` - The type of the media.
+/// * `caption` - The optional caption of the message.
+///
+/// # Returns
+///
+/// An `Option` containing the `InputMedia`, or `None` if the file object has no file ID.
+fn extract_media(media: &Value, media_type: InputMediaType, caption: Option<String>) -> Option<InputMedia> {
+    let file_id = media.get("file_id").and_then(|file_id| file_id.as_str())?.to_string();
+    let mime_type = media.get("mime_type").and_then(|mime_type| mime_type.as_str()).map(|mime_type| mime_type.to_string());
+    let file_name = media.get("file_name").and_then(|file_name| file_name.as_str()).map(|file_name| file_name.to_string());
+    Some(InputMedia::new(media_type, file_id, caption, mime_type, file_name))
+}
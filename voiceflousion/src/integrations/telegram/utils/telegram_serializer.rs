@@ -1,7 +1,7 @@
 use serde_json::{json, Value};
 use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard};
-use crate::integrations::utils::ButtonCallbackDataBuilder;
+use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowText};
+use crate::integrations::utils::{ButtonCallbackDataBuilder, TextSplitter};
 
 /// `TelegramSerializer` provides methods for serializing various types of messages
 /// into JSON format compatible with the Telegram API. These include text, image,
@@ -9,6 +9,15 @@ use crate::integrations::utils::ButtonCallbackDataBuilder;
 pub(crate) struct TelegramSerializer;
 
 impl TelegramSerializer {
+    /// Maximum allowed length of the message text.
+    pub const MAX_TEXT_LENGTH: usize = 4096;
+
+    /// Maximum allowed length of the media caption.
+    pub const MAX_CAPTION_LENGTH: usize = 1024;
+
+    /// The parse mode of the formatted texts.
+    pub const PARSE_MODE: &'static str = "HTML";
+
     /// Builds the JSON body for sending a text message via the Telegram API.
     ///
     /// # Parameters
@@ -26,6 +35,62 @@ impl TelegramSerializer {
         })
     }
 
+    /// Builds the JSON bodies for sending a text message via the Telegram API,
+    /// splitting the text longer than the message limit into several messages.
+    ///
+    /// The formatted text is sent as HTML, its length is limited by the text without the markup.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `text` - The `VoiceflowText` to send.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` containing the JSON bodies of the messages in the sending order.
+    pub fn build_text_parts(chat_id: &str, text: &VoiceflowText) -> Vec<Value> {
+        match text.rich_text() {
+            Some(rich_text) => TextSplitter::split_rich_text(rich_text, Self::MAX_TEXT_LENGTH).iter()
+                .map(|part| {
+                    let mut body = Self::build_text_body(chat_id, &part.to_telegram_html());
+                    body["parse_mode"] = json!(Self::PARSE_MODE);
+                    body
+                })
+                .collect(),
+            None => TextSplitter::split(text.message(), Self::MAX_TEXT_LENGTH).iter()
+                .map(|part| Self::build_text_body(chat_id, part))
+                .collect()
+        }
+    }
+
+    /// Builds the unformatted copy of the message body for resending it when Telegram rejects its markup.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The JSON body of the message.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the JSON body without the parse mode and the markup,
+    /// or `None` if the message is not formatted.
+    pub fn build_plain_body(body: &Value) -> Option<Value> {
+        if body.get("parse_mode").and_then(|parse_mode| parse_mode.as_str()) != Some(Self::PARSE_MODE) {
+            return None;
+        }
+
+        let mut plain_body = body.clone();
+        if let Some(plain_body) = plain_body.as_object_mut() {
+            plain_body.remove("parse_mode");
+            for field in ["text", "caption"] {
+                if let Some(text) = plain_body.get(field).and_then(|text| text.as_str()) {
+                    let text = Self::strip_html(text);
+                    plain_body.insert(field.to_string(), json!(text));
+                }
+            }
+        }
+        Some(plain_body)
+    }
+
     /// Builds the JSON body for sending an image message via the Telegram API.
     ///
     /// # Parameters
@@ -43,31 +108,68 @@ impl TelegramSerializer {
         })
     }
 
-    /// Builds the JSON body for sending a message with buttons via the Telegram API.
+    /// Builds the JSON body for sending an audio message via the Telegram API.
     ///
     /// # Parameters
     ///
     /// * `chat_id` - The chat ID of the recipient.
-    /// * `buttons` - The `VoiceflowButtons` to send.
+    /// * `audio_url` - The URL of the audio to send.
     ///
     /// # Returns
     ///
     /// A `Value` containing the JSON body for the request.
-    pub fn build_buttons_body(chat_id: &str, buttons: &VoiceflowButtons) -> Value {
+    pub fn build_audio_body(chat_id: &str, audio_url: &str) -> Value {
+        json!({
+            "chat_id": chat_id,
+            "audio": audio_url,
+        })
+    }
+
+    /// Builds the JSON body for getting a file via the Telegram API.
+    ///
+    /// # Parameters
+    ///
+    /// * `file_id` - The ID of the file.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the JSON body for the request.
+    pub fn build_get_file_body(file_id: &str) -> Value {
+        json!({
+            "file_id": file_id,
+        })
+    }
+
+    /// Builds the JSON bodies for sending a message with buttons via the Telegram API.
+    ///
+    /// The text longer than the message limit is split, its leading parts are sent
+    /// as text messages and the last part is sent with the buttons.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `buttons` - The `VoiceflowButtons` to send.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` containing the JSON bodies of the messages in the sending order.
+    pub fn build_buttons_parts(chat_id: &str, buttons: &VoiceflowButtons) -> Vec<Value> {
         let text = match buttons.option() {
-            VoiceflowButtonsOption::Text(text) => text.message().clone(),
-            VoiceflowButtonsOption::Empty => String::from("Invalid behavior. Please fix errors in TelegramSender usage")
+            VoiceflowButtonsOption::Text(text) => text.clone(),
+            VoiceflowButtonsOption::Empty => VoiceflowText::new(String::from("Invalid behavior. Please fix errors in TelegramSender usage"))
         };
 
+        let mut parts = Self::build_text_parts(chat_id, &text);
+
         let inline_keyboard: Vec<Vec<Value>> = Self::build_buttons_vec(buttons);
 
-        json!({
-            "chat_id": chat_id,
-            "text": text,
-            "reply_markup": {
+        // The buttons are attached to the last part of the text
+        if let Some(last_part) = parts.last_mut() {
+            last_part["reply_markup"] = json!({
                 "inline_keyboard": inline_keyboard,
-            }
-        })
+            });
+        }
+        parts
     }
 
     /// Builds the JSON body for sending a card message via the Telegram API.
@@ -137,6 +239,7 @@ impl TelegramSerializer {
         let text = format!("{}\n\n{}", title, description);
 
         let inline_keyboard: Vec<Vec<Value>> = Self::build_carousel_card_buttons_vec(card, index, carousel_length);
+        let text = Self::truncate_card_text(&text, has_image);
 
         if has_image {
             json!({
@@ -176,6 +279,8 @@ impl TelegramSerializer {
     ///
     /// A `Value` containing the JSON body for the request.
     fn build_card_base_body(chat_id: &str, text: String, image_url: &Option<String>, inline_keyboard: Vec<Vec<Value>>) -> Value {
+        let text = Self::truncate_card_text(&text, image_url.is_some());
+
         match image_url {
             None => {
                 json!({
@@ -199,6 +304,52 @@ impl TelegramSerializer {
         }
     }
 
+    /// Removes the HTML tags from the text and unescapes its entities.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The HTML text.
+    ///
+    /// # Returns
+    ///
+    /// The text without the markup.
+    fn strip_html(text: &str) -> String {
+        let mut stripped = String::with_capacity(text.len());
+        let mut is_tag = false;
+        for c in text.chars() {
+            match c {
+                '<' => is_tag = true,
+                '>' if is_tag => is_tag = false,
+                _ if !is_tag => stripped.push(c),
+                _ => {}
+            }
+        }
+
+        stripped.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&amp;", "&")
+    }
+
+    /// Truncates the text of a card to the caption limit if the card has an image, or to the message limit otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text of the card.
+    /// * `has_image` - A boolean indicating if the card has an image.
+    ///
+    /// # Returns
+    ///
+    /// The text fitting the limit.
+    fn truncate_card_text(text: &str, has_image: bool) -> String {
+        let max_length = if has_image {
+            Self::MAX_CAPTION_LENGTH
+        } else {
+            Self::MAX_TEXT_LENGTH
+        };
+        TextSplitter::truncate(text, max_length)
+    }
+
     /// Converts `VoiceflowButtons` to a keyboard layout for Telegram inline keyboard.
     ///
     /// # Parameters
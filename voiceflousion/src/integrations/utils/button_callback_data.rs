@@ -8,6 +8,8 @@ pub struct ButtonCallbackData {
     timestamp_mark: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     direction: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<usize>,
 }
 
 impl ButtonCallbackData {
@@ -18,15 +20,17 @@ impl ButtonCallbackData {
     /// * `index` - An optional index value.
     /// * `timestamp_mark` - An optional timestamp mark.
     /// * `direction` - An optional direction (true for forward, false for backward).
+    /// * `card` - An optional index of the carousel card the button belongs to.
     ///
     /// # Returns
     ///
     /// A new `ButtonCallbackData` instance.
-    fn new(index: Option<usize>, timestamp_mark: Option<i64>, direction: Option<bool>) -> Self {
+    fn new(index: Option<usize>, timestamp_mark: Option<i64>, direction: Option<bool>, card: Option<usize>) -> Self {
         Self {
             index,
             timestamp_mark,
             direction,
+            card,
         }
     }
 
@@ -53,6 +57,11 @@ impl ButtonCallbackData {
     pub fn direction(&self) -> Option<bool> {
         self.direction
     }
+
+    /// Returns a reference to the carousel card index, if any.
+    pub fn card(&self) -> Option<usize> {
+        self.card
+    }
 }
 
 #[derive(Debug, Default)]
@@ -60,6 +69,7 @@ pub struct ButtonCallbackDataBuilder {
     index: Option<usize>,
     timestamp_mark: Option<i64>,
     direction: Option<bool>,
+    card: Option<usize>,
 }
 
 impl ButtonCallbackDataBuilder {
@@ -114,12 +124,26 @@ impl ButtonCallbackDataBuilder {
         self
     }
 
+    /// Sets the index of the carousel card the button belongs to.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - The carousel card index to set.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder instance.
+    pub fn card(mut self, card: usize) -> Self {
+        self.card = Some(card);
+        self
+    }
+
     /// Builds the `ButtonCallbackData` instance.
     ///
     /// # Returns
     ///
     /// A new `ButtonCallbackData` instance.
     pub fn build(self) -> ButtonCallbackData {
-        ButtonCallbackData::new(self.index, self.timestamp_mark, self.direction)
+        ButtonCallbackData::new(self.index, self.timestamp_mark, self.direction, self.card)
     }
 }
\ No newline at end of file
@@ -1,2 +1,7 @@
 mod button_callback_data;
-pub use button_callback_data::{ButtonCallbackData, ButtonCallbackDataBuilder};
\ No newline at end of file
+#[cfg(any(feature = "telegram", feature = "whatsapp"))]
+mod text_splitter;
+
+pub use button_callback_data::{ButtonCallbackData, ButtonCallbackDataBuilder};
+#[cfg(any(feature = "telegram", feature = "whatsapp"))]
+pub use text_splitter::TextSplitter;
\ No newline at end of file
@@ -0,0 +1,325 @@
+use std::ops::Range;
+use crate::core::formatting::RichText;
+
+/// Splits and truncates the texts to fit the length limits of the client platforms.
+///
+/// The length of a text is measured in UTF-16 code units as the Telegram API counts it,
+/// which never falls below the number of characters counted by the other platforms.
+/// The texts are cut only on the boundaries of the grapheme clusters, so emoji sequences,
+/// flags and letters with combining marks are never broken.
+pub struct TextSplitter;
+
+impl TextSplitter {
+    /// The ellipsis appended to the truncated texts.
+    const ELLIPSIS: char = '…';
+
+    /// Returns the length of the text as it is counted by the client platforms.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to measure.
+    ///
+    /// # Returns
+    ///
+    /// The length of the text in UTF-16 code units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::utils::TextSplitter;
+    ///
+    /// assert_eq!(TextSplitter::length("Hello"), 5);
+    /// assert_eq!(TextSplitter::length("👋"), 2);
+    /// ```
+    pub fn length(text: &str) -> usize {
+        text.encode_utf16().count()
+    }
+
+    /// Splits the text into the parts fitting the length limit.
+    ///
+    /// The text is split on the last paragraph boundary within the limit, then on the last line break,
+    /// the last sentence end and the last whitespace. The words longer than the limit are split
+    /// on the grapheme boundaries.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to split.
+    /// * `max_length` - The maximum length of a part.
+    ///
+    /// # Returns
+    ///
+    /// A vector of at least one part of the text, or the text itself if it fits the limit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::utils::TextSplitter;
+    ///
+    /// let parts = TextSplitter::split("First paragraph.\n\nSecond one. And more.", 25);
+    /// assert_eq!(parts, vec!["First paragraph.", "Second one. And more."]);
+    ///
+    /// let parts = TextSplitter::split("One sentence. Another sentence.", 20);
+    /// assert_eq!(parts, vec!["One sentence.", "Another sentence."]);
+    /// ```
+    pub fn split(text: &str, max_length: usize) -> Vec<String> {
+        Self::split_ranges(text, max_length).into_iter()
+            .map(|range| text[range].to_string())
+            .collect()
+    }
+
+    /// Splits the formatted text into the parts fitting the length limit, keeping their formatting.
+    ///
+    /// The plain text is split as by [`TextSplitter::split`], so the formatting never affects the parts.
+    ///
+    /// # Parameters
+    ///
+    /// * `rich_text` - The formatted text to split.
+    /// * `max_length` - The maximum length of the plain text of a part.
+    ///
+    /// # Returns
+    ///
+    /// A vector of at least one part of the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::formatting::RichText;
+    /// use voiceflousion::integrations::utils::TextSplitter;
+    ///
+    /// let rich_text = RichText::from_markdown("**First** sentence. *Second* sentence.");
+    /// let parts = TextSplitter::split_rich_text(&rich_text, 20);
+    /// assert_eq!(parts[0].to_telegram_html(), "<b>First</b> sentence.");
+    /// assert_eq!(parts[1].to_telegram_html(), "<i>Second</i> sentence.");
+    /// ```
+    pub fn split_rich_text(rich_text: &RichText, max_length: usize) -> Vec<RichText> {
+        Self::split_ranges(&rich_text.plain_text(), max_length).into_iter()
+            .map(|range| rich_text.slice(range))
+            .collect()
+    }
+
+    /// Splits the text into the byte ranges of the parts fitting the length limit.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to split.
+    /// * `max_length` - The maximum length of a part.
+    ///
+    /// # Returns
+    ///
+    /// A vector of at least one range of the text, trimmed of the whitespaces at the split points.
+    fn split_ranges(text: &str, max_length: usize) -> Vec<Range<usize>> {
+        if Self::length(text) <= max_length || max_length == 0 {
+            return vec![0..text.len()];
+        }
+
+        let mut ranges = Vec::new();
+        let mut start = 0;
+
+        while Self::length(&text[start..]) > max_length {
+            let rest = &text[start..];
+            let limit = Self::fitting_prefix_len(rest, max_length);
+            let cut = Self::boundary_index(rest, limit);
+
+            let part = rest[..cut].trim_end();
+            if !part.is_empty() {
+                ranges.push(start..start + part.len());
+            }
+
+            let next = &rest[cut..];
+            start += cut + next.len() - next.trim_start().len();
+        }
+
+        if start < text.len() || ranges.is_empty() {
+            ranges.push(start..text.len());
+        }
+
+        ranges
+    }
+
+    /// Truncates the text to the length limit, ending it with an ellipsis.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to truncate.
+    /// * `max_length` - The maximum length of the text including the ellipsis.
+    ///
+    /// # Returns
+    ///
+    /// The text itself if it fits the limit, or its longest grapheme-aligned prefix followed by the ellipsis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::utils::TextSplitter;
+    ///
+    /// assert_eq!(TextSplitter::truncate("Short", 10), "Short");
+    /// assert_eq!(TextSplitter::truncate("Truncated title", 10), "Truncated…");
+    /// assert_eq!(TextSplitter::truncate("Café 🇺🇦🇺🇦", 7), "Café…");
+    /// ```
+    pub fn truncate(text: &str, max_length: usize) -> String {
+        if Self::length(text) <= max_length {
+            return text.to_string();
+        }
+        if max_length == 0 {
+            return String::new();
+        }
+
+        let limit = Self::fitting_prefix_len(text, max_length - Self::ELLIPSIS.len_utf16());
+        let mut truncated = text[..limit].trim_end().to_string();
+        truncated.push(Self::ELLIPSIS);
+        truncated
+    }
+
+    /// Finds the longest prefix of the text ending on a grapheme boundary and fitting the length limit.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text.
+    /// * `max_length` - The maximum length of the prefix.
+    ///
+    /// # Returns
+    ///
+    /// The length of the prefix in bytes.
+    fn fitting_prefix_len(text: &str, max_length: usize) -> usize {
+        let mut length = 0;
+        let mut end = 0;
+
+        for grapheme in Self::graphemes(text) {
+            length += Self::length(grapheme);
+            if length > max_length {
+                break;
+            }
+            end += grapheme.len();
+        }
+
+        end
+    }
+
+    /// Finds the index to split the text at within the prefix, preferring the paragraph,
+    /// line, sentence and word boundaries.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text.
+    /// * `limit` - The length of the prefix in bytes.
+    ///
+    /// # Returns
+    ///
+    /// The index of the split in bytes.
+    fn boundary_index(text: &str, limit: usize) -> usize {
+        // A single grapheme longer than the limit is taken whole to keep going
+        if limit == 0 {
+            return Self::graphemes(text).next().map(|grapheme| grapheme.len()).unwrap_or(text.len());
+        }
+
+        let prefix = &text[..limit];
+
+        if let Some(index) = prefix.rfind("\n\n").filter(|index| *index > 0) {
+            return index;
+        }
+        if let Some(index) = prefix.rfind('\n').filter(|index| *index > 0) {
+            return index;
+        }
+
+        // The sentence ends with a punctuation mark followed by a whitespace
+        let sentence_end = prefix.char_indices().rev()
+            .filter(|(_, c)| matches!(c, '.' | '!' | '?' | '…'))
+            .map(|(index, c)| index + c.len_utf8())
+            .find(|end| text[*end..].starts_with(char::is_whitespace));
+        if let Some(index) = sentence_end {
+            return index;
+        }
+
+        if let Some(index) = prefix.rfind(char::is_whitespace).filter(|index| *index > 0) {
+            return index;
+        }
+
+        limit
+    }
+
+    /// Splits the text into the extended grapheme clusters of the common scripts and emoji.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The text to split.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the grapheme clusters.
+    fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+        let mut rest = text;
+
+        std::iter::from_fn(move || {
+            let mut chars = rest.char_indices().peekable();
+            let (_, first) = chars.next()?;
+            let mut previous = first;
+            let mut regional_indicators = usize::from(Self::is_regional_indicator(first));
+            let mut end = rest.len();
+
+            while let Some(&(index, c)) = chars.peek() {
+                let joined = (previous == '\r' && c == '\n')
+                    || previous == '\u{200D}'
+                    || Self::is_extender(c)
+                    || (regional_indicators == 1 && Self::is_regional_indicator(c));
+                if !joined {
+                    end = index;
+                    break;
+                }
+                if Self::is_regional_indicator(c) {
+                    regional_indicators += 1;
+                }
+                previous = c;
+                chars.next();
+            }
+
+            let (grapheme, tail) = rest.split_at(end);
+            rest = tail;
+            Some(grapheme)
+        })
+    }
+
+    /// Checks whether the character extends the previous grapheme cluster.
+    ///
+    /// # Parameters
+    ///
+    /// * `c` - The character.
+    ///
+    /// # Returns
+    ///
+    /// `true` for the combining marks, the variation selectors, the zero width joiner,
+    /// the emoji modifiers and the tag characters.
+    fn is_extender(c: char) -> bool {
+        matches!(c,
+            '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{0591}'..='\u{05BD}'
+            | '\u{0610}'..='\u{061A}'
+            | '\u{064B}'..='\u{065F}'
+            | '\u{0900}'..='\u{0903}'
+            | '\u{093A}'..='\u{094F}'
+            | '\u{0E31}' | '\u{0E34}'..='\u{0E3A}' | '\u{0E47}'..='\u{0E4E}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{200C}' | '\u{200D}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FE20}'..='\u{FE2F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+        )
+    }
+
+    /// Checks whether the character is a regional indicator, pairs of which form the flags.
+    ///
+    /// # Parameters
+    ///
+    /// * `c` - The character.
+    ///
+    /// # Returns
+    ///
+    /// `true` for the regional indicator symbols.
+    fn is_regional_indicator(c: char) -> bool {
+        matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+mod web_client;
+mod web_event_stream;
+mod web_responder;
+mod web_sender;
+mod web_update;
+mod utils;
+
+#[cfg(feature = "advanced")]
+pub use self::{
+    web_sender::WebSender
+};
+
+#[cfg(not(feature = "advanced"))]
+pub(super) use self::{
+    web_sender::WebSender
+};
+
+pub use self::web_client::WebClient;
+pub use self::web_update::WebUpdate;
+pub use self::web_responder::WebResponder;
+pub use self::web_event_stream::WebEventStream;
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod web_serializer;
+
+pub(super) use self::web_serializer::WebSerializer;
@@ -0,0 +1,187 @@
+use serde_json::{json, Value};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel};
+use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
+
+/// Serializer for rendering messages for web chat frontends.
+///
+/// `WebSerializer` provides methods to create JSON messages for the frontends from the Voiceflow blocks.
+/// Every button of the rendered messages contains the `callback` object, which must be sent back
+/// in the update body when the button is pressed.
+pub(crate) struct WebSerializer {}
+
+impl WebSerializer {
+
+    /// Builds a JSON message with text.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `text` - The text content of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_text_body(message_id: &str, text: &str) -> Value {
+        json!({
+            "message_id": message_id,
+            "type": "text",
+            "text": text
+        })
+    }
+
+    /// Builds a JSON message with an image.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `url` - The URL of the image.
+    /// * `height` - The optional height of the image.
+    /// * `width` - The optional width of the image.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_image_body(message_id: &str, url: &str, height: Option<u64>, width: Option<u64>) -> Value {
+        json!({
+            "message_id": message_id,
+            "type": "image",
+            "url": url,
+            "height": height,
+            "width": width
+        })
+    }
+
+    /// Builds a JSON message with an audio.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `url` - The URL of the audio.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_audio_body(message_id: &str, url: &str) -> Value {
+        json!({
+            "message_id": message_id,
+            "type": "audio",
+            "url": url
+        })
+    }
+
+    /// Builds a JSON message with text and buttons.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' data.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_buttons_body(message_id: &str, buttons: &VoiceflowButtons) -> Value {
+        let text = match buttons.option() {
+            VoiceflowButtonsOption::Text(text) => Some(text.message().clone()),
+            VoiceflowButtonsOption::Empty => None
+        };
+
+        json!({
+            "message_id": message_id,
+            "type": "buttons",
+            "text": text,
+            "buttons": Self::build_buttons_vec(buttons, buttons.mark(), None)
+        })
+    }
+
+    /// Builds a JSON message with a card.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `card` - The `VoiceflowCard` object containing the card data.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_card_body(message_id: &str, card: &VoiceflowCard) -> Value {
+        let mut body = Self::build_card(card, card.buttons().as_ref().map(|buttons| buttons.mark()).unwrap_or_default(), None);
+        body["message_id"] = json!(message_id);
+        body["type"] = json!("card");
+        body
+    }
+
+    /// Builds a JSON message with all the cards of a carousel.
+    ///
+    /// # Parameters
+    ///
+    /// * `message_id` - The ID of the message.
+    /// * `carousel` - The `VoiceflowCarousel` object containing the cards.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the rendered message.
+    pub fn build_carousel_body(message_id: &str, carousel: &VoiceflowCarousel) -> Value {
+        let mark = carousel.get_selected_mark();
+        let cards: Vec<Value> = carousel.cards().iter().enumerate()
+            .map(|(card_index, card)| Self::build_card(card, mark, Some(card_index)))
+            .collect();
+
+        json!({
+            "message_id": message_id,
+            "type": "carousel",
+            "cards": cards
+        })
+    }
+
+    /// Builds the JSON object of a card.
+    ///
+    /// # Parameters
+    ///
+    /// * `card` - A reference to the `VoiceflowCard` object.
+    /// * `mark` - A mark (i64) associated with the buttons, used in the callbacks.
+    /// * `card_index` - The optional index of the carousel card.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` representing the card.
+    fn build_card(card: &VoiceflowCard, mark: i64, card_index: Option<usize>) -> Value {
+        let buttons = card.buttons().as_ref()
+            .map(|buttons| Self::build_buttons_vec(buttons, mark, card_index))
+            .unwrap_or_default();
+
+        json!({
+            "title": card.title(),
+            "description": card.description(),
+            "image_url": card.image_url(),
+            "buttons": buttons
+        })
+    }
+
+    /// Builds a list of `VoiceflowButtons` into a vector of buttons with their callbacks.
+    ///
+    /// # Parameters
+    ///
+    /// * `buttons` - A reference to the `VoiceflowButtons` object.
+    /// * `buttons_mark` - A mark (i64) associated with the buttons, used in the callbacks.
+    /// * `card_index` - The optional index of the carousel card the buttons belong to.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` representing the buttons.
+    fn build_buttons_vec(buttons: &VoiceflowButtons, buttons_mark: i64, card_index: Option<usize>) -> Vec<Value> {
+        buttons.iter().enumerate().map(|(index, b)| {
+            let mut callback = json!({
+                "button_index": index,
+                "timestamp_mark": buttons_mark
+            });
+            if let Some(card_index) = card_index {
+                callback["card_index"] = json!(card_index);
+            }
+
+            json!({
+                "title": b.name(),
+                "callback": callback
+            })
+        }).collect()
+    }
+}
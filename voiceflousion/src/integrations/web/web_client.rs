@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use crate::core::base_structs::ClientBase;
+use crate::core::ClientBuilder;
+use crate::core::session_wrappers::LockedSession;
+use crate::core::traits::{Client, Sender};
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::integrations::web::{WebEventStream, WebSender, WebUpdate};
+
+/// Represents a client for web chat integration with Voiceflow.
+///
+/// `WebClient` manages sessions and interactions with the Voiceflow API for custom frontends,
+/// such as website widgets and mobile applications. The messages are not sent to any external API,
+/// they are returned as the HTTP response body or pushed to the event stream of the chat.
+pub struct WebClient{
+    /// The base structure that provides core functionalities.
+    client_base: ClientBase<WebSender>
+}
+
+impl WebClient{
+    /// Creates a new `WebClient`.
+    ///
+    /// This method initializes a new `WebClient` using the provided `ClientBuilder`.
+    /// The client ID identifies the frontend and the API key isn't used to call any API.
+    ///
+    /// # Parameters
+    ///
+    /// * `builder` - The `ClientBuilder` containing the necessary configurations.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebClient`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::web::WebClient;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("website".to_string(), "".to_string(), voiceflow_client, 10);
+    /// let client = WebClient::new(builder);
+    /// ```
+    pub fn new(builder: ClientBuilder) -> Self {
+        let api_key = builder.api_key().clone();
+        let max_connections_per_moment = builder.max_connections_per_moment();
+        let connection_duration = builder.connection_duration();
+        let sender = WebSender::new(max_connections_per_moment, api_key, connection_duration);
+
+        Self {
+            client_base: ClientBase::new(builder, sender)
+        }
+    }
+
+    /// Subscribes to the messages of the chat.
+    ///
+    /// While the subscription is active, the messages of the chat are pushed to the returned stream
+    /// instead of being returned as the HTTP response body.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID to subscribe to.
+    ///
+    /// # Returns
+    ///
+    /// A `WebEventStream` with the messages of the chat.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::web::WebClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///     let builder = ClientBuilder::new("website".to_string(), "".to_string(), voiceflow_client, 10);
+    ///     let client = WebClient::new(builder);
+    ///     let stream = client.subscribe(&"chat_id".to_string()).await;
+    /// }
+    /// ```
+    pub async fn subscribe(&self, chat_id: &str) -> WebEventStream {
+        self.client_base.sender().subscribe(chat_id).await
+    }
+
+    /// Takes the rendered messages of the chat, which weren't pushed to the event stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the messages.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the rendered messages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::web::WebClient;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///     let builder = ClientBuilder::new("website".to_string(), "".to_string(), voiceflow_client, 10);
+    ///     let client = WebClient::new(builder);
+    ///     let messages = client.take_messages(&"chat_id".to_string()).await;
+    /// }
+    /// ```
+    pub async fn take_messages(&self, chat_id: &String) -> Vec<Value> {
+        self.client_base.sender().take_messages(chat_id).await
+    }
+}
+
+#[async_trait]
+impl Client for WebClient{
+    type ClientUpdate<'async_trait> = WebUpdate;
+    type ClientSender<'async_trait> = WebSender;
+
+    /// Returns a reference to the `ClientBase`.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the `ClientBase` instance.
+    fn client_base(&self) -> &ClientBase<Self::ClientSender<'_>> {
+        &self.client_base
+    }
+
+    /// Handles carousel switch interactions in a web chat session.
+    ///
+    /// The web chat renders all the cards of the carousel at once,
+    /// so there is nothing to switch and this method always returns an error.
+    ///
+    /// # Parameters
+    ///
+    /// * `_locked_session` - The locked session for the interaction.
+    /// * `_interaction_time` - The time of the interaction in seconds since the Unix epoch.
+    /// * `_switch_direction` - The direction to switch the carousel (`true` for next, `false` for previous).
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionError` indicating that carousels can't be switched in the web chat.
+    async fn handle_carousel_switch(&self, _locked_session: &LockedSession<'_>, _interaction_time: i64, _switch_direction: bool) -> VoiceflousionResult<Vec<<Self::ClientSender<'_> as Sender>::SenderResponder>> {
+        Err(VoiceflousionError::ValidationError(
+            "WebClient".to_string(),
+            "Carousels are rendered entirely in the web chat and can't be switched".to_string(),
+        ))
+    }
+}
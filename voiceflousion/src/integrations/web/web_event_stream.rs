@@ -0,0 +1,49 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_core::Stream;
+use serde_json::Value;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Represents a stream of the messages rendered for a web chat.
+///
+/// `WebEventStream` yields every message sent to the subscribed chat as soon as it is rendered,
+/// so it can be forwarded to the frontend over Server-Sent Events or a WebSocket.
+/// Dropping the stream cancels the subscription.
+#[derive(Debug)]
+pub struct WebEventStream {
+    /// The receiver of the rendered messages.
+    receiver: UnboundedReceiver<Value>,
+}
+
+impl WebEventStream {
+    /// Creates a new `WebEventStream`.
+    ///
+    /// # Parameters
+    ///
+    /// * `receiver` - The receiver of the rendered messages.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebEventStream`.
+    pub(crate) fn new(receiver: UnboundedReceiver<Value>) -> Self {
+        Self { receiver }
+    }
+
+    /// Waits for the next message of the chat.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the next rendered message, or `None` if the subscription is replaced or the sender is dropped.
+    pub async fn next_message(&mut self) -> Option<Value> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for WebEventStream {
+    type Item = Value;
+
+    /// Polls the next message of the chat.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
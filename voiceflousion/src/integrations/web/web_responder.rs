@@ -0,0 +1,75 @@
+use std::fmt::Debug;
+use std::ops::Deref;
+use async_trait::async_trait;
+use reqwest::Response;
+use crate::core::base_structs::ResponderBase;
+use crate::core::traits::Responder;
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents a responder for web chat interactions.
+///
+/// `WebResponder` contains details of the message rendered for a web chat, including
+/// the chat ID, message ID, date, and the content of the message.
+#[derive(Debug)]
+pub struct WebResponder{
+    /// The base structure that provides core functionalities.
+    responder_base: ResponderBase
+}
+
+impl Deref for WebResponder {
+    type Target = ResponderBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.responder_base
+    }
+}
+
+impl WebResponder{
+    /// Creates a new `WebResponder`.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the message.
+    /// * `message_id` - The ID of the message.
+    /// * `content` - The content of the message as a `VoiceflowBlock`.
+    /// * `date` - The date of the message.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    /// use voiceflousion::core::voiceflow::VoiceflowBlock;
+    /// use voiceflousion::integrations::web::WebResponder;
+    ///
+    /// let block = VoiceflowBlock::Text(VoiceflowText::new("text".to_string()));
+    /// let responder = WebResponder::new("chat_id".to_string(), "message_id".to_string(), block, 1627554661);
+    /// ```
+    pub fn new(chat_id: String, message_id: String, content: VoiceflowBlock, date: i64) -> Self {
+        Self {
+            responder_base: ResponderBase::new(chat_id, message_id, content, date)
+        }
+    }
+}
+
+#[async_trait]
+impl Responder for WebResponder{
+
+    /// Web chat messages are rendered without HTTP requests, so `WebResponder` can't be created from the response.
+    ///
+    /// # Parameters
+    ///
+    /// * `_response` - The HTTP response.
+    /// * `_content` - The content of the message as a `VoiceflowBlock`.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionError` indicating that web chat messages have no HTTP responses.
+    async fn from_response(_response: Response, _content: VoiceflowBlock) -> VoiceflousionResult<Self> {
+        Err(VoiceflousionError::ClientResponseReadingError("WebResponder".to_string(), "Web chat messages aren't sent with HTTP requests".to_string()))
+    }
+}
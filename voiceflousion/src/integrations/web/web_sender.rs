@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+use async_trait::async_trait;
+use chrono::Utc;
+use rand::Rng;
+use serde_json::Value;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::sync::RwLock;
+use crate::core::base_structs::SenderBase;
+use crate::core::traits::Sender;
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use crate::core::voiceflow::VoiceflowBlock;
+use crate::errors::VoiceflousionResult;
+use crate::integrations::web::utils::WebSerializer;
+use crate::integrations::web::{WebEventStream, WebResponder};
+
+/// Represents a sender for web chat integration.
+///
+/// `WebSender` doesn't call any external API. It renders the messages into JSON and pushes them
+/// to the subscribed event stream of the chat, or keeps them in the outbox of the chat
+/// until they are taken as the HTTP response body.
+pub struct WebSender{
+    /// The base structure that provides core functionalities.
+    sender_base: SenderBase,
+    /// The rendered messages waiting to be taken, mapped by the chat IDs.
+    outbox: RwLock<HashMap<String, Vec<Value>>>,
+    /// The senders of the subscribed event streams, mapped by the chat IDs.
+    subscribers: RwLock<HashMap<String, UnboundedSender<Value>>>
+}
+
+impl Deref for WebSender {
+    type Target = SenderBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sender_base
+    }
+}
+
+impl WebSender{
+    /// Creates a new `WebSender`.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_sessions_per_moment` - The maximum number of idle connections per host.
+    /// * `api_key` - The API key of the client.
+    /// * `connection_duration` - The optional duration for which sessions can remain idle (in seconds).
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebSender`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    ///
+    /// let sender = WebSender::new(10, "api_key".to_string(), None);
+    /// ```
+    pub fn new(max_sessions_per_moment: usize, api_key: String, connection_duration: Option<u64>) -> Self {
+        Self {
+            sender_base: SenderBase::new(max_sessions_per_moment, api_key, connection_duration),
+            outbox: RwLock::new(HashMap::new()),
+            subscribers: RwLock::new(HashMap::new())
+        }
+    }
+
+    /// Subscribes to the messages of the chat.
+    ///
+    /// While the subscription is active, the messages of the chat are pushed to the returned stream
+    /// instead of the outbox. A new subscription replaces the previous one.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID to subscribe to.
+    ///
+    /// # Returns
+    ///
+    /// A `WebEventStream` with the messages of the chat.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let stream = sender.subscribe(&"chat_id".to_string()).await;
+    /// }
+    /// ```
+    pub async fn subscribe(&self, chat_id: &str) -> WebEventStream {
+        let (sender, receiver) = unbounded_channel();
+        self.subscribers.write().await.insert(chat_id.to_string(), sender);
+        WebEventStream::new(receiver)
+    }
+
+    /// Takes the rendered messages of the chat from the outbox.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the messages.
+    ///
+    /// # Returns
+    ///
+    /// A vector of the rendered messages, empty if there are no messages waiting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let messages = sender.take_messages(&"chat_id".to_string()).await;
+    ///     assert!(messages.is_empty());
+    /// }
+    /// ```
+    pub async fn take_messages(&self, chat_id: &String) -> Vec<Value> {
+        self.outbox.write().await.remove(chat_id).unwrap_or_default()
+    }
+
+    /// Delivers the rendered message to the chat.
+    ///
+    /// The message is pushed to the event stream of the chat if it is subscribed,
+    /// otherwise it is put into the outbox.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `message` - The rendered message.
+    async fn deliver(&self, chat_id: &String, message: Value) {
+        let message = {
+            let mut subscribers = self.subscribers.write().await;
+            match subscribers.get(chat_id) {
+                Some(subscriber) => match subscriber.send(message) {
+                    Ok(()) => return,
+                    Err(error) => {
+                        // Remove the closed subscription and keep the message in the outbox
+                        subscribers.remove(chat_id);
+                        error.0
+                    }
+                },
+                None => message
+            }
+        };
+        self.outbox.write().await.entry(chat_id.clone()).or_default().push(message);
+    }
+
+    /// Generates a random ID for the message.
+    ///
+    /// # Returns
+    ///
+    /// A `String` with the hex ID of the message.
+    fn generate_message_id() -> String {
+        format!("{:x}", rand::thread_rng().gen::<u64>())
+    }
+
+    /// Delivers the rendered message to the chat and creates the responder of the message.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `message_id` - The ID of the message.
+    /// * `message` - The rendered message.
+    /// * `content` - The `VoiceflowBlock` of the message.
+    ///
+    /// # Returns
+    ///
+    /// A `WebResponder` of the delivered message.
+    async fn deliver_block(&self, chat_id: &String, message_id: String, message: Value, content: VoiceflowBlock) -> WebResponder {
+        self.deliver(chat_id, message).await;
+
+        // The buttons are marked with the timestamp of their creation
+        let date = match &content{
+            VoiceflowBlock::Buttons(buttons) => Some(buttons.mark()),
+            VoiceflowBlock::Card(card) => card.buttons().as_ref().map(|buttons| buttons.mark()),
+            VoiceflowBlock::Carousel(carousel) => Some(carousel.get_selected_mark()),
+            _ => None
+        }.unwrap_or_else(|| Utc::now().timestamp());
+
+        WebResponder::new(chat_id.clone(), message_id, content, date)
+    }
+}
+
+#[async_trait]
+impl Sender for WebSender{
+    type SenderResponder = WebResponder;
+
+    /// Renders a text message for the web chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `_client_id` - The client ID.
+    /// * `text` - The `VoiceflowText` object containing the message.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowText;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let text = VoiceflowText::new("Hello, World!".to_string());
+    ///     let response = sender.send_text(&client_id, text, &chat_id).await;
+    ///     assert_eq!(sender.take_messages(&chat_id).await.len(), 1);
+    /// }
+    /// ```
+    async fn send_text(&self, _client_id: &String, text: VoiceflowText, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let message_id = Self::generate_message_id();
+        let message = WebSerializer::build_text_body(&message_id, text.message());
+        Ok(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Text(text)).await)
+    }
+
+    /// Renders an image message for the web chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `_client_id` - The client ID.
+    /// * `image` - The `VoiceflowImage` object containing the image URL.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowImage;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let image = VoiceflowImage::new("https://example.com/image.jpg".to_string(), Some(100), Some(200));
+    ///     let response = sender.send_image(&client_id, image, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_image(&self, _client_id: &String, image: VoiceflowImage, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let message_id = Self::generate_message_id();
+        let message = WebSerializer::build_image_body(&message_id, image.url(), image.height(), image.width());
+        Ok(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Image(image)).await)
+    }
+
+    /// Renders a buttons message for the web chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `_client_id` - The client ID.
+    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' configuration.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowButton, VoiceflowButtons};
+    /// use serde_json::Value;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let buttons = vec![VoiceflowButton::new("Click me".to_string(), Value::Null, None)];
+    ///     let voiceflow_buttons = VoiceflowButtons::new(buttons);
+    ///     let response = sender.send_buttons(&client_id, voiceflow_buttons, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_buttons(&self, _client_id: &String, buttons: VoiceflowButtons, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let message_id = Self::generate_message_id();
+        let message = WebSerializer::build_buttons_body(&message_id, &buttons);
+        Ok(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Buttons(buttons)).await)
+    }
+
+    /// Renders a card message for the web chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `_client_id` - The client ID.
+    /// * `card` - The `VoiceflowCard` object containing the card details.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowCard;
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let card = VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None);
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let response = sender.send_card(&client_id, card, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_card(&self, _client_id: &String, card: VoiceflowCard, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let message_id = Self::generate_message_id();
+        let message = WebSerializer::build_card_body(&message_id, &card);
+        Ok(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Card(card)).await)
+    }
+
+    /// Renders a carousel message with all the cards for the web chat.
+    ///
+    /// # Parameters
+    ///
+    /// * `_client_id` - The client ID.
+    /// * `carousel` - The `VoiceflowCarousel` object containing the carousel details.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::{VoiceflowCard, VoiceflowCarousel};
+    /// use voiceflousion::core::traits::Sender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let cards = vec![VoiceflowCard::new(Some("https://example.com/image.jpg".to_string()), Some("Title".to_string()), Some("Description".to_string()), None)];
+    ///     let carousel = VoiceflowCarousel::new(cards, true);
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let response = sender.send_carousel(&client_id, carousel, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_carousel(&self, _client_id: &String, carousel: VoiceflowCarousel, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
+        let message_id = Self::generate_message_id();
+        let message = WebSerializer::build_carousel_body(&message_id, &carousel);
+        Ok(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Carousel(carousel)).await)
+    }
+
+    /// Renders an audio message for the web chat.
+    ///
+    /// If the audio source is not a URL, the message of the audio or nothing is rendered instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID.
+    /// * `audio` - The `VoiceflowAudio` object containing the audio source.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `WebResponder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::web::WebSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WebSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = "chat_id".to_string();
+    ///     let client_id = String::new();
+    ///     let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    ///     let response = sender.send_audio(&client_id, audio, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_audio(&self, client_id: &String, audio: VoiceflowAudio, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        let message_id = Self::generate_message_id();
+        let message = match audio.url() {
+            Some(url) => WebSerializer::build_audio_body(&message_id, url),
+            None => {
+                return match audio.fallback_text() {
+                    Some(text) => self.send_text(client_id, text, chat_id).await.map(Some),
+                    None => Ok(None)
+                }
+            }
+        };
+
+        Ok(Some(self.deliver_block(chat_id, message_id, message, VoiceflowBlock::Audio(audio)).await))
+    }
+}
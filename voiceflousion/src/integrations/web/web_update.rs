@@ -0,0 +1,124 @@
+use std::ops::Deref;
+use chrono::Utc;
+use serde_json::Value;
+use crate::core::base_structs::UpdateBase;
+use crate::core::subtypes::InteractionType;
+use crate::core::traits::Update;
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+
+/// Represents an update received from a web chat frontend.
+///
+/// `WebUpdate` holds the details of a message or a button press sent by the frontend
+/// as a simple JSON body, such as chat ID, interaction time, interaction type, and update ID.
+#[derive(Debug)]
+pub struct WebUpdate {
+    /// The base structure that provides core functionalities.
+    update_base: UpdateBase,
+}
+
+impl Deref for WebUpdate {
+    type Target = UpdateBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.update_base
+    }
+}
+
+impl WebUpdate{
+
+    /// Creates a new `WebUpdate` instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The ID of the chat where the interaction occurred.
+    /// * `interaction_time` - The timestamp of the interaction.
+    /// * `interaction_type` - The type of interaction (e.g., text message, button click).
+    /// * `update_id` - The unique ID of the update.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WebUpdate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::InteractionType;
+    /// use voiceflousion::integrations::web::WebUpdate;
+    ///
+    /// let interaction_type = InteractionType::new("message".to_string(), None, None);
+    /// let update = WebUpdate::new("chat_id".to_string(), 1627554661, interaction_type, "update_id".to_string());
+    /// ```
+    pub fn new(chat_id: String, interaction_time: i64, interaction_type: InteractionType, update_id: String) -> Self {
+        Self {
+            update_base: UpdateBase::new(chat_id, interaction_time, interaction_type, update_id)
+        }
+    }
+}
+
+impl Update for WebUpdate{
+    /// Constructs a `WebUpdate` from the request body.
+    ///
+    /// The body must contain the `chat_id` and either the `text` of the message or the `button_index`
+    /// of the pressed button. Buttons of the rendered messages provide the `callback` object with the
+    /// `button_index`, `timestamp_mark` and the optional `card_index`, which can be merged into the body.
+    /// The optional `update_id` is generated if missing.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The JSON body of the request.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the `WebUpdate` instance or an error if the parsing fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::core::traits::Update;
+    /// use voiceflousion::integrations::web::WebUpdate;
+    ///
+    /// let text_update = WebUpdate::from_request_body(json!({"chat_id": "user", "text": "Hello"})).unwrap();
+    /// let button_update = WebUpdate::from_request_body(json!({"chat_id": "user", "button_index": 0, "timestamp_mark": 1627554661})).unwrap();
+    /// ```
+    fn from_request_body(body: Value) -> VoiceflousionResult<Self> {
+        // Extract chat ID
+        let chat_id = body.get("chat_id")
+            .and_then(|chat_id| chat_id.as_str())
+            .map(|chat_id| chat_id.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WebUpdate chat id".to_string(), body.clone()))?;
+
+        let now = Utc::now();
+
+        // Extract update ID or generate it from the time of the request
+        let update_id = body.get("update_id")
+            .and_then(|update_id| update_id.as_str())
+            .map(|update_id| update_id.to_string())
+            .unwrap_or_else(|| format!("{}_{}", chat_id, now.timestamp_millis()));
+
+        // Handle a button press
+        if let Some(button_index) = body.get("button_index").and_then(|button_index| button_index.as_u64()) {
+            let button_index = button_index as usize;
+
+            // The buttons are marked with the timestamp of their creation
+            let interaction_time = body.get("timestamp_mark")
+                .and_then(|timestamp_mark| timestamp_mark.as_i64())
+                .unwrap_or_else(|| now.timestamp());
+
+            let interaction_type = match body.get("card_index").and_then(|card_index| card_index.as_u64()) {
+                Some(card_index) => InteractionType::CarouselButton(card_index as usize, button_index),
+                None => InteractionType::Button(button_index)
+            };
+
+            return Ok(Self::new(chat_id, interaction_time, interaction_type, update_id))
+        }
+
+        // Extract text
+        let text = body.get("text")
+            .and_then(|text| text.as_str())
+            .map(|text| text.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WebUpdate text or button index".to_string(), body.clone()))?;
+
+        Ok(Self::new(chat_id, now.timestamp(), InteractionType::Text(text), update_id))
+    }
+}
@@ -2,6 +2,7 @@ mod whatsapp_responder;
 mod whatsapp_sender;
 mod whatsapp_update;
 mod whatsapp_client;
+mod whatsapp_template;
 mod utils;
 
 #[cfg(feature = "advanced")]
@@ -16,4 +17,5 @@ pub(super) use self::{
 
 pub use whatsapp_responder::WhatsAppResponder;
 pub use whatsapp_client::WhatsAppClient;
-pub use whatsapp_update::WhatsAppUpdate;
\ No newline at end of file
+pub use whatsapp_update::WhatsAppUpdate;
+pub use whatsapp_template::WhatsAppTemplate;
\ No newline at end of file
@@ -1,7 +1,8 @@
 use serde_json::{json, Value};
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowText};
 use crate::core::voiceflow::dialog_blocks::enums::VoiceflowButtonsOption;
-use crate::integrations::utils::ButtonCallbackDataBuilder;
+use crate::integrations::utils::{ButtonCallbackDataBuilder, TextSplitter};
+use crate::integrations::whatsapp::WhatsAppTemplate;
 
 /// Serializer for constructing WhatsApp message bodies.
 ///
@@ -14,6 +15,18 @@ impl WhatsAppSerializer {
     /// Maximum allowed length for button titles.
     pub const ALLOWED_BUTTON_TITLE: usize = 24;
 
+    /// Maximum allowed length of the text message body.
+    pub const MAX_TEXT_LENGTH: usize = 4096;
+
+    /// Maximum allowed length of the interactive message body.
+    pub const MAX_INTERACTIVE_BODY_LENGTH: usize = 1024;
+
+    /// Maximum allowed number of the rows in a list message.
+    pub const MAX_LIST_ROWS: usize = 10;
+
+    /// The body of the additional list messages with the buttons exceeding the rows limit.
+    const MORE_BUTTONS_TEXT: &'static str = "👇";
+
     /// Builds a JSON body for a text message to be sent via WhatsApp.
     ///
     /// # Parameters
@@ -35,6 +48,25 @@ impl WhatsAppSerializer {
         })
     }
 
+    /// Builds the JSON bodies for a text message to be sent via WhatsApp,
+    /// splitting the text longer than the message limit into several messages.
+    ///
+    /// The formatted text is sent with the WhatsApp markup.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `text` - The `VoiceflowText` to send.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` containing the structured JSON payloads in the sending order.
+    pub fn build_text_parts(chat_id: &str, text: &VoiceflowText) -> Vec<Value> {
+        Self::split_text(text, Self::MAX_TEXT_LENGTH, Self::MAX_TEXT_LENGTH).iter()
+            .map(|part| Self::build_text_body(chat_id, part))
+            .collect()
+    }
+
     /// Builds a JSON body for an image message to be sent via WhatsApp.
     ///
     /// # Parameters
@@ -56,25 +88,129 @@ impl WhatsAppSerializer {
         })
     }
 
-    /// Builds a JSON body for an interactive buttons message to be sent via WhatsApp.
+    /// Builds a JSON body for a template message to be sent via WhatsApp.
     ///
     /// # Parameters
     ///
     /// * `chat_id` - The recipient's chat ID.
-    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' data.
+    /// * `template` - The template with its components.
+    ///
+    /// # Returns
+    ///
+    /// A `Value` containing the structured JSON payload.
+    pub fn build_template_body(chat_id: &str, template: &WhatsAppTemplate) -> Value {
+        let mut template_value = json!({
+            "name": template.name(),
+            "language": {
+                "code": template.language_code(),
+            }
+        });
+        if !template.components().is_empty() {
+            template_value["components"] = json!(template.components());
+        }
+
+        json!({
+            "messaging_product": "whatsapp",
+            "to": chat_id,
+            "type": "template",
+            "template": template_value
+        })
+    }
+
+    /// Builds a JSON body for an audio message to be sent via WhatsApp.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `audio_url` - The URL of the audio to be sent.
     ///
     /// # Returns
     ///
     /// A `Value` containing the structured JSON payload.
-    pub fn build_buttons_body(chat_id: &str, buttons: &VoiceflowButtons) -> Value {
+    pub fn build_audio_body(chat_id: &str, audio_url: &str) -> Value {
+        json!({
+            "messaging_product": "whatsapp",
+            "to": chat_id,
+            "type": "audio",
+            "audio": {
+                "link": audio_url,
+            }
+        })
+    }
+
+    /// Builds the JSON bodies for an interactive buttons message to be sent via WhatsApp.
+    ///
+    /// The text longer than the interactive body limit is split and its leading parts are sent as text messages.
+    /// The buttons exceeding the rows limit of a list are sent in the additional list messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The recipient's chat ID.
+    /// * `buttons` - The `VoiceflowButtons` object containing the buttons' data.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `Value` containing the structured JSON payloads in the sending order.
+    pub fn build_buttons_parts(chat_id: &str, buttons: &VoiceflowButtons) -> Vec<Value> {
         let text = match buttons.option() {
-            VoiceflowButtonsOption::Text(text) => text.message().clone(),
-            VoiceflowButtonsOption::Empty => String::from("Invalid behavior. Please fix errors in WhatsAppSender usage")
+            VoiceflowButtonsOption::Text(text) => text.clone(),
+            VoiceflowButtonsOption::Empty => VoiceflowText::new(String::from("Invalid behavior. Please fix errors in WhatsAppSender usage"))
         };
 
+        // Split the text into the text messages and the body of the interactive message
+        let mut texts = Self::split_text(&text, Self::MAX_TEXT_LENGTH, Self::MAX_INTERACTIVE_BODY_LENGTH);
+        let body_text = texts.pop().unwrap_or_default();
+
+        let mut parts: Vec<Value> = texts.iter()
+            .map(|text| Self::build_text_body(chat_id, text))
+            .collect();
+
         let interactive_rows = Self::build_buttons_vec(buttons, buttons.mark());
+        let mut rows_chunks = interactive_rows.chunks(Self::MAX_LIST_ROWS);
 
-        Self::build_buttons_base_body(chat_id, text, interactive_rows)
+        parts.push(Self::build_buttons_base_body(chat_id, body_text, rows_chunks.next().unwrap_or_default().to_vec()));
+        parts.extend(rows_chunks.map(|rows| Self::build_buttons_base_body(chat_id, Self::MORE_BUTTONS_TEXT.to_string(), rows.to_vec())));
+
+        parts
+    }
+
+    /// Splits the text into the parts fitting the length limits, applying the WhatsApp markup to the formatted text.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - The `VoiceflowText` to split.
+    /// * `max_length` - The maximum length of the leading parts.
+    /// * `last_max_length` - The maximum length of the last part.
+    ///
+    /// # Returns
+    ///
+    /// A vector of at least one part of the text.
+    fn split_text(text: &VoiceflowText, max_length: usize, last_max_length: usize) -> Vec<String> {
+        let rich_text = match text.rich_text() {
+            Some(rich_text) => rich_text,
+            None => {
+                let mut parts = TextSplitter::split(text.message(), max_length);
+                let last_part = parts.pop().unwrap_or_default();
+                parts.extend(TextSplitter::split(&last_part, last_max_length));
+                return parts;
+            }
+        };
+
+        let mut parts = TextSplitter::split_rich_text(rich_text, max_length);
+        let last_part = parts.pop().unwrap_or_default();
+        parts.extend(TextSplitter::split_rich_text(&last_part, last_max_length));
+
+        let parts_count = parts.len();
+        parts.iter().enumerate().map(|(index, part)| {
+            let limit = if index == parts_count - 1 { last_max_length } else { max_length };
+            // The markup counts towards the limit, so the part overflowing with it is sent unformatted
+            let markup = part.to_whatsapp_markup();
+            if TextSplitter::length(&markup) <= limit {
+                markup
+            } else {
+                part.plain_text()
+            }
+        }).collect()
     }
 
     /// Builds a JSON body for a carousel card with buttons to be sent via WhatsApp.
@@ -93,6 +229,7 @@ impl WhatsAppSerializer {
     /// A `Value` containing the structured JSON payload.
     fn build_carousel_buttons_body(chat_id: &str, card: &VoiceflowCard, text: String, mark: i64, index: usize, carousel_len: usize) -> Value {
         let interactive_rows = Self::build_carousel_card_buttons_vec(card, mark, index, carousel_len);
+        let text = TextSplitter::truncate(&text, Self::MAX_INTERACTIVE_BODY_LENGTH);
         Self::build_buttons_base_body(chat_id, text, interactive_rows)
     }
 
@@ -152,9 +289,9 @@ impl WhatsAppSerializer {
         }
 
         if let Some(buttons) = card.buttons() {
-            card_parts.push(Self::build_buttons_body(chat_id, buttons));
+            card_parts.extend(Self::build_buttons_parts(chat_id, buttons));
         } else {
-            card_parts.push(Self::build_text_body(chat_id, &text));
+            card_parts.extend(Self::build_text_parts(chat_id, &VoiceflowText::new(text)));
         }
 
         card_parts
@@ -201,12 +338,7 @@ impl WhatsAppSerializer {
     fn build_buttons_vec(buttons: &VoiceflowButtons, buttons_mark: i64) -> Vec<Value> {
         buttons.iter().enumerate().map(|(index, b)| {
 
-            let button_name = if b.name().len() > Self::ALLOWED_BUTTON_TITLE {
-               b.name()[..Self::ALLOWED_BUTTON_TITLE].to_string()
-            }
-            else{
-                b.name().clone()
-            };
+            let button_name = TextSplitter::truncate(b.name(), Self::ALLOWED_BUTTON_TITLE);
 
             let callback_data_string =ButtonCallbackDataBuilder::new().index(index).timestamp_mark(buttons_mark).build().to_json_string();
 
@@ -230,11 +362,16 @@ impl WhatsAppSerializer {
     /// # Returns
     ///
     /// A vector of `Value` representing the list rows, including navigation buttons.
+    /// The card buttons exceeding the rows limit of a list are dropped.
     fn build_carousel_card_buttons_vec(card: &VoiceflowCard, mark: i64, index: usize, carousel_len: usize) -> Vec<Value> {
         let mut list_rows: Vec<Value> = card.buttons().as_ref()
             .map(|b| Self::build_buttons_vec(b, mark))
             .unwrap_or_else(Vec::new);
 
+        // Leave the rows for the navigation buttons
+        let navigation_rows = usize::from(index < carousel_len - 1) + usize::from(index > 0);
+        list_rows.truncate(Self::MAX_LIST_ROWS - navigation_rows);
+
         // Add a next button if this is not the last card
         if index < carousel_len - 1 {
             let carousel_next= ButtonCallbackDataBuilder::new().direction(true).timestamp_mark(mark).build().to_json_string();
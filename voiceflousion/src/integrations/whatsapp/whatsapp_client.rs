@@ -1,13 +1,15 @@
 use std::ops::Deref;
 use async_trait::async_trait;
+use chrono::Utc;
 use crate::core::base_structs::ClientBase;
 use crate::core::ClientBuilder;
 use crate::core::session_wrappers::LockedSession;
-use crate::core::traits::{get_last_sent_message, Client, Sender};
+use crate::core::subtypes::InputMedia;
+use crate::core::traits::{get_last_sent_message, lock_outbound_session, Client, Responder, Sender};
 use crate::core::voiceflow::dialog_blocks::VoiceflowCarousel;
 use crate::core::voiceflow::VoiceflowBlock;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
-use crate::integrations::whatsapp::{WhatsAppResponder, WhatsAppUpdate, WhatsAppSender};
+use crate::integrations::whatsapp::{WhatsAppResponder, WhatsAppTemplate, WhatsAppUpdate, WhatsAppSender};
 
 /// Represents a client for WhatsApp integration with Voiceflow.
 ///
@@ -48,12 +50,80 @@ impl WhatsAppClient {
         let max_connections_per_moment = builder.max_connections_per_moment();
         let connection_duration = builder.connection_duration();
         let sender = WhatsAppSender::new(max_connections_per_moment, api_key, connection_duration);
+        let sender = if let Some(delivery_policy) = builder.delivery_policy(){
+            sender.set_delivery_policy(delivery_policy.clone())
+        }
+        else {
+            sender
+        };
 
         Self{
             client_base: ClientBase::new(builder, sender)
         }
     }
 
+    /// Pushes a template message to the chat outside of the updates of the user.
+    ///
+    /// WhatsApp delivers only template messages to the users who haven't written to the business in the last 24 hours.
+    /// The template is sent through the locked session of the chat and stored as its previous message.
+    /// The session is created if the chat has no session yet.
+    ///
+    /// # Parameters
+    ///
+    /// * `chat_id` - The chat ID of the user.
+    /// * `template` - The template with its components.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WhatsAppResponder` or a `VoiceflousionError` if the session is busy or the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::whatsapp::{WhatsAppClient, WhatsAppTemplate};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, None));
+    ///     let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    ///     let client = WhatsAppClient::new(builder);
+    ///
+    ///     let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string())
+    ///         .add_body_parameters(vec!["#42".to_string()]);
+    ///     let response = client.push_template(&"chat_id".to_string(), &template).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    pub async fn push_template(&self, chat_id: &String, template: &WhatsAppTemplate) -> VoiceflousionResult<WhatsAppResponder> {
+        if !self.client_base.is_active(){
+            return Err(VoiceflousionError::ClientRequestError(format!("Client {} is deactivated!", self.client_base.client_id()), "".to_string()))
+        }
+
+        let sessions = self.client_base.sessions();
+        let session = match sessions.get_session(chat_id).await {
+            Some(session) => session,
+            None => sessions.add_session(chat_id.clone()).await
+        };
+        let locked_session = lock_outbound_session(&self.client_base, &session).await?;
+
+        // Keep the session valid for answering the template
+        locked_session.set_last_interaction(Some(Utc::now().timestamp()));
+        let result = self.client_base.sender().send_template(self.client_base.client_id(), template, chat_id).await;
+
+        // Update the session with the previous message
+        if let Ok(response) = &result {
+            locked_session.set_previous_message(Some(response.create_sent_message())).await;
+        }
+
+        // Save the updated session state into the session store
+        sessions.persist_session(&locked_session).await;
+        result
+    }
+
     /// Switches the carousel card at Client's message.
     ///
     /// # Parameters
@@ -121,4 +191,17 @@ impl Client for WhatsAppClient {
         locked_session.set_previous_message(bot_last_message).await;
         Ok(response)
     }
-}
\ No newline at end of file
+
+    /// Resolves the download URL of a media with the WhatsApp Graph API media endpoint.
+    ///
+    /// # Parameters
+    ///
+    /// * `media` - The media sent by the user.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the download URL or a `VoiceflousionError` if the request fails.
+    async fn resolve_media_url(&self, media: &InputMedia) -> VoiceflousionResult<Option<String>> {
+        self.client_base.sender().get_media_url(media.file_id()).await.map(Some)
+    }
+}
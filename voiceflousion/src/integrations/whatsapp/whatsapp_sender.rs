@@ -6,11 +6,13 @@ use reqwest::Response;
 use serde_json::Value;
 use tokio::time::sleep;
 use crate::core::base_structs::SenderBase;
+use crate::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
 use crate::core::traits::{Responder, Sender};
-use crate::core::voiceflow::dialog_blocks::{VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
+use crate::core::voiceflow::dialog_blocks::{VoiceflowAudio, VoiceflowButtons, VoiceflowCard, VoiceflowCarousel, VoiceflowImage, VoiceflowText};
 use crate::core::voiceflow::VoiceflowBlock;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 use crate::integrations::whatsapp::whatsapp_responder::WhatsAppResponder;
+use crate::integrations::whatsapp::WhatsAppTemplate;
 use crate::integrations::whatsapp::utils::WhatsAppSerializer;
 
 /// Represents a sender for WhatsApp integration.
@@ -34,7 +36,10 @@ impl WhatsAppSender {
 
     /// The base URL for WhatsApp API.
     const WHATSAPP_API_URL: &'static str = "https://graph.facebook.com/v20.0/";
-    const DELAY_AFTER_CARD_IMAGE: u64 = 1000;
+    /// The delay between the parts of a message in milliseconds, keeping their order.
+    const DELAY_BETWEEN_PARTS: u64 = 1000;
+    /// The codes of the WhatsApp API errors reporting the throttling of the messages.
+    const THROTTLING_ERROR_CODES: [i64; 5] = [4, 80007, 130429, 131048, 131056];
     /// Creates a new instance of `WhatsAppSender`.
     ///
     /// # Parameters
@@ -49,50 +54,165 @@ impl WhatsAppSender {
     pub fn new(max_sessions_per_moment: usize, api_key: String, connection_duration: Option<u64>) -> Self {
         Self {
             sender_base: SenderBase::new(max_sessions_per_moment, api_key, connection_duration)
+                .set_delivery_policy(Self::default_delivery_policy())
         }
     }
 
-    /// Sends a message to the WhatsApp API.
+    /// Returns the default policy of delivering the messages to WhatsApp.
+    ///
+    /// The policy follows the default WhatsApp Cloud API throughput of 80 messages per second for the business phone number.
+    /// The pair rate limit of a chat allows bursts, so it is handled by retrying the throttled messages.
+    ///
+    /// # Returns
+    ///
+    /// The default `DeliveryPolicy` of WhatsApp.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::RateLimit;
+    /// use voiceflousion::integrations::whatsapp::WhatsAppSender;
+    ///
+    /// let policy = WhatsAppSender::default_delivery_policy();
+    /// assert_eq!(policy.global_rate_limit(), &Some(RateLimit::new(80, 1000)));
+    /// ```
+    pub fn default_delivery_policy() -> DeliveryPolicy {
+        DeliveryPolicy::new(Some(RateLimit::new(80, 1000)), None, RetryPolicy::default())
+    }
+
+    /// Sets the policy of delivering the messages to WhatsApp.
+    ///
+    /// # Parameters
+    ///
+    /// * `delivery_policy` - The rate limits and the retry policy of the requests.
+    ///
+    /// # Returns
+    ///
+    /// The updated `WhatsAppSender` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::core::subtypes::{DeliveryPolicy, RateLimit, RetryPolicy};
+    /// use voiceflousion::integrations::whatsapp::WhatsAppSender;
+    ///
+    /// let policy = DeliveryPolicy::new(Some(RateLimit::new(80, 1000)), Some(RateLimit::new(10, 6000)), RetryPolicy::default());
+    /// let sender = WhatsAppSender::new(10, "api_key".to_string(), None).set_delivery_policy(policy);
+    /// ```
+    pub fn set_delivery_policy(mut self, delivery_policy: DeliveryPolicy) -> Self {
+        self.sender_base = self.sender_base.set_delivery_policy(delivery_policy);
+        self
+    }
+
+    /// Sends a message to the WhatsApp API within the rate limits, retrying it while it is throttled.
     ///
     /// # Parameters
     ///
     /// * `api_url` - The API endpoint URL.
+    /// * `chat_id` - The chat ID of the recipient.
     /// * `body` - The JSON body of the message.
     ///
     /// # Returns
     ///
     /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails.
-    async fn send_message(&self, api_url: &str, body: Value) -> VoiceflousionResult<Response> {
-        self.http_client()
+    async fn send_message(&self, api_url: &str, chat_id: &str, body: Value) -> VoiceflousionResult<Response> {
+        let request = self.http_client()
             .post(api_url)
             .json(&body)
+            .header("Authorization", format!("Bearer {}", self.api_key()));
+
+        self.http_client()
+            .send_with_retry(request, Some(chat_id), Self::is_throttled)
+            .await
+            .map_err(|e| VoiceflousionError::ClientRequestError("WhatsAppSender send_message".to_string(), e.to_string()))
+    }
+
+    /// Resolves the download URL of a media with the WhatsApp Graph API media endpoint.
+    ///
+    /// Note that downloading the media from the URL requires the access token of the WhatsApp API.
+    ///
+    /// # Parameters
+    ///
+    /// * `media_id` - The ID of the media.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the download URL or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppSender;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WhatsAppSender::new(10, "api_key".to_string(), None);
+    ///     let url = sender.get_media_url("media_id").await;
+    ///     println!("{:?}", url);
+    /// }
+    /// ```
+    pub async fn get_media_url(&self, media_id: &str) -> VoiceflousionResult<String> {
+        let api_url = format!("{}{}", Self::WHATSAPP_API_URL, media_id);
+
+        let whatsapp_response = self.http_client()
+            .get(&api_url)
             .header("Authorization", format!("Bearer {}", self.api_key()))
             .send()
             .await
-            .map_err(|e| VoiceflousionError::ClientRequestError("WhatsAppSender send_message".to_string(), e.to_string()))
+            .map_err(|e| VoiceflousionError::ClientRequestError("WhatsAppSender get_media_url".to_string(), e.to_string()))?;
+
+        if !whatsapp_response.status().is_success() {
+            let error_text = whatsapp_response.text().await.unwrap_or_default();
+            return Err(VoiceflousionError::ClientRequestError("WhatsAppSender get_media_url".to_string(), error_text));
+        }
+
+        let json: Value = whatsapp_response.json().await
+            .map_err(|e| VoiceflousionError::ClientResponseReadingError("WhatsAppSender get_media_url".to_string(), e.to_string()))?;
+
+        json.get("url")
+            .and_then(|url| url.as_str())
+            .map(|url| url.to_string())
+            .ok_or_else(|| VoiceflousionError::ClientResponseReadingError("WhatsAppSender get_media_url url".to_string(), json.to_string()))
+    }
+
+    /// Checks whether the WhatsApp API error reports the throttling of the messages.
+    ///
+    /// # Parameters
+    ///
+    /// * `body` - The JSON body of the failed response.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the error code is one of the throttling error codes.
+    fn is_throttled(body: &Value) -> bool {
+        body["error"]["code"].as_i64()
+            .map(|code| Self::THROTTLING_ERROR_CODES.contains(&code))
+            .unwrap_or(false)
     }
 
-    /// Sends parts of a card to the WhatsApp API.
+    /// Sends the parts of a message to the WhatsApp API, i.e. the parts of a card or the parts split to fit the WhatsApp limits.
     ///
     /// # Parameters
     ///
     /// * `api_url` - The API endpoint URL.
-    /// * `card_parts` - A vector of JSON bodies representing the parts of the card.
+    /// * `chat_id` - The chat ID of the recipient.
+    /// * `parts` - A vector of JSON bodies representing the parts of the message.
     ///
     /// # Returns
     ///
-    /// A `VoiceflousionResult` containing a `Response` or a `VoiceflousionError` if the request fails.
-    async fn send_card_parts(&self, api_url: &str, card_parts: Vec<Value>) -> VoiceflousionResult<Response> {
+    /// A `VoiceflousionResult` containing the `Response` to the last part or a `VoiceflousionError` if the request fails.
+    async fn send_parts(&self, api_url: &str, chat_id: &str, parts: Vec<Value>) -> VoiceflousionResult<Response> {
         let mut last_response = None;
-        for (index, body) in card_parts.iter().enumerate() {
-            let response = self.send_message(api_url, body.clone()).await?;
+        for (index, body) in parts.iter().enumerate() {
+            let response = self.send_message(api_url, chat_id, body.clone()).await?;
             if !response.status().is_success() {
                 let error_text = response.text().await.unwrap_or_default();
-                return Err(VoiceflousionError::ClientRequestError("WhatsAppSender send_card_parts".to_string(), error_text));
+                return Err(VoiceflousionError::ClientRequestError("WhatsAppSender send_parts".to_string(), error_text));
             }
             // Add a delay if it's not the last part
-            if index < card_parts.len() - 1 {
-                sleep(Duration::from_millis(Self::DELAY_AFTER_CARD_IMAGE)).await;
+            if index < parts.len() - 1 {
+                sleep(Duration::from_millis(Self::DELAY_BETWEEN_PARTS)).await;
             }
             last_response = Some(response);
         }
@@ -138,11 +258,55 @@ impl WhatsAppSender {
         let (card, index) = carousel.get_next_card(direction)?;
 
         let carousel_card_parts = WhatsAppSerializer::build_carousel_card_parts(card, chat_id, timestamp, index, carousel.len());
-        let whatsapp_response = self.send_card_parts(&api_url, carousel_card_parts).await?;
+        let whatsapp_response = self.send_parts(&api_url, chat_id, carousel_card_parts).await?;
         carousel.set_selected_card(index, timestamp);
         WhatsAppResponder::from_response(whatsapp_response, VoiceflowBlock::Carousel(carousel.clone())).await
     }
 
+    /// Sends a template message via WhatsApp.
+    ///
+    /// Templates are delivered outside of the 24-hour conversation window of the user.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID for the WhatsApp API.
+    /// * `template` - The template with its components.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing a `WhatsAppResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::{WhatsAppSender, WhatsAppTemplate};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WhatsAppSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string());
+    ///     let response = sender.send_template(&client_id, &template, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    pub async fn send_template(&self, client_id: &String, template: &WhatsAppTemplate, chat_id: &String) -> VoiceflousionResult<WhatsAppResponder> {
+        let api_url = Self::prepare_api_url(client_id);
+        let body = WhatsAppSerializer::build_template_body(chat_id, template);
+        let whatsapp_response = self.send_message(&api_url, chat_id, body).await?;
+
+        if whatsapp_response.status().is_success() {
+            // The template is stored as a text block with its name
+            WhatsAppResponder::from_response(whatsapp_response, VoiceflowBlock::Text(VoiceflowText::new(template.name().clone()))).await
+        } else {
+            let error_text = whatsapp_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError("WhatsAppSender send_template".to_string(), error_text))
+        }
+    }
+
     /// Prepares the API URL for sending messages.
     ///
     /// # Parameters
@@ -197,8 +361,8 @@ impl Sender for WhatsAppSender {
     /// ```
     async fn send_text(&self, client_id: &String, text: VoiceflowText, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
         let api_url = Self::prepare_api_url(client_id);
-        let body = WhatsAppSerializer::build_text_body(chat_id, text.message());
-        let whatsapp_response = self.send_message(&api_url, body).await?;
+        let parts = WhatsAppSerializer::build_text_parts(chat_id, &text);
+        let whatsapp_response = self.send_parts(&api_url, chat_id, parts).await?;
 
         if whatsapp_response.status().is_success() {
             Self::SenderResponder::from_response(whatsapp_response, VoiceflowBlock::Text(text)).await
@@ -241,7 +405,7 @@ impl Sender for WhatsAppSender {
     async fn send_image(&self, client_id: &String, image: VoiceflowImage, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
         let api_url = Self::prepare_api_url(client_id);
         let body = WhatsAppSerializer::build_image_body(chat_id, image.url());
-        let whatsapp_response = self.send_message(&api_url, body).await?;
+        let whatsapp_response = self.send_message(&api_url, chat_id, body).await?;
 
         if whatsapp_response.status().is_success() {
             Self::SenderResponder::from_response(whatsapp_response, VoiceflowBlock::Image(image)).await
@@ -285,9 +449,9 @@ impl Sender for WhatsAppSender {
     /// ```
     async fn send_buttons(&self, client_id: &String, buttons: VoiceflowButtons, chat_id: &String) -> VoiceflousionResult<Self::SenderResponder> {
         let api_url = Self::prepare_api_url(client_id);
-        let body = WhatsAppSerializer::build_buttons_body(chat_id, &buttons);
+        let parts = WhatsAppSerializer::build_buttons_parts(chat_id, &buttons);
 
-        let whatsapp_response = self.send_message(&api_url, body).await?;
+        let whatsapp_response = self.send_parts(&api_url, chat_id, parts).await?;
 
         if whatsapp_response.status().is_success() {
             Self::SenderResponder::from_response(whatsapp_response, VoiceflowBlock::Buttons(buttons)).await
@@ -332,7 +496,7 @@ impl Sender for WhatsAppSender {
         let api_url = Self::prepare_api_url(client_id);
 
         let card_parts = WhatsAppSerializer::build_card_parts(&card, chat_id);
-        let whatsapp_response = self.send_card_parts(&api_url, card_parts).await?;
+        let whatsapp_response = self.send_parts(&api_url, chat_id, card_parts).await?;
 
         Self::SenderResponder::from_response(whatsapp_response, VoiceflowBlock::Card(card)).await
     }
@@ -376,7 +540,61 @@ impl Sender for WhatsAppSender {
 
         let carousel_card_parts = WhatsAppSerializer::build_carousel_card_parts(card, chat_id, mark, index, carousel.len());
 
-        let whatsapp_response = self.send_card_parts(&api_url, carousel_card_parts).await?;
+        let whatsapp_response = self.send_parts(&api_url, chat_id, carousel_card_parts).await?;
         WhatsAppResponder::from_response(whatsapp_response, VoiceflowBlock::Carousel(carousel.clone())).await
     }
+
+    /// Sends an audio message via WhatsApp.
+    ///
+    /// If the audio source is not a URL, the message of the audio or nothing is sent instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `client_id` - The client ID for the WhatsApp API.
+    /// * `audio` - The `VoiceflowAudio` object containing the audio source.
+    /// * `chat_id` - The chat ID of the recipient.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing an optional `WhatsAppResponder` or a `VoiceflousionError` if the request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppSender;
+    /// use voiceflousion::core::traits::Sender;
+    /// use voiceflousion::core::voiceflow::dialog_blocks::VoiceflowAudio;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let sender = WhatsAppSender::new(10, "api_key".to_string(), None);
+    ///     let chat_id = String::new();
+    ///     let client_id = String::new();
+    ///     let audio = VoiceflowAudio::new("https://example.com/audio.mp3".to_string(), None);
+    ///     let response = sender.send_audio(&client_id, audio, &chat_id).await;
+    ///     println!("{:?}", response);
+    /// }
+    /// ```
+    async fn send_audio(&self, client_id: &String, audio: VoiceflowAudio, chat_id: &String) -> VoiceflousionResult<Option<Self::SenderResponder>> {
+        let body = match audio.url() {
+            Some(url) => WhatsAppSerializer::build_audio_body(chat_id, url),
+            None => {
+                return match audio.fallback_text() {
+                    Some(text) => self.send_text(client_id, text, chat_id).await.map(Some),
+                    None => Ok(None)
+                }
+            }
+        };
+
+        let api_url = Self::prepare_api_url(client_id);
+        let whatsapp_response = self.send_message(&api_url, chat_id, body).await?;
+
+        if whatsapp_response.status().is_success() {
+            Self::SenderResponder::from_response(whatsapp_response, VoiceflowBlock::Audio(audio)).await.map(Some)
+        } else {
+            let error_text = whatsapp_response.text().await.unwrap_or_default();
+            Err(VoiceflousionError::ClientRequestError("WhatsAppSender send_audio".to_string(), error_text))
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,155 @@
+use serde_json::{json, Value};
+
+/// Represents a WhatsApp message template approved in the WhatsApp Business Manager.
+///
+/// Templates are the only messages WhatsApp delivers to the users who haven't written to the business
+/// in the last 24 hours, so they are used for the notifications pushed outside of the conversation window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhatsAppTemplate {
+    /// The name of the template.
+    name: String,
+    /// The language code of the template, e.g. `en_US`.
+    language_code: String,
+    /// The components of the template with their parameters.
+    components: Vec<Value>,
+}
+
+impl WhatsAppTemplate {
+    /// Creates a new `WhatsAppTemplate` without parameters.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the template.
+    /// * `language_code` - The language code of the template.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `WhatsAppTemplate`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string());
+    /// ```
+    pub fn new(name: String, language_code: String) -> Self {
+        Self {
+            name,
+            language_code,
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds a component with its parameters to the template.
+    ///
+    /// # Parameters
+    ///
+    /// * `component` - The JSON object of the component in the WhatsApp Cloud API format.
+    ///
+    /// # Returns
+    ///
+    /// The updated `WhatsAppTemplate` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string())
+    ///     .add_component(json!({
+    ///         "type": "button",
+    ///         "sub_type": "quick_reply",
+    ///         "index": "0",
+    ///         "parameters": [{"type": "payload", "payload": "track_order"}]
+    ///     }));
+    /// ```
+    pub fn add_component(mut self, component: Value) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Adds the body component with the text parameters to the template.
+    ///
+    /// # Parameters
+    ///
+    /// * `parameters` - The texts of the body parameters in the order of their placeholders.
+    ///
+    /// # Returns
+    ///
+    /// The updated `WhatsAppTemplate` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string())
+    ///     .add_body_parameters(vec!["John".to_string(), "#42".to_string()]);
+    /// assert_eq!(template.components().len(), 1);
+    /// ```
+    pub fn add_body_parameters(self, parameters: Vec<String>) -> Self {
+        let parameters: Vec<Value> = parameters.into_iter()
+            .map(|text| json!({"type": "text", "text": text}))
+            .collect();
+        self.add_component(json!({
+            "type": "body",
+            "parameters": parameters
+        }))
+    }
+
+    /// Returns the name of the template.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the name string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string());
+    /// assert_eq!(template.name(), "order_shipped");
+    /// ```
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    /// Returns the language code of the template.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the language code string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string());
+    /// assert_eq!(template.language_code(), "en_US");
+    /// ```
+    pub fn language_code(&self) -> &String {
+        &self.language_code
+    }
+
+    /// Returns the components of the template.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the vector of the components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::whatsapp::WhatsAppTemplate;
+    ///
+    /// let template = WhatsAppTemplate::new("order_shipped".to_string(), "en_US".to_string());
+    /// assert!(template.components().is_empty());
+    /// ```
+    pub fn components(&self) -> &Vec<Value> {
+        &self.components
+    }
+}
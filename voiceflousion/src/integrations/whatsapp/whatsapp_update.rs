@@ -1,7 +1,7 @@
 use std::ops::Deref;
 use serde_json::Value;
 use crate::core::base_structs::UpdateBase;
-use crate::core::subtypes::InteractionType;
+use crate::core::subtypes::{InputContact, InputLocation, InputMedia, InputMediaType, InputReaction, InteractionType};
 use crate::core::traits::Update;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
 use crate::integrations::utils::ButtonCallbackData;
@@ -98,47 +98,63 @@ impl Update for WhatsAppUpdate{
             .map(|id| id.to_string())
             .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update id".to_string(), message.clone()))?;
 
-        // Determine if the message is an interactive type
+        // Determine the type of the message
         let message_type = message["type"].as_str()
             .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update type".to_string(), message.clone()))?;
-        let is_message = message_type != "interactive";
 
-        // Initialize variables for text, carousel direction, and button index
-        let mut text: String = String::new();
+        // Declare the text and initialize variables for carousel direction and button index
+        let text: String;
         let mut carousel_direction = None;
         let mut button_index = None;
 
-        if is_message {
-            // Extract text from the message body
-            text = message["text"].get("body")
-                .and_then(|body| body.as_str())
-                .map(|text_str| text_str.to_string())
-                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update message text".to_string(), message.clone()))?;
-        } else {
-            // Extract interactive reply data
-            let interactive_reply = message["interactive"].get("list_reply")
-                .or_else(|| message["interactive"].get("button_reply"))
-                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update interactive reply".to_string(), message.clone()))?;
-
-            // Extract text from the interactive reply
-            text = interactive_reply.get("title")
-                .and_then(|text_value| text_value.as_str())
-                .map(|text_str| text_str.to_string())
-                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update button title".to_string(), interactive_reply.clone()))?;
-
-            // Extract callback data from the interactive reply
-            let data = interactive_reply.get("id")
-                .and_then(|data| data.as_str())
-                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate callback data".to_string(), interactive_reply.clone()))?;
-
-            let callback_data: ButtonCallbackData = serde_json::from_str(data)
-                .map_err(|_error| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate callback data must be a valid JSON string".to_string(), interactive_reply.clone()))?;
-
-            // Update interaction time, carousel direction, and button index based on the callback data
-            interaction_time = callback_data.timestamp_mark()
-                .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate button timestamp mark".to_string(), interactive_reply.clone()))?;
-            carousel_direction = callback_data.direction();
-            button_index = callback_data.index();
+        match message_type {
+            "text" => {
+                // Extract text from the message body
+                text = message["text"].get("body")
+                    .and_then(|body| body.as_str())
+                    .map(|text_str| text_str.to_string())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update message text".to_string(), message.clone()))?;
+            },
+            "button" => {
+                // Extract the text of the quick reply button of a template message
+                text = message["button"].get("text")
+                    .and_then(|text_value| text_value.as_str())
+                    .map(|text_str| text_str.to_string())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update template button text".to_string(), message.clone()))?;
+            },
+            "interactive" => {
+                // Extract interactive reply data
+                let interactive_reply = message["interactive"].get("list_reply")
+                    .or_else(|| message["interactive"].get("button_reply"))
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update interactive reply".to_string(), message.clone()))?;
+
+                // Extract text from the interactive reply
+                text = interactive_reply.get("title")
+                    .and_then(|text_value| text_value.as_str())
+                    .map(|text_str| text_str.to_string())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate update button title".to_string(), interactive_reply.clone()))?;
+
+                // Extract callback data from the interactive reply
+                let data = interactive_reply.get("id")
+                    .and_then(|data| data.as_str())
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate callback data".to_string(), interactive_reply.clone()))?;
+
+                let callback_data: ButtonCallbackData = serde_json::from_str(data)
+                    .map_err(|_error| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate callback data must be a valid JSON string".to_string(), interactive_reply.clone()))?;
+
+                // Update interaction time, carousel direction, and button index based on the callback data
+                interaction_time = callback_data.timestamp_mark()
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError("WhatsAppUpdate button timestamp mark".to_string(), interactive_reply.clone()))?;
+                carousel_direction = callback_data.direction();
+                button_index = callback_data.index();
+            },
+            _ => {
+                // Extract the media, file, location, contact or reaction from the message
+                let interaction_type = extract_input(message, message_type)
+                    .ok_or_else(|| VoiceflousionError::ClientUpdateConvertationError(format!("WhatsAppUpdate unsupported message type {}", message_type), message.clone()))?;
+
+                return Ok(Self::new(chat_id, interaction_time, interaction_type, update_id))
+            }
         }
 
         // Create interaction type
@@ -147,4 +163,74 @@ impl Update for WhatsAppUpdate{
         // Return the constructed WhatsAppUpdate instance
         Ok(Self::new(chat_id, interaction_time, interaction_type, update_id))
     }
-}
\ No newline at end of file
+}
+
+/// Extracts a media, file, location, contact or reaction sent by the user from a WhatsApp message.
+///
+/// # Parameters
+///
+/// * `message` - The JSON `Value` of the message.
+/// * `message_type` - The type of the message.
+///
+/// # Returns
+///
+/// An `Option` containing the `InteractionType` of the input, or `None` if the message type is not supported.
+fn extract_input(message: &Value, message_type: &str) -> Option<InteractionType> {
+    let content = message.get(message_type)?;
+    match message_type {
+        "image" => extract_media(content, InputMediaType::Image).map(InteractionType::Media),
+        "video" => extract_media(content, InputMediaType::Video).map(InteractionType::Media),
+        "sticker" => extract_media(content, InputMediaType::Sticker).map(InteractionType::Media),
+        "audio" => {
+            // Voice notes are sent as audio with the voice flag
+            let media_type = if content.get("voice").and_then(|voice| voice.as_bool()).unwrap_or(false) {
+                InputMediaType::Voice
+            } else {
+                InputMediaType::Audio
+            };
+            extract_media(content, media_type).map(InteractionType::Media)
+        },
+        "document" => extract_media(content, InputMediaType::Document).map(InteractionType::File),
+        "location" => {
+            let latitude = content.get("latitude").and_then(|latitude| latitude.as_f64())?;
+            let longitude = content.get("longitude").and_then(|longitude| longitude.as_f64())?;
+            let name = content.get("name").and_then(|name| name.as_str()).map(|name| name.to_string());
+            let address = content.get("address").and_then(|address| address.as_str()).map(|address| address.to_string());
+            Some(InteractionType::Location(InputLocation::new(latitude, longitude, name, address)))
+        },
+        "contacts" => {
+            // Only the first shared contact with its first phone is used
+            let contact = content.as_array()?.first()?;
+            let phone = &contact["phones"][0];
+            let phone_number = phone.get("phone").and_then(|phone_number| phone_number.as_str())?.to_string();
+            let name = contact["name"].get("formatted_name").and_then(|name| name.as_str()).map(|name| name.to_string());
+            let user_id = phone.get("wa_id").and_then(|user_id| user_id.as_str()).map(|user_id| user_id.to_string());
+            Some(InteractionType::Contact(InputContact::new(phone_number, name, user_id)))
+        },
+        "reaction" => {
+            let message_id = content.get("message_id").and_then(|message_id| message_id.as_str())?.to_string();
+            // The emoji is absent if the reaction was removed
+            let emoji = content.get("emoji").and_then(|emoji| emoji.as_str()).unwrap_or_default().to_string();
+            Some(InteractionType::Reaction(InputReaction::new(message_id, emoji)))
+        },
+        _ => None
+    }
+}
+
+/// Extracts a media from a WhatsApp media object.
+///
+/// # Parameters
+///
+/// * `media` - The JSON `Value` of the media object.
+/// * `media_type` - The type of the media.
+///
+/// # Returns
+///
+/// An `Option` containing the `InputMedia`, or `None` if the media object has no media ID.
+fn extract_media(media: &Value, media_type: InputMediaType) -> Option<InputMedia> {
+    let media_id = media.get("id").and_then(|media_id| media_id.as_str())?.to_string();
+    let caption = media.get("caption").and_then(|caption| caption.as_str()).map(|caption| caption.to_string());
+    let mime_type = media.get("mime_type").and_then(|mime_type| mime_type.as_str()).map(|mime_type| mime_type.to_string());
+    let file_name = media.get("filename").and_then(|file_name| file_name.as_str()).map(|file_name| file_name.to_string());
+    Some(InputMedia::new(media_type, media_id, caption, mime_type, file_name))
+}
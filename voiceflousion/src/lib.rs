@@ -1,6 +1,6 @@
 pub mod core;
 pub mod errors;
-#[cfg(any(feature = "telegram", feature = "whatsapp", feature = "discord_unimplemented"))]
+#[cfg(any(feature = "telegram", feature = "whatsapp", feature = "discord", feature = "messenger", feature = "web"))]
 pub mod integrations;
 #[cfg(any(feature = "server"))]
 pub mod server;
\ No newline at end of file
@@ -0,0 +1,455 @@
+use std::ops::Deref;
+use std::sync::Arc;
+use axum::body::Bytes;
+use axum::extract::{Path, Request, State};
+use axum::http::header::AUTHORIZATION;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::{Extension, Json};
+use axum_core::response::{IntoResponse, Response};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use serde_json::{json, Value};
+use tracing::{info, warn};
+use crate::core::base_structs::ClientsManager;
+use crate::core::session_wrappers::Session;
+use crate::server::subtypes::ClientDescription;
+use crate::server::traits::ServerClient;
+
+/// The length of the generated bot authentication tokens.
+const GENERATED_TOKEN_LENGTH: usize = 32;
+
+/// Authentication middleware of the admin API.
+///
+/// This function checks that the request contains the `Authorization: Bearer <token>` header
+/// with the admin token of the server before passing it to the admin endpoints.
+///
+/// # Parameters
+///
+/// * `admin_token` - The admin token of the server.
+/// * `request` - The incoming request.
+/// * `next` - The next handler of the request.
+///
+/// # Returns
+///
+/// The response of the admin endpoint, or `401 Unauthorized` if the token is missing or invalid.
+pub(super) async fn admin_auth_middleware(State(admin_token): State<Arc<String>>, request: Request, next: Next) -> Response {
+    let bearer_token = request.headers().get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "));
+
+    if bearer_token != Some(admin_token.as_str()) {
+        warn!(path = %request.uri().path(), "Unauthorized access to admin API");
+        return (StatusCode::UNAUTHORIZED, Json("Unauthorized access".to_string())).into_response();
+    }
+    next.run(request).await
+}
+
+/// Admin endpoint listing the clients.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about every client.
+pub(super) async fn list_clients_endpoint<C: ServerClient>(Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    let mut clients_info = Vec::new();
+    for client in clients.get_all_clients().await {
+        clients_info.push(client_info(client.deref()).await);
+    }
+    (StatusCode::OK, Json(Value::Array(clients_info))).into_response()
+}
+
+/// Admin endpoint registering a new client from its JSON description.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+/// * `raw_body` - The raw body of the request with the `ClientDescription`.
+///
+/// # Returns
+///
+/// A response with the information about the registered client, or a response indicating the failure.
+pub(super) async fn register_client_endpoint<C: ServerClient>(Extension(clients): Extension<Arc<ClientsManager<C>>>, raw_body: Bytes) -> Response {
+    let description: ClientDescription = match serde_json::from_slice(&raw_body) {
+        Ok(description) => description,
+        Err(err) => return (StatusCode::BAD_REQUEST, Json(format!("Invalid client description: {}", err))).into_response()
+    };
+
+    if clients.get_client(description.client_id()).await.is_some() {
+        return (StatusCode::CONFLICT, Json("Client already exists".to_string())).into_response();
+    }
+
+    let client = match C::from_description(&description) {
+        Ok(client) => client,
+        Err(error) => return (StatusCode::BAD_REQUEST, Json(error.to_string())).into_response()
+    };
+    let client = clients.add_client(client).await;
+
+    info!(client_id = %description.client_id(), "Client registered");
+    (StatusCode::CREATED, Json(client_info(client.deref()).await)).into_response()
+}
+
+/// Admin endpoint showing the client with its sessions.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the client and its sessions, or `404 Not Found`.
+pub(super) async fn client_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    let client = match get_client(&clients, &id).await {
+        Ok(client) => client,
+        Err(response) => return response
+    };
+
+    let mut info = client_info(client.deref()).await;
+    info["sessions"] = sessions_info(client.deref()).await;
+    (StatusCode::OK, Json(info)).into_response()
+}
+
+/// Admin endpoint deactivating and deleting the client.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response indicating the result of the deletion.
+pub(super) async fn delete_client_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    let client = match get_client(&clients, &id).await {
+        Ok(client) => client,
+        Err(response) => return response
+    };
+
+    // Stop the sessions cleanup of the client before removing it
+    client.client_base().deactivate();
+    clients.delete_client(&id).await;
+
+    info!(client_id = %id, "Client deleted");
+    (StatusCode::OK, Json("Client deleted".to_string())).into_response()
+}
+
+/// Admin endpoint activating the client.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the client, or `404 Not Found`.
+pub(super) async fn activate_client_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    change_client_status(&clients, &id, true).await
+}
+
+/// Admin endpoint deactivating the client.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the client, or `404 Not Found`.
+pub(super) async fn deactivate_client_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    change_client_status(&clients, &id, false).await
+}
+
+/// Admin endpoint rotating the bot authentication token of the client.
+///
+/// The body `{"token": "<token>"}` sets the token, `{"token": null}` removes it,
+/// and a body without the `token` field generates a new random token.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+/// * `raw_body` - The raw body of the request.
+///
+/// # Returns
+///
+/// A response with the new token of the client, or a response indicating the failure.
+pub(super) async fn change_token_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>, raw_body: Bytes) -> Response {
+    let client = match get_client(&clients, &id).await {
+        Ok(client) => client,
+        Err(response) => return response
+    };
+
+    // An empty body is treated as a request without the token field
+    let body: Value = if raw_body.is_empty() {
+        json!({})
+    } else {
+        match serde_json::from_slice(&raw_body) {
+            Ok(body) => body,
+            Err(_) => return (StatusCode::BAD_REQUEST, Json("Invalid request body".to_string())).into_response()
+        }
+    };
+
+    let token = match body.get("token") {
+        None => Some(generate_token()),
+        Some(Value::Null) => None,
+        Some(Value::String(token)) => Some(token.clone()),
+        Some(_) => return (StatusCode::BAD_REQUEST, Json("Token must be a string or null".to_string())).into_response()
+    };
+    client.client_base().change_bot_auth_token(token.clone()).await;
+
+    info!(client_id = %id, "Bot authentication token changed");
+    (StatusCode::OK, Json(json!({
+        "client_id": id,
+        "token": token
+    }))).into_response()
+}
+
+/// Admin endpoint listing the sessions of the client.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the client.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the sessions, or `404 Not Found`.
+pub(super) async fn sessions_endpoint<C: ServerClient>(Path(id): Path<String>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    match get_client(&clients, &id).await {
+        Ok(client) => (StatusCode::OK, Json(sessions_info(client.deref()).await)).into_response(),
+        Err(response) => response
+    }
+}
+
+/// Admin endpoint showing the session of the client.
+///
+/// # Parameters
+///
+/// * `ids` - The ID of the client and the chat ID of the session.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the session, or `404 Not Found`.
+pub(super) async fn session_endpoint<C: ServerClient>(Path((id, chat_id)): Path<(String, String)>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    match get_session(&clients, &id, &chat_id).await {
+        Ok((_, session)) => (StatusCode::OK, Json(session_info(&session).await)).into_response(),
+        Err(response) => response
+    }
+}
+
+/// Admin endpoint deleting the session of the client.
+///
+/// # Parameters
+///
+/// * `ids` - The ID of the client and the chat ID of the session.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response indicating the result of the deletion.
+pub(super) async fn delete_session_endpoint<C: ServerClient>(Path((id, chat_id)): Path<(String, String)>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    match get_session(&clients, &id, &chat_id).await {
+        Ok((client, _)) => {
+            client.client_base().sessions().delete_session(&chat_id).await;
+            info!(client_id = %id, chat_id = %chat_id, "Session deleted");
+            (StatusCode::OK, Json("Session deleted".to_string())).into_response()
+        },
+        Err(response) => response
+    }
+}
+
+/// Admin endpoint activating the session of the client.
+///
+/// # Parameters
+///
+/// * `ids` - The ID of the client and the chat ID of the session.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the session, or `404 Not Found`.
+pub(super) async fn activate_session_endpoint<C: ServerClient>(Path((id, chat_id)): Path<(String, String)>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    change_session_status(&clients, &id, &chat_id, true).await
+}
+
+/// Admin endpoint deactivating the session of the client.
+///
+/// # Parameters
+///
+/// * `ids` - The ID of the client and the chat ID of the session.
+/// * `clients` - The clients manager containing the bot clients.
+///
+/// # Returns
+///
+/// A response with the information about the session, or `404 Not Found`.
+pub(super) async fn deactivate_session_endpoint<C: ServerClient>(Path((id, chat_id)): Path<(String, String)>, Extension(clients): Extension<Arc<ClientsManager<C>>>) -> Response {
+    change_session_status(&clients, &id, &chat_id, false).await
+}
+
+/// Activates or deactivates the client.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+/// * `id` - The ID of the client.
+/// * `status` - The new status of the client.
+///
+/// # Returns
+///
+/// A response with the information about the client, or `404 Not Found`.
+async fn change_client_status<C: ServerClient>(clients: &ClientsManager<C>, id: &String, status: bool) -> Response {
+    let client = match get_client(clients, id).await {
+        Ok(client) => client,
+        Err(response) => return response
+    };
+
+    if status {
+        client.client_base().activate();
+    } else {
+        client.client_base().deactivate();
+    }
+
+    info!(client_id = %id, status, "Client status changed");
+    (StatusCode::OK, Json(client_info(client.deref()).await)).into_response()
+}
+
+/// Activates or deactivates the session and saves it into the session store.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+/// * `id` - The ID of the client.
+/// * `chat_id` - The chat ID of the session.
+/// * `status` - The new status of the session.
+///
+/// # Returns
+///
+/// A response with the information about the session, or `404 Not Found`.
+async fn change_session_status<C: ServerClient>(clients: &ClientsManager<C>, id: &String, chat_id: &String, status: bool) -> Response {
+    let (client, session) = match get_session(clients, id, chat_id).await {
+        Ok(client_session) => client_session,
+        Err(response) => return response
+    };
+
+    if status {
+        session.activate();
+    } else {
+        session.deactivate();
+    }
+    client.client_base().sessions().persist_session(&session).await;
+
+    info!(client_id = %id, chat_id = %chat_id, status, "Session status changed");
+    (StatusCode::OK, Json(session_info(&session).await)).into_response()
+}
+
+/// Returns the client by its ID.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+/// * `id` - The ID of the client.
+///
+/// # Returns
+///
+/// A `Result` containing the client or the `404 Not Found` response.
+async fn get_client<C: ServerClient>(clients: &ClientsManager<C>, id: &String) -> Result<Arc<C>, Response> {
+    clients.get_client(id).await
+        .ok_or_else(|| (StatusCode::NOT_FOUND, Json("Client not found".to_string())).into_response())
+}
+
+/// Returns the session of the client by its chat ID.
+///
+/// # Parameters
+///
+/// * `clients` - The clients manager containing the bot clients.
+/// * `id` - The ID of the client.
+/// * `chat_id` - The chat ID of the session.
+///
+/// # Returns
+///
+/// A `Result` containing the client with the session or the `404 Not Found` response.
+async fn get_session<C: ServerClient>(clients: &ClientsManager<C>, id: &String, chat_id: &String) -> Result<(Arc<C>, Arc<Session>), Response> {
+    let client = get_client(clients, id).await?;
+    let session = client.client_base().sessions().get_session(chat_id).await
+        .ok_or_else(|| (StatusCode::NOT_FOUND, Json("Session not found".to_string())).into_response())?;
+    Ok((client, session))
+}
+
+/// Builds the JSON information about the client.
+///
+/// The bot authentication token isn't included, only the flag of its presence.
+///
+/// # Parameters
+///
+/// * `client` - The client.
+///
+/// # Returns
+///
+/// A JSON `Value` with the information about the client.
+async fn client_info<C: ServerClient>(client: &C) -> Value {
+    let client_base = client.client_base();
+    json!({
+        "client_id": client_base.client_id(),
+        "integration": C::BASE_URL,
+        "active": client_base.is_active(),
+        "streaming": client_base.is_streaming(),
+        "has_bot_auth_token": client_base.bot_auth_token().await.is_some(),
+        "sessions_count": client_base.sessions().sessions_count().await
+    })
+}
+
+/// Builds the JSON information about the sessions of the client.
+///
+/// # Parameters
+///
+/// * `client` - The client.
+///
+/// # Returns
+///
+/// A JSON array with the information about the sessions.
+async fn sessions_info<C: ServerClient>(client: &C) -> Value {
+    let mut sessions_info = Vec::new();
+    for session in client.client_base().sessions().get_all_sessions().await {
+        sessions_info.push(session_info(&session).await);
+    }
+    Value::Array(sessions_info)
+}
+
+/// Builds the JSON information about the session.
+///
+/// # Parameters
+///
+/// * `session` - The session.
+///
+/// # Returns
+///
+/// A JSON `Value` with the information about the session.
+async fn session_info(session: &Session) -> Value {
+    json!({
+        "chat_id": session.get_chat_id(),
+        "active": session.is_active(),
+        "handoff": session.is_in_handoff(),
+        "last_interaction": session.get_last_interaction(),
+        "previous_message": session.previous_message().await.deref()
+    })
+}
+
+/// Generates a random bot authentication token.
+///
+/// # Returns
+///
+/// A `String` with the generated token.
+fn generate_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(GENERATED_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
@@ -1,29 +1,37 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use axum::body::Bytes;
 use axum::extract::{Path, Query};
 use axum::{Extension, Json};
+use axum::http::header::CONTENT_TYPE;
 use axum::http::StatusCode;
+use axum::response::sse::{KeepAlive, Sse};
 use axum_core::response::IntoResponse;
 use serde_json::Value;
+use tracing::field::Empty;
+use tracing::{info_span, warn, Instrument};
 use crate::core::base_structs::ClientsManager;
+use crate::core::metrics::{increment_counter, PrometheusMetrics, ERRORS_TOTAL};
 use crate::core::traits::Update;
-use crate::server::subtypes::{AuthResult, QueryParams, VoiceflousionHeadersWrapper};
-use crate::server::traits::{BotHandler, ServerClient};
+use crate::errors::VoiceflousionResult;
+use crate::server::subtypes::{AuthResult, QueryParams, ServerEventStream, UpdateProcessing, VoiceflousionHeadersWrapper};
+use crate::server::traits::ServerClient;
 
 /// Main endpoint function for handling incoming webhook requests.
 ///
 /// This function processes the incoming request, validates the client and authentication token,
-/// checks the origin header, and deserializes the update before passing it to the handler function.
+/// checks the origin header and the signature of the body, and deserializes the update before passing it to the handler function.
+/// The processing of the update is traced in the `webhook` span with the client ID, chat ID and update ID.
 ///
 /// # Parameters
 ///
 /// * `id` - The ID of the bot client extracted from the request path.
 /// * `params` - Query parameters containing additional request data and the bot authentication token.
-/// * `body` - The JSON body of the incoming request.
+/// * `raw_body` - The raw body of the incoming request.
 /// * `headers` - Wrapper for the HTTP headers in the request.
 /// * `clients` - The clients manager containing the bot clients.
 /// * `optional_allowed_origins` - Optional allowed origins for CORS settings.
-/// * `handler` - The handler function for processing the update.
+/// * `processing` - The processing of the update, either by the handler function or by the background workers.
 ///
 /// # Returns
 ///
@@ -31,37 +39,67 @@ use crate::server::traits::{BotHandler, ServerClient};
 pub(super) async fn main_endpoint<C: ServerClient>(
     id: Path<String>,
     mut params: Query<QueryParams>,
-    Json(body): Json<Value>,
+    raw_body: Bytes,
     headers: VoiceflousionHeadersWrapper,
     Extension(clients): Extension<Arc<ClientsManager<C>>>,
     Extension(optional_allowed_origins): Extension<Arc<Option<HashMap<&'static str, ()>>>>,
-    Extension(handler): Extension<Arc<dyn BotHandler<C>>>
+    Extension(processing): Extension<UpdateProcessing<C>>
 ) -> impl IntoResponse {
-    // Authenticate the request, including checking the origin and token.
-    let client = match authenticate_request::<C>(id, &mut params, Some(&body), headers, clients.clone(), optional_allowed_origins.clone()).await{
+    let span = info_span!("webhook", client_id = %id.as_str(), chat_id = Empty, update_id = Empty);
+
+    // Parse the JSON body of the request.
+    let body: Value = match serde_json::from_slice(&raw_body) {
+        Ok(body) => body,
+        Err(err) => {
+            warn!(parent: &span, error = %err, "Invalid request body");
+            return (StatusCode::BAD_REQUEST, Json("Invalid request body".to_string())).into_response();
+        }
+    };
+
+    // Authenticate the request, including checking the origin, token and body signature.
+    let client = match authenticate_request::<C>(id, &mut params, Some((&body, &raw_body)), headers, clients.clone(), optional_allowed_origins.clone()).await{
         AuthResult::Client(client) => client,
         AuthResult::Response(response) => return response
     };
 
+    // Keep the raw body for the dead letter handler of the background processing
+    let raw_update = match &processing {
+        UpdateProcessing::Background(_) => Some(body.clone()),
+        UpdateProcessing::Immediate(_) => None
+    };
+
     // Deserialize the update from the request body.
-    let update = match deserialize_update::<C::ClientUpdate<'static>>(body) {
+    let update = match info_span!(parent: &span, "parse_update").in_scope(|| deserialize_update::<C::ClientUpdate<'static>>(body)) {
         Ok(update) => update,
-        Err(err) => {
-            println!("Error deserializing update: {:?}", err);
+        Err(error) => {
+            increment_counter(ERRORS_TOTAL, &[("client_id", client.client_base().client_id().clone()), ("error", error.kind().to_string())]).await;
             return (StatusCode::OK, Json("Invalid update".to_string())).into_response();
         }
     };
+    span.record("chat_id", update.chat_id().as_str());
+    span.record("update_id", update.update_id().as_str());
 
     // Check if the client is active
     if !client.client_base().is_active() {
-        println!("Client {} deactivated!", client.client_base().client_id());
+        warn!(parent: &span, "Access to deactivated client");
         return (StatusCode::OK, Json("Access to deactivated client".to_string())).into_response();
     }
 
-    // Process the update using the handler function
-    match handler(update, client).await {
-        Ok(_) => (StatusCode::OK, Json("Ok".to_string())).into_response(),
-        Err(_) => (StatusCode::OK, Json("Handler error".to_string())).into_response(),
+    let chat_id = update.chat_id().clone();
+    match processing {
+        UpdateProcessing::Immediate(handler) => {
+            // Process the update using the handler function and let the client build the response
+            let result = handler(update, client.clone()).instrument(span).await;
+            client.build_handler_response(&chat_id, result).await
+        },
+        UpdateProcessing::Background(updates_queue) => {
+            // Queue the update for the background workers and acknowledge it right away
+            if !updates_queue.enqueue(update, raw_update.unwrap_or_default(), client.clone()) {
+                warn!(parent: &span, "Updates queue is full");
+                return (StatusCode::SERVICE_UNAVAILABLE, Json("Updates queue is full".to_string())).into_response();
+            }
+            client.build_handler_response(&chat_id, Ok(())).await
+        }
     }
 }
 
@@ -96,6 +134,62 @@ pub(super) async fn get_auth_endpoint<C: ServerClient>(
     }
 }
 
+/// Event stream endpoint for GET requests.
+///
+/// This function authenticates the request like `get_auth_endpoint` and subscribes to the messages
+/// of the chat specified by the `chat_id` query parameter. The messages are sent as Server-Sent Events.
+///
+/// # Parameters
+///
+/// * `id` - The ID of the bot client extracted from the request path.
+/// * `params` - Query parameters containing the chat ID and the bot authentication token.
+/// * `headers` - Wrapper for the HTTP headers in the request.
+/// * `clients` - The clients manager containing the bot clients.
+/// * `optional_allowed_origins` - Optional allowed origins for CORS settings.
+///
+/// # Returns
+///
+/// A Server-Sent Events response with the messages of the chat, or a response indicating the failure.
+pub(super) async fn events_endpoint<C: ServerClient>(
+    id: Path<String>,
+    mut params: Query<QueryParams>,
+    headers: VoiceflousionHeadersWrapper,
+    Extension(clients): Extension<Arc<ClientsManager<C>>>,
+    Extension(optional_allowed_origins): Extension<Arc<Option<HashMap<&'static str, ()>>>>
+) -> impl IntoResponse {
+    let client = match authenticate_request::<C>(id, &mut params, None, headers, clients.clone(), optional_allowed_origins.clone()).await{
+        AuthResult::Client(client) => client,
+        AuthResult::Response(response) => return response
+    };
+
+    // Extract the chat ID to subscribe to
+    let chat_id = if let Some(chat_id) = params.remove("chat_id") {
+        chat_id
+    } else {
+        return (StatusCode::BAD_REQUEST, Json("Missing chat_id parameter".to_string())).into_response();
+    };
+
+    match client.subscribe_events(&chat_id).await {
+        Some(messages) => Sse::new(ServerEventStream::new(messages)).keep_alive(KeepAlive::default()).into_response(),
+        None => (StatusCode::NOT_FOUND, Json("Client doesn't support event stream".to_string())).into_response()
+    }
+}
+
+/// Metrics endpoint for GET requests.
+///
+/// This function renders the metrics recorded by `PrometheusMetrics` in the Prometheus text format.
+///
+/// # Parameters
+///
+/// * `metrics` - The metrics to render.
+///
+/// # Returns
+///
+/// A response with the rendered metrics.
+pub(super) async fn metrics_endpoint(Extension(metrics): Extension<Arc<PrometheusMetrics>>) -> impl IntoResponse {
+    ([(CONTENT_TYPE, "text/plain; version=0.0.4")], metrics.render().await).into_response()
+}
+
 /// Deserializes the incoming JSON body into the appropriate update type.
 ///
 /// This function attempts to deserialize the incoming JSON body into the type expected
@@ -107,27 +201,23 @@ pub(super) async fn get_auth_endpoint<C: ServerClient>(
 ///
 /// # Returns
 ///
-/// A `Result` containing either the deserialized update or a `StatusCode` indicating an error.
-fn deserialize_update<U: Update>(body: Value) -> Result<U, StatusCode> {
-    match U::from_request_body(body) {
-        Ok(update) => Ok(update),
-        Err(err) => {
-            println!("Error: {:?}", &err);
-            Err(StatusCode::OK)
-        }
-    }
+/// A `VoiceflousionResult` containing either the deserialized update or a `VoiceflousionError` indicating an error.
+fn deserialize_update<U: Update>(body: Value) -> VoiceflousionResult<U> {
+    U::from_request_body(body).inspect_err(|error| {
+        warn!(%error, "Failed to deserialize the update");
+    })
 }
 
 /// Authenticates an incoming request.
 ///
-/// This function handles client ID validation, token authentication, and origin header checks.
+/// This function handles client ID validation, token authentication, origin header and body signature checks.
 /// If the request is authenticated successfully, it returns the client object; otherwise, it returns a response indicating the failure.
 ///
 /// # Parameters
 ///
 /// * `id` - The ID of the bot client extracted from the request path.
 /// * `params` - The query parameters extracted from the request, including the bot authentication token.
-/// * `body` - The optional JSON body of the request.
+/// * `body` - The optional JSON body of the request together with its raw bytes.
 /// * `headers` - Wrapper for the HTTP headers in the request.
 /// * `clients` - The clients manager containing the bot clients.
 /// * `optional_allowed_origins` - Optional allowed origins for CORS settings.
@@ -138,7 +228,7 @@ fn deserialize_update<U: Update>(body: Value) -> Result<U, StatusCode> {
 async fn authenticate_request<C: ServerClient>(
     Path(id): Path<String>,
     Query(params): &mut Query<QueryParams>,
-    body: Option<&Value>,
+    body: Option<(&Value, &[u8])>,
     headers: VoiceflousionHeadersWrapper,
     clients: Arc<ClientsManager<C>>,
     optional_allowed_origins: Arc<Option<HashMap<&'static str, ()>>>
@@ -147,12 +237,12 @@ async fn authenticate_request<C: ServerClient>(
     if let Some(allowed_origins) = &*optional_allowed_origins {
         if let Some(origin) = headers.get_header_str("Origin"){
             if !allowed_origins.contains_key(origin) {
-                println!("Unauthorized origin: {}", origin);
+                warn!(client_id = %id, origin, "Unauthorized origin");
                 return AuthResult::Response((StatusCode::OK, Json("Unauthorized origin".to_string())).into_response());
             }
         }
         else{
-            println!("Missing origin header in request! Client: {}\nAdd headers to request or turn of allowed origins in server!", id);
+            warn!(client_id = %id, "Missing origin header in request! Add headers to request or turn of allowed origins in server!");
             return AuthResult::Response((StatusCode::OK, Json("Missing origin header in request! Add headers to request or turn of allowed origins in server!".to_string())).into_response());
         }
     }
@@ -161,22 +251,30 @@ async fn authenticate_request<C: ServerClient>(
     let client = if let Some(client) = clients.get_client(&id).await {
         client
     } else {
-        println!("Invalid client id - {}", id);
+        warn!(client_id = %id, "Invalid client id");
         return AuthResult::Response((StatusCode::OK, Json("Invalid client id".to_string())).into_response());
     };
 
+    // Verify the signature of the raw body if the client requires it
+    if let Some((_, raw_body)) = body {
+        if let Some(response) = client.verify_request_body(&headers, raw_body) {
+            return AuthResult::Response(response);
+        }
+    }
+    let body = body.map(|(json_body, _)| json_body);
+
     // Validate authentication token if present
     if let Some(client_token) = client.client_base().bot_auth_token().await {
         let bot_auth_token = if let Some(token) = params.extract_bot_auth_token() {
             token
         } else {
-            println!("Missing token parameter");
+            warn!(client_id = %id, "Missing token parameter");
             return AuthResult::Response((StatusCode::OK, Json("Missing token parameter".to_string())).into_response());
         };
 
         // Check if the token matches
         if client_token.token() != bot_auth_token.token() {
-            println!("Unauthorized access to client {}", client.client_base().client_id());
+            warn!(client_id = %id, "Unauthorized access");
             return AuthResult::Response((StatusCode::OK, Json("Unauthorized access".to_string())).into_response());
         }
 
@@ -1,10 +1,11 @@
 use std::sync::Arc;
+use tracing::{debug, error};
 use crate::core::traits::Client;
 use crate::errors::VoiceflousionResult;
 
 /// Base dialog handler that processes client updates.
 ///
-/// This function is a default implementation for handling client updates. It prints the received update,
+/// This function is a default implementation for handling client updates. It traces the received update,
 /// interacts with the client, and handles the response.
 ///
 /// # Parameters
@@ -16,14 +17,14 @@ use crate::errors::VoiceflousionResult;
 ///
 /// A `VoiceflousionResult` indicating the success or failure of the operation.
 pub async fn base_dialog_handler<C: Client>(update: C::ClientUpdate<'_>, client: Arc<C>) -> VoiceflousionResult<()> {
-    println!("Update: {:?}", &update);
+    debug!(update = ?&update, "Update received");
     match client.interact_with_client(update, None).await {
         Ok(message) => {
-            println!("Task: {:?}", message);
+            debug!(responses = ?message, "Update answered");
             Ok(())
         },
         Err(e) => {
-            println!("Dialog: Error {:?}", e);
+            error!(error = %e, "Dialog failed");
             Err(e)
         },
     }
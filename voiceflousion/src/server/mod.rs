@@ -1,6 +1,8 @@
 mod voiceflousion_server;
+mod voiceflousion_multi_server;
 pub mod handlers;
 mod endpoints;
+mod admin_endpoints;
 pub mod traits;
 
 #[cfg(not(feature = "advanced"))]
@@ -10,4 +12,5 @@ mod subtypes;
 pub mod subtypes;
 
 
-pub use voiceflousion_server::VoiceflousionServer;
\ No newline at end of file
+pub use voiceflousion_server::VoiceflousionServer;
+pub use voiceflousion_multi_server::VoiceflousionMultiServer;
\ No newline at end of file
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::core::ClientBuilder;
+use crate::core::voiceflow::{State, VoiceflowClient};
+
+/// Represents a JSON description of a `ClientBuilder` for registering clients through the admin API.
+///
+/// The fields specific to an integration (e.g. `public_key` of Discord or `app_secret` of Messenger)
+/// are kept as extra fields and read by `ServerClient::from_description()`.
+#[derive(Debug, Deserialize)]
+pub struct ClientDescription {
+    /// The ID of the client.
+    client_id: String,
+    /// The API key of the client platform.
+    api_key: String,
+    /// The API key for accessing Voiceflow.
+    voiceflow_api_key: String,
+    /// The project ID of the Voiceflow bot.
+    voiceflow_project_id: String,
+    /// The version ID of the Voiceflow bot.
+    voiceflow_version_id: String,
+    /// The maximum number of connections per moment.
+    max_connections_per_moment: usize,
+    /// The optional duration of the connections in seconds.
+    #[serde(default)]
+    connection_duration: Option<u64>,
+    /// The optional duration a session is considered valid in seconds.
+    #[serde(default)]
+    session_duration: Option<i64>,
+    /// The optional interval of the sessions cleanup in seconds.
+    #[serde(default)]
+    sessions_cleanup_interval: Option<u64>,
+    /// The optional bot authentication token.
+    #[serde(default)]
+    bot_auth_token: Option<String>,
+    /// The status of the client, active by default.
+    #[serde(default = "default_status")]
+    status: bool,
+    /// The flag of the streaming mode.
+    #[serde(default)]
+    streaming: bool,
+    /// The variables of the launch state.
+    #[serde(default)]
+    launch_state: Vec<Value>,
+    /// The fields specific to the integration.
+    #[serde(flatten)]
+    extra_fields: HashMap<String, Value>,
+}
+
+impl ClientDescription {
+    /// Returns a reference to the ID of the client.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the client ID string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::server::subtypes::ClientDescription;
+    ///
+    /// let description: ClientDescription = serde_json::from_value(json!({
+    ///     "client_id": "client_id",
+    ///     "api_key": "api_key",
+    ///     "voiceflow_api_key": "vf_api_key",
+    ///     "voiceflow_project_id": "bot_id",
+    ///     "voiceflow_version_id": "version_id",
+    ///     "max_connections_per_moment": 10
+    /// })).unwrap();
+    /// assert_eq!(description.client_id(), "client_id");
+    /// ```
+    pub fn client_id(&self) -> &String {
+        &self.client_id
+    }
+
+    /// Returns the string field specific to the integration.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// An `Option` containing the value of the field if it is present and is a string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::server::subtypes::ClientDescription;
+    ///
+    /// let description: ClientDescription = serde_json::from_value(json!({
+    ///     "client_id": "client_id",
+    ///     "api_key": "api_key",
+    ///     "voiceflow_api_key": "vf_api_key",
+    ///     "voiceflow_project_id": "bot_id",
+    ///     "voiceflow_version_id": "version_id",
+    ///     "max_connections_per_moment": 10,
+    ///     "public_key": "public_key"
+    /// })).unwrap();
+    /// assert_eq!(description.extra_field("public_key"), Some("public_key"));
+    /// ```
+    pub fn extra_field(&self, name: &str) -> Option<&str> {
+        self.extra_fields.get(name).and_then(|value| value.as_str())
+    }
+
+    /// Builds the `ClientBuilder` described by the `ClientDescription`.
+    ///
+    /// A new `VoiceflowClient` is created for the client with the described Voiceflow credentials.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `ClientBuilder`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use voiceflousion::server::subtypes::ClientDescription;
+    ///
+    /// let description: ClientDescription = serde_json::from_value(json!({
+    ///     "client_id": "client_id",
+    ///     "api_key": "api_key",
+    ///     "voiceflow_api_key": "vf_api_key",
+    ///     "voiceflow_project_id": "bot_id",
+    ///     "voiceflow_version_id": "version_id",
+    ///     "max_connections_per_moment": 10,
+    ///     "session_duration": 3600
+    /// })).unwrap();
+    /// let builder = description.to_client_builder();
+    /// assert_eq!(builder.session_duration(), Some(3600));
+    /// ```
+    pub fn to_client_builder(&self) -> ClientBuilder {
+        let voiceflow_client = Arc::new(VoiceflowClient::new(
+            self.voiceflow_api_key.clone(),
+            self.voiceflow_project_id.clone(),
+            self.voiceflow_version_id.clone(),
+            self.max_connections_per_moment,
+            self.connection_duration
+        ));
+
+        let mut builder = ClientBuilder::new(self.client_id.clone(), self.api_key.clone(), voiceflow_client, self.max_connections_per_moment)
+            .set_status(self.status)
+            .set_streaming(self.streaming)
+            .set_launch_state(State::new(self.launch_state.clone()));
+
+        if let Some(connection_duration) = self.connection_duration {
+            builder = builder.set_connection_duration(connection_duration);
+        }
+        if let Some(session_duration) = self.session_duration {
+            builder = builder.set_session_duration(session_duration);
+        }
+        if let Some(interval) = self.sessions_cleanup_interval {
+            builder = builder.allow_sessions_cleaning(interval);
+        }
+        if let Some(bot_auth_token) = &self.bot_auth_token {
+            builder = builder.set_bot_auth_token(bot_auth_token.clone());
+        }
+        builder
+    }
+}
+
+/// Returns the default status of the described client.
+///
+/// # Returns
+///
+/// `true`, the described clients are active by default.
+fn default_status() -> bool {
+    true
+}
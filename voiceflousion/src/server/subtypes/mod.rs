@@ -1,7 +1,17 @@
 mod query_params;
 mod auth_result;
 mod voiceflousion_headers_wrapper;
+mod server_event_stream;
+mod client_description;
+mod origins_policy;
+mod updates_queue;
+mod update_processing;
 
 pub use self::query_params::QueryParams;
 pub use self::voiceflousion_headers_wrapper::VoiceflousionHeadersWrapper;
-pub(super) use self::auth_result::AuthResult;
\ No newline at end of file
+pub use self::client_description::ClientDescription;
+pub(super) use self::auth_result::AuthResult;
+pub(super) use self::server_event_stream::ServerEventStream;
+pub(super) use self::origins_policy::OriginsPolicy;
+pub(super) use self::updates_queue::UpdatesQueue;
+pub(super) use self::update_processing::UpdateProcessing;
\ No newline at end of file
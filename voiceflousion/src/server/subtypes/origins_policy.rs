@@ -0,0 +1,13 @@
+/// Enum representing the allowed origins settings shared by the integrations of `VoiceflousionMultiServer`.
+///
+/// # Variants
+///
+/// * `Default` - Applies the predefined origins of every integration.
+/// * `Override` - Extends the predefined origins of every integration with the provided origins.
+pub(crate) enum OriginsPolicy {
+    /// Variant applying the predefined origins.
+    Default,
+
+    /// Variant holding the origins extending the predefined origins.
+    Override(Vec<&'static str>),
+}
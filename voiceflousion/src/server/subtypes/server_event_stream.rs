@@ -0,0 +1,40 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use axum::response::sse::Event;
+use futures_core::Stream;
+use serde_json::Value;
+
+/// Stream of the Server-Sent Events built from the messages of the chat.
+///
+/// `ServerEventStream` wraps the stream of the messages provided by the server client
+/// and serializes every message into the data of the event.
+pub(crate) struct ServerEventStream {
+    /// The stream of the messages sent to the chat.
+    messages: Pin<Box<dyn Stream<Item = Value> + Send>>,
+}
+
+impl ServerEventStream {
+    /// Creates a new `ServerEventStream`.
+    ///
+    /// # Parameters
+    ///
+    /// * `messages` - The stream of the messages sent to the chat.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `ServerEventStream`.
+    pub fn new(messages: Pin<Box<dyn Stream<Item = Value> + Send>>) -> Self {
+        Self { messages }
+    }
+}
+
+impl Stream for ServerEventStream {
+    type Item = Result<Event, axum::Error>;
+
+    /// Polls the next message and converts it into the event.
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.as_mut()
+            .poll_next(cx)
+            .map(|message| message.map(|message| Event::default().json_data(message)))
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+use crate::server::subtypes::UpdatesQueue;
+use crate::server::traits::{BotHandler, ServerClient};
+
+/// Enum representing the way the updates received by the webhook endpoint are processed.
+///
+/// # Variants
+///
+/// * `Immediate` - The update is processed by the handler function before the request is answered.
+/// * `Background` - The update is queued for the background workers and the request is acknowledged right away.
+pub(crate) enum UpdateProcessing<C: ServerClient + 'static> {
+    /// Variant holding the handler function for processing the updates.
+    Immediate(Arc<dyn BotHandler<C>>),
+
+    /// Variant holding the queue of the background workers.
+    Background(Arc<UpdatesQueue<C>>),
+}
+
+impl<C: ServerClient + 'static> Clone for UpdateProcessing<C> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Immediate(handler) => Self::Immediate(handler.clone()),
+            Self::Background(updates_queue) => Self::Background(updates_queue.clone())
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Arc;
+use serde_json::Value;
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::task::JoinHandle;
+use tracing::{info_span, warn, Instrument};
+use crate::server::traits::{BotHandler, DeadLetterHandler, ServerClient};
+
+/// The update waiting in the queue with its raw body and the client.
+struct QueuedUpdate<C: ServerClient + 'static> {
+    /// The deserialized update.
+    update: C::ClientUpdate<'static>,
+    /// The raw JSON body of the update passed to the dead letter handler.
+    body: Value,
+    /// The client the update is addressed to.
+    client: Arc<C>,
+}
+
+/// Represents the bounded queue of the updates processed by the pool of background workers.
+///
+/// Every chat is assigned to one worker, so the updates of the chat are processed in the order they were received
+/// while the updates of different chats are processed concurrently.
+pub(crate) struct UpdatesQueue<C: ServerClient + 'static> {
+    /// The senders to the queues of the workers.
+    senders: Vec<Sender<QueuedUpdate<C>>>,
+}
+
+impl<C: ServerClient + 'static> UpdatesQueue<C> {
+    /// Creates a new `UpdatesQueue` and spawns its workers.
+    ///
+    /// Must be called within the tokio runtime.
+    ///
+    /// # Parameters
+    ///
+    /// * `workers` - The number of the workers processing the updates concurrently.
+    /// * `capacity` - The maximum number of the updates waiting in the queue of every worker.
+    /// * `handler` - The handler function for processing the updates.
+    /// * `dead_letter_handler` - The optional handler of the updates failed in the handler function.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the new `UpdatesQueue` and the handles of the workers, which finish once the queue is dropped
+    /// and the waiting updates are processed.
+    pub fn new(workers: usize, capacity: usize, handler: Arc<dyn BotHandler<C>>, dead_letter_handler: Option<Arc<dyn DeadLetterHandler<C>>>) -> (Self, Vec<JoinHandle<()>>) {
+        let (senders, handles) = (0..workers.max(1)).map(|_| {
+            let (sender, receiver) = channel(capacity.max(1));
+            let handle = tokio::spawn(Self::run_worker(receiver, handler.clone(), dead_letter_handler.clone()));
+            (sender, handle)
+        }).unzip();
+
+        (Self { senders }, handles)
+    }
+
+    /// Puts the update into the queue of the worker assigned to its chat without waiting.
+    ///
+    /// # Parameters
+    ///
+    /// * `update` - The deserialized update.
+    /// * `body` - The raw JSON body of the update.
+    /// * `client` - The client the update is addressed to.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the update is queued, `false` if the queue of the worker is full.
+    pub fn enqueue(&self, update: C::ClientUpdate<'static>, body: Value, client: Arc<C>) -> bool {
+        // Assign the chat to the worker by the hash of the client ID and the chat ID
+        let mut hasher = DefaultHasher::new();
+        client.client_base().client_id().hash(&mut hasher);
+        update.chat_id().hash(&mut hasher);
+        let index = (hasher.finish() % self.senders.len() as u64) as usize;
+
+        self.senders[index].try_send(QueuedUpdate { update, body, client }).is_ok()
+    }
+
+    /// Processes the updates from the queue of the worker one by one until the queue is closed.
+    ///
+    /// # Parameters
+    ///
+    /// * `receiver` - The receiver of the worker queue.
+    /// * `handler` - The handler function for processing the updates.
+    /// * `dead_letter_handler` - The optional handler of the updates failed in the handler function.
+    async fn run_worker(mut receiver: Receiver<QueuedUpdate<C>>, handler: Arc<dyn BotHandler<C>>, dead_letter_handler: Option<Arc<dyn DeadLetterHandler<C>>>) {
+        while let Some(QueuedUpdate { update, body, client }) = receiver.recv().await {
+            let span = info_span!("queued_update", client_id = %client.client_base().client_id(), chat_id = %update.chat_id(), update_id = %update.update_id());
+
+            if let Err(error) = handler(update, client.clone()).instrument(span.clone()).await {
+                warn!(parent: &span, %error, "Queued update failed");
+
+                if let Some(dead_letter_handler) = &dead_letter_handler {
+                    dead_letter_handler(body, client, error).instrument(span).await;
+                }
+            }
+        }
+    }
+}
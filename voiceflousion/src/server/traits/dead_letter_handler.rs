@@ -0,0 +1,22 @@
+use std::pin::Pin;
+use std::future::Future;
+use std::sync::Arc;
+use serde_json::Value;
+use crate::core::traits::Client;
+use crate::errors::VoiceflousionError;
+
+/// Trait for functions that handle the updates failed in the background processing queue.
+///
+/// `DeadLetterHandler` is a trait that defines a callback for updates which handler returned an error.
+/// The callback is an asynchronous function that takes the raw JSON body of the update, a reference to the client
+/// and the error of the handler, so the update can be logged, persisted or replayed later.
+pub trait DeadLetterHandler<C: Client + 'static>: Fn(Value, Arc<C>, VoiceflousionError) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync {}
+
+/// Implementation of `DeadLetterHandler` for any function that matches the required signature.
+///
+/// This implementation allows any function or closure that matches the required signature to be used as a `DeadLetterHandler`.
+impl<C, F> DeadLetterHandler<C> for F
+where
+    F: Fn(Value, Arc<C>, VoiceflousionError) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync,
+    C: Client + 'static,
+{}
\ No newline at end of file
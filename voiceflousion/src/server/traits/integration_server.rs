@@ -0,0 +1,34 @@
+use axum::Router;
+use tokio::task::JoinHandle;
+use crate::server::subtypes::OriginsPolicy;
+
+/// Trait erasing the client type of a `VoiceflousionServer` so that servers of different integrations
+/// can be hosted side by side by `VoiceflousionMultiServer`.
+pub(crate) trait IntegrationServer: Send {
+    /// Returns the base URL of the integration.
+    ///
+    /// # Returns
+    ///
+    /// A static string slice with the base URL.
+    fn base_url(&self) -> &'static str;
+
+    /// Returns the route path of the integration.
+    ///
+    /// # Returns
+    ///
+    /// A `String` representing the route path.
+    fn route(&self) -> String;
+
+    /// Converts the integration server into its router.
+    ///
+    /// # Parameters
+    ///
+    /// * `origins_policy` - The optional allowed origins settings shared by the integrations,
+    ///   overriding the settings of the integration server.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `Router` instance with the webhook, events and admin routes of the integration,
+    /// and the handles of its background workers.
+    fn into_integration_router(self: Box<Self>, origins_policy: Option<&OriginsPolicy>) -> (Router, Vec<JoinHandle<()>>);
+}
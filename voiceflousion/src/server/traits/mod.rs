@@ -1,9 +1,12 @@
 mod bot_handler;
+mod dead_letter_handler;
 mod server_client;
+mod integration_server;
 #[cfg(all(not(feature = "advanced"), any(
     feature = "telegram",
     feature = "whatsapp",
-    feature = "discord_unimplemented"
+    feature = "discord",
+    feature = "messenger"
 )))]
 mod utils;
 
@@ -11,4 +14,6 @@ mod utils;
 pub mod utils;
 
 pub use self::bot_handler::BotHandler;
-pub use self::server_client::ServerClient;
\ No newline at end of file
+pub use self::dead_letter_handler::DeadLetterHandler;
+pub use self::server_client::ServerClient;
+pub(crate) use self::integration_server::IntegrationServer;
\ No newline at end of file
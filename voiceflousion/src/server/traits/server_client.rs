@@ -1,26 +1,38 @@
+use std::pin::Pin;
+use async_trait::async_trait;
 use axum::http::StatusCode;
 use axum::Json;
 use axum_core::response::{IntoResponse, Response};
+use futures_core::Stream;
 use serde_json::{json, Value};
 use crate::core::subtypes::BotAuthToken;
 use crate::core::traits::Client;
-use crate::server::subtypes::{QueryParams, VoiceflousionHeadersWrapper};
+use crate::errors::{VoiceflousionError, VoiceflousionResult};
+use crate::server::subtypes::{ClientDescription, QueryParams, VoiceflousionHeadersWrapper};
 
 #[cfg(feature = "telegram")]
 use crate::integrations::telegram::TelegramClient;
 #[cfg(feature = "whatsapp")]
 use crate::integrations::whatsapp::WhatsAppClient;
-#[cfg(feature = "discord_unimplemented")]
+#[cfg(feature = "discord")]
 use crate::{
     integrations::discord::DiscordClient,
     server::traits::utils::discord_public_key_verify
 };
+#[cfg(feature = "messenger")]
+use crate::{
+    integrations::messenger::MessengerClient,
+    server::traits::utils::messenger_signature_verify
+};
+#[cfg(feature = "web")]
+use crate::integrations::web::WebClient;
 
 /// Trait that extends the `Client` trait to add server-specific functionality.
 ///
 /// `ServerClient` is designed to be implemented by clients that interact with the server
 /// and require additional logic for server client requests authentication. This trait provides a default
 /// method for authenticating server client requests, which can be overridden by specific client implementations.
+#[async_trait]
 pub trait ServerClient: Client {
 
     /// A list of allowed origins for CORS.
@@ -63,6 +75,129 @@ pub trait ServerClient: Client {
     ) -> Option<Response> {
         None
     }
+
+    /// Verifies the signature of the raw body of incoming POST requests to the server client.
+    ///
+    /// This method is used by the clients whose platforms sign the exact bytes of the request body,
+    /// so the signature can't be checked against the deserialized JSON body.
+    /// By default, it returns `None`, meaning no verification is performed.
+    ///
+    /// # Parameters
+    ///
+    /// * `_headers` - The HTTP headers extracted from the request.
+    /// * `_raw_body` - The raw body of the request.
+    ///
+    /// # Returns
+    ///
+    /// An optional `Response` indicating the result of the verification. If `None` is returned,
+    /// the request body is considered verified.
+    #[inline]
+    fn verify_request_body(
+        &self,
+        _headers: &VoiceflousionHeadersWrapper,
+        _raw_body: &[u8]
+    ) -> Option<Response> {
+        None
+    }
+
+    /// Builds the HTTP response to the processed update.
+    ///
+    /// This method is called after the handler function has processed the update.
+    /// By default, it returns a short status message, since the messages are sent to the platform API by the handler.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID of the processed update.
+    /// * `result` - The result of the handler function.
+    ///
+    /// # Returns
+    ///
+    /// A `Response` to the request with the update.
+    async fn build_handler_response(&self, _chat_id: &String, result: VoiceflousionResult<()>) -> Response {
+        match result {
+            Ok(_) => (StatusCode::OK, Json("Ok".to_string())).into_response(),
+            Err(_) => (StatusCode::OK, Json("Handler error".to_string())).into_response(),
+        }
+    }
+
+    /// Subscribes to the messages sent to the chat.
+    ///
+    /// This method is used by the clients which deliver the messages to the frontends over the event stream endpoint.
+    /// By default, it returns `None`, meaning the client doesn't support the event stream.
+    ///
+    /// # Parameters
+    ///
+    /// * `_chat_id` - The chat ID to subscribe to.
+    ///
+    /// # Returns
+    ///
+    /// An optional stream of the messages sent to the chat.
+    async fn subscribe_events(&self, _chat_id: &String) -> Option<Pin<Box<dyn Stream<Item = Value> + Send>>> {
+        None
+    }
+
+    /// Creates the client from its JSON description.
+    ///
+    /// This method is used by the admin API to register new clients without redeploying.
+    /// By default, it returns an error, meaning the client can't be registered through the admin API.
+    ///
+    /// # Parameters
+    ///
+    /// * `_description` - The description of the client.
+    ///
+    /// # Returns
+    ///
+    /// A `VoiceflousionResult` containing the client or a `VoiceflousionError` if the description is invalid.
+    fn from_description(_description: &ClientDescription) -> VoiceflousionResult<Self> where Self: Sized {
+        Err(VoiceflousionError::ValidationError(Self::BASE_URL.to_string(), "Client can't be registered from description".to_string()))
+    }
+}
+
+/// Returns the required string field of the client description specific to the integration.
+///
+/// # Parameters
+///
+/// * `description` - The description of the client.
+/// * `name` - The name of the field.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult` containing the value of the field or a `VoiceflousionError` if the field is missing.
+#[cfg(any(feature = "discord", feature = "messenger"))]
+fn required_extra_field(description: &ClientDescription, name: &str) -> VoiceflousionResult<String> {
+    description.extra_field(name)
+        .map(|value| value.to_string())
+        .ok_or_else(|| VoiceflousionError::ValidationError(description.client_id().clone(), format!("Client description doesn't contain {}", name)))
+}
+
+/// Handles the webhook verification request of the Meta platforms (WhatsApp, Messenger and Instagram).
+///
+/// The verification request contains the `hub.challenge` parameter, which must be returned
+/// if the `hub.verify_token` parameter matches the bot authentication token of the client.
+///
+/// # Parameters
+///
+/// * `params` - The query parameters extracted from the request.
+/// * `bot_auth_token` - The optional bot authentication token.
+///
+/// # Returns
+///
+/// An optional `Response` with the challenge or the authorization failure, or `None` if the request isn't a verification request.
+#[cfg(any(feature = "whatsapp", feature = "messenger"))]
+fn meta_webhook_verification(params: &mut QueryParams, bot_auth_token: Option<BotAuthToken>) -> Option<Response> {
+    if let Some(challenge) = params.remove("hub.challenge") {
+        if let Some(bot_token) = bot_auth_token {
+            if let Some(verify_token) = params.remove("hub.verify_token") {
+                if bot_token.token() != &verify_token {
+                    return Some((StatusCode::OK, Json("Webhook authorization failed!".to_string())).into_response());
+                }
+            } else {
+                return Some((StatusCode::OK, Json("Webhook authorization failed!".to_string())).into_response());
+            }
+        }
+        return Some((StatusCode::OK, challenge).into_response());
+    }
+    None
 }
 
 /// Implementation of `ServerClient` for `WhatsAppClient`.
@@ -97,20 +232,117 @@ impl ServerClient for WhatsAppClient {
         }
 
         // Handle the request verification for WhatsApp
-        if let Some(challenge) = params.remove("hub.challenge") {
-            if let Some(bot_token) = bot_auth_token {
-                if let Some(verify_token) = params.remove("hub.verify_token") {
-                    if bot_token.token() != &verify_token {
-                        return Some((StatusCode::OK, Json("Webhook authorization failed!".to_string())).into_response());
-                    }
-                } else {
-                    return Some((StatusCode::OK, Json("Webhook authorization failed!".to_string())).into_response());
-                }
+        meta_webhook_verification(params, bot_auth_token)
+    }
+
+    fn from_description(description: &ClientDescription) -> VoiceflousionResult<Self> {
+        Ok(Self::new(description.to_client_builder()))
+    }
+}
+
+/// Implementation of `ServerClient` for `MessengerClient`.
+///
+/// This implementation overrides the `authenticate_server_client_request` and `verify_request_body` methods
+/// to provide specific authentication logic for Messenger and Instagram server client requests,
+/// including the `X-Hub-Signature-256` signature verification.
+#[cfg(feature = "messenger")]
+impl ServerClient for MessengerClient {
+    /// Allowed origins for CORS specific to the Messenger client.
+    const ORIGINS: &'static [&'static str] = &[];
+
+    /// Base URL path for the Messenger client.
+    const BASE_URL: &'static str = "messenger";
+
+    fn authenticate_server_client_request(
+        &self,
+        _headers: VoiceflousionHeadersWrapper,
+        params: &mut QueryParams,
+        value: Option<&Value>,
+        bot_auth_token: Option<BotAuthToken>
+    ) -> Option<Response> {
+        // Reject the events which aren't messages, postbacks or reactions, and the echoes of the messages sent by the page
+        if let Some(json) = value {
+            let event = &json["entry"][0]["messaging"][0];
+            let is_echo = event["message"].get("is_echo").and_then(|is_echo| is_echo.as_bool()).unwrap_or(false);
+            let is_supported = event.get("message").is_some() || event.get("postback").is_some() || event.get("reaction").is_some();
+
+            if is_echo || !is_supported {
+                return Some((StatusCode::OK, Json("Unsupported event type Update rejected".to_string())).into_response());
             }
-            return Some((StatusCode::OK, challenge).into_response());
+
+            return None;
         }
+
+        // Handle the request verification for Messenger
+        meta_webhook_verification(params, bot_auth_token)
+    }
+
+    fn verify_request_body(
+        &self,
+        headers: &VoiceflousionHeadersWrapper,
+        raw_body: &[u8]
+    ) -> Option<Response> {
+        let signature = headers.get_header_str_or_empty("x-hub-signature-256");
+
+        // Return an error response if the signature header is missing
+        if signature.is_empty() {
+            return Some((StatusCode::UNAUTHORIZED, Json("Signature header isn't provided".to_string())).into_response());
+        }
+
+        // Verify the request signature using the application secret
+        if let Err(error) = messenger_signature_verify(self.get_app_secret(), signature, raw_body) {
+            tracing::warn!(%error, "Messenger signature verification failed");
+            return Some((StatusCode::UNAUTHORIZED, Json("Signature verification failed".to_string())).into_response());
+        }
+
         None
     }
+
+    fn from_description(description: &ClientDescription) -> VoiceflousionResult<Self> {
+        let app_secret = required_extra_field(description, "app_secret")?;
+        Ok(Self::new(description.to_client_builder(), app_secret))
+    }
+}
+
+/// Implementation of `ServerClient` for `WebClient`.
+///
+/// This implementation overrides the `build_handler_response` and `subscribe_events` methods
+/// to return the rendered messages as the HTTP response body or push them over the event stream.
+#[cfg(feature = "web")]
+#[async_trait]
+impl ServerClient for WebClient {
+    /// Allowed origins for CORS specific to the web client.
+    const ORIGINS: &'static [&'static str] = &[];
+
+    /// Base URL path for the web client.
+    const BASE_URL: &'static str = "web";
+
+    async fn build_handler_response(&self, chat_id: &String, result: VoiceflousionResult<()>) -> Response {
+        // Take the messages which weren't pushed to the event stream
+        let messages = self.take_messages(chat_id).await;
+
+        let body = match result {
+            Ok(_) => json!({
+                "chat_id": chat_id,
+                "messages": messages
+            }),
+            Err(error) => json!({
+                "chat_id": chat_id,
+                "messages": messages,
+                "error": error.to_string()
+            })
+        };
+
+        (StatusCode::OK, Json(body)).into_response()
+    }
+
+    async fn subscribe_events(&self, chat_id: &String) -> Option<Pin<Box<dyn Stream<Item = Value> + Send>>> {
+        Some(Box::pin(self.subscribe(chat_id).await))
+    }
+
+    fn from_description(description: &ClientDescription) -> VoiceflousionResult<Self> {
+        Ok(Self::new(description.to_client_builder()))
+    }
 }
 
 /// Implementation of `ServerClient` for `TelegramClient`.
@@ -170,13 +402,17 @@ impl ServerClient for TelegramClient {
 
     /// Base URL path for the Telegram client.
     const BASE_URL: &'static str = "telegram";
+
+    fn from_description(description: &ClientDescription) -> VoiceflousionResult<Self> {
+        Ok(Self::new(description.to_client_builder()))
+    }
 }
 
 /// Implementation of `ServerClient` for `DiscordClient`.
 ///
 /// This implementation overrides the `authenticate_server_client_request` method to provide specific
 /// authentication logic for Discord server client requests, including signature verification.
-#[cfg(feature = "discord_unimplemented")]
+#[cfg(feature = "discord")]
 impl ServerClient for DiscordClient {
     /// Allowed origins for CORS specific to the Discord client.
     const ORIGINS: &'static [&'static str] = &[];
@@ -209,7 +445,7 @@ impl ServerClient for DiscordClient {
         // Verify the request signature using the Discord public key
         let public_key = self.get_public_key();
         if let Err(error) = discord_public_key_verify(public_key, signature, timestamp, body) {
-            println!("Discord authentication error: {}", error);
+            tracing::warn!(%error, "Discord signature verification failed");
             return Some((StatusCode::UNAUTHORIZED, Json("Key verification failed".to_string())).into_response());
         }
 
@@ -224,8 +460,14 @@ impl ServerClient for DiscordClient {
 
                 Some((StatusCode::OK, body).into_response())
             },
-            4 => None,
+            // Application commands and message components are handled as updates
+            2 | 3 => None,
             _ => Some((StatusCode::UNAUTHORIZED, Json("Invalid request type".to_string())).into_response())
         }
     }
+
+    fn from_description(description: &ClientDescription) -> VoiceflousionResult<Self> {
+        let public_key = required_extra_field(description, "public_key")?;
+        Ok(Self::new(description.to_client_builder(), public_key))
+    }
 }
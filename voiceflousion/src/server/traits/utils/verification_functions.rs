@@ -1,7 +1,10 @@
+#[cfg(feature = "discord")]
 use serde_json::Value;
 use crate::errors::{VoiceflousionError, VoiceflousionResult};
-#[cfg(feature = "discord_unimplemented")]
+#[cfg(feature = "discord")]
 use ring::signature::{UnparsedPublicKey, ED25519};
+#[cfg(feature = "messenger")]
+use ring::hmac;
 
 /// Verifies a Discord request using a public key, signature, and timestamp.
 ///
@@ -43,7 +46,7 @@ use ring::signature::{UnparsedPublicKey, ED25519};
 ///     Err(err) => eprintln!("Verification failed: {}", err),
 /// }
 /// ```
-#[cfg(feature = "discord_unimplemented")]
+#[cfg(feature = "discord")]
 pub fn discord_public_key_verify(public_key: &String, signature: &str, timestamp: &str, body: &Value) -> VoiceflousionResult<()>{
     let signature_bytes = hex::decode(signature).map_err(|_| {
         VoiceflousionError::ClientRequestInvalidBodyError(
@@ -75,4 +78,59 @@ pub fn discord_public_key_verify(public_key: &String, signature: &str, timestamp
             "Discord public key verification".to_string(),
             error.to_string())
     })
+}
+
+/// Verifies a Messenger (or Instagram) webhook request using the application secret and the `X-Hub-Signature-256` header.
+///
+/// This function checks the authenticity of a Messenger request by comparing the provided
+/// signature with the HMAC-SHA256 of the raw request body, keyed with the application secret.
+///
+/// # Parameters
+///
+/// * `app_secret` - The secret of the Meta application.
+/// * `signature` - The signature provided in the `X-Hub-Signature-256` header, as a hex string with the `sha256=` prefix.
+/// * `body` - The raw body of the request.
+///
+/// # Returns
+///
+/// A `VoiceflousionResult<()>` indicating success if the verification passes, or an error if it fails.
+///
+/// # Errors
+///
+/// Returns a `VoiceflousionError::ClientRequestInvalidBodyError` if the signature
+/// is not a valid hex string, or if the verification fails.
+///
+/// # Example
+///
+/// ```rust
+/// use voiceflousion::server::traits::utils::messenger_signature_verify;
+///
+/// let app_secret = "app_secret";
+/// let signature = "sha256=1234abcd5678ef901234abcd5678ef901234abcd5678ef901234abcd5678ef90";
+/// let body = br#"{"object":"page","entry":[]}"#;
+///
+/// let result = messenger_signature_verify(app_secret, signature, body);
+///
+/// match result {
+///     Ok(_) => println!("Verification successful"),
+///     Err(err) => eprintln!("Verification failed: {}", err),
+/// }
+/// ```
+#[cfg(feature = "messenger")]
+pub fn messenger_signature_verify(app_secret: &str, signature: &str, body: &[u8]) -> VoiceflousionResult<()>{
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let signature_bytes = hex::decode(signature).map_err(|_| {
+        VoiceflousionError::ClientRequestInvalidBodyError(
+            "Messenger signature".to_string(),
+            "Signature isn't a hex string".to_string(),
+        )
+    })?;
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, app_secret.as_bytes());
+
+    hmac::verify(&key, body, &signature_bytes).map_err(|_| {
+        VoiceflousionError::ClientRequestInvalidBodyError(
+            "Messenger signature verification".to_string(),
+            "Signature doesn't match the request body".to_string())
+    })
 }
\ No newline at end of file
@@ -0,0 +1,410 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use axum::{Extension, Router};
+use axum::routing::{get, IntoMakeService};
+use tokio::task::JoinHandle;
+use tracing::info;
+use crate::core::metrics::PrometheusMetrics;
+use crate::server::endpoints::metrics_endpoint;
+use crate::server::subtypes::OriginsPolicy;
+use crate::server::traits::{IntegrationServer, ServerClient};
+use crate::server::VoiceflousionServer;
+
+/// VoiceflousionMultiServer hosts the servers of different integrations on a single listener.
+///
+/// Every integration keeps its clients manager, handler and admin API and is routed under its `BASE_URL`,
+/// while the allowed origins settings and the metrics endpoint are shared by all the integrations.
+/// The integrations must have different base URLs.
+pub struct VoiceflousionMultiServer {
+    /// Servers of the hosted integrations.
+    integrations: Vec<Box<dyn IntegrationServer>>,
+    /// Optional allowed origins settings shared by the integrations.
+    origins_policy: Option<OriginsPolicy>,
+    /// Optional metrics exposed on the `/metrics` endpoint.
+    metrics: Option<Arc<PrometheusMetrics>>,
+}
+
+impl VoiceflousionMultiServer {
+    /// Creates a new instance of `VoiceflousionMultiServer` without integrations.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `VoiceflousionMultiServer`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionMultiServer;
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            integrations: Vec::new(),
+            origins_policy: None,
+            metrics: None,
+        }
+    }
+
+    /// Adds the server of an integration.
+    ///
+    /// The metrics set on the integration server are ignored, use `VoiceflousionMultiServer::set_metrics()` instead.
+    ///
+    /// # Parameters
+    ///
+    /// * `server` - The server of the integration with the clients manager set.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionMultiServer` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an integration with the same base URL is already added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::integrations::whatsapp::WhatsAppClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///
+    /// let builder = ClientBuilder::new("telegram_id".to_string(), "telegram_api_key".to_string(), voiceflow_client.clone(), 10);
+    /// let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    /// let builder = ClientBuilder::new("whatsapp_id".to_string(), "whatsapp_api_key".to_string(), voiceflow_client, 10);
+    /// let whatsapp_client_manager = Arc::new(ClientsManager::from_clients(vec![WhatsAppClient::new(builder)]));
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new()
+    ///     .add_integration(VoiceflousionServer::<TelegramClient>::new({
+    ///         |update, client| Box::pin(base_dialog_handler(update, client))
+    ///     }).set_clients_manager(telegram_client_manager))
+    ///     .add_integration(VoiceflousionServer::<WhatsAppClient>::new({
+    ///         |update, client| Box::pin(base_dialog_handler(update, client))
+    ///     }).set_clients_manager(whatsapp_client_manager));
+    /// ```
+    pub fn add_integration<C: ServerClient + 'static>(mut self, server: VoiceflousionServer<C>) -> Self {
+        if self.integrations.iter().any(|integration| integration.base_url() == C::BASE_URL) {
+            panic!("Integration with base URL {} is already added", C::BASE_URL);
+        }
+        self.integrations.push(Box::new(server));
+        self
+    }
+
+    /// Enables default allowed origins settings of every integration using their predefined origins.
+    ///
+    /// The shared settings override the allowed origins settings of the integration servers.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionMultiServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionMultiServer;
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new()
+    ///     .enable_default_origins();
+    /// ```
+    pub fn enable_default_origins(mut self) -> Self {
+        self.origins_policy = Some(OriginsPolicy::Default);
+        self
+    }
+
+    /// Overrides the allowed origins of every integration.
+    ///
+    /// The provided origins extend the predefined origins of every integration.
+    /// The shared settings override the allowed origins settings of the integration servers.
+    ///
+    /// # Parameters
+    ///
+    /// * `origins` - A vector of strings containing the origins to allow.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionMultiServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionMultiServer;
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new()
+    ///     .override_allow_origins(vec!["http://example.com"]);
+    /// ```
+    pub fn override_allow_origins(mut self, origins: Vec<&'static str>) -> Self {
+        self.origins_policy = Some(OriginsPolicy::Override(origins));
+        self
+    }
+
+    /// Sets the metrics exposed on the `/metrics` endpoint in the Prometheus text format.
+    ///
+    /// The metrics are recorded only if the same `PrometheusMetrics` is installed with `set_metrics_recorder()`.
+    ///
+    /// # Parameters
+    ///
+    /// * `metrics` - The metrics to expose.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionMultiServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::metrics::PrometheusMetrics;
+    /// use voiceflousion::server::VoiceflousionMultiServer;
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new()
+    ///     .set_metrics(Arc::new(PrometheusMetrics::new()));
+    /// ```
+    pub fn set_metrics(mut self, metrics: Arc<PrometheusMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Returns the route paths of the hosted integrations.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `String` with the route paths in the order the integrations were added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
+    ///
+    /// let voiceflousion_server = VoiceflousionMultiServer::new()
+    ///     .add_integration(VoiceflousionServer::<TelegramClient>::new({
+    ///         |update, client| Box::pin(base_dialog_handler(update, client))
+    ///     }));
+    ///
+    /// assert_eq!(voiceflousion_server.get_routes(), vec!["/telegram/:id".to_string()]);
+    /// ```
+    pub fn get_routes(&self) -> Vec<String> {
+        self.integrations.iter().map(|integration| integration.route()).collect()
+    }
+
+    /// Converts the server into a `Router` that can be merged into an existing axum application.
+    ///
+    /// # Returns
+    ///
+    /// A `Router` instance with the routes of every integration and the metrics endpoint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clients manager of an integration is not set, or if the background processing is enabled outside the tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use axum::Router;
+    /// use axum::routing::{get, IntoMakeService};
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    /// let voiceflousion_router = VoiceflousionMultiServer::new()
+    ///     .add_integration(VoiceflousionServer::<TelegramClient>::new({
+    ///         |update, client| Box::pin(base_dialog_handler(update, client))
+    ///     }).set_clients_manager(telegram_client_manager))
+    ///     .into_router();
+    ///
+    /// let app: Router = Router::new()
+    ///     .route("/health", get(|| async { "OK" }))
+    ///     .merge(voiceflousion_router);
+    /// ```
+    pub fn into_router(self) -> Router {
+        self.create_router().0
+    }
+
+    /// Converts the server into a service creating the router for every connection.
+    ///
+    /// The service can be served with `axum::serve()` or any hyper based server, e.g. over TLS.
+    ///
+    /// # Returns
+    ///
+    /// An `IntoMakeService` wrapping the router of the server.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clients manager of an integration is not set, or if the background processing is enabled outside the tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionMultiServer;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let service = VoiceflousionMultiServer::new().into_service();
+    ///
+    ///     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    ///     tokio::spawn(async move {
+    ///         axum::serve(listener, service).await
+    ///     });
+    /// }
+    /// ```
+    pub fn into_service(self) -> IntoMakeService<Router> {
+        self.into_router().into_make_service()
+    }
+
+    /// Starts the server and begins listening for incoming requests of every integration.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` - The address to bind the server to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::integrations::whatsapp::WhatsAppClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///
+    ///     let builder = ClientBuilder::new("telegram_id".to_string(), "telegram_api_key".to_string(), voiceflow_client.clone(), 10);
+    ///     let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    ///     let builder = ClientBuilder::new("whatsapp_id".to_string(), "whatsapp_api_key".to_string(), voiceflow_client, 10);
+    ///     let whatsapp_client_manager = Arc::new(ClientsManager::from_clients(vec![WhatsAppClient::new(builder)]));
+    ///
+    ///     tokio::spawn(async move {
+    ///         VoiceflousionMultiServer::new()
+    ///             .add_integration(VoiceflousionServer::<TelegramClient>::new({
+    ///                 |update, client| Box::pin(base_dialog_handler(update, client))
+    ///             }).set_clients_manager(telegram_client_manager))
+    ///             .add_integration(VoiceflousionServer::<WhatsAppClient>::new({
+    ///                 |update, client| Box::pin(base_dialog_handler(update, client))
+    ///             }).set_clients_manager(whatsapp_client_manager))
+    ///             .run(([127, 0, 0, 1], 8080))
+    ///             .await
+    ///     });
+    /// }
+    /// ```
+    pub async fn run(self, address: impl Into<SocketAddr>) {
+        self.run_with_graceful_shutdown(address, std::future::pending()).await
+    }
+
+    /// Starts the server and begins listening for incoming requests of every integration until the shutdown signal completes.
+    ///
+    /// After the signal the server stops accepting new connections and waits for the in-flight requests,
+    /// including their handler tasks and the updates waiting in the background processing queues, to finish.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` - The address to bind the server to.
+    /// * `shutdown_signal` - The future completing when the server should shut down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::{VoiceflousionMultiServer, VoiceflousionServer};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///     let builder = ClientBuilder::new("telegram_id".to_string(), "telegram_api_key".to_string(), voiceflow_client, 10);
+    ///     let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    ///     VoiceflousionMultiServer::new()
+    ///         .add_integration(VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    ///         }).set_clients_manager(telegram_client_manager))
+    ///         .run_with_graceful_shutdown(([127, 0, 0, 1], 0), async {})
+    ///         .await;
+    /// }
+    /// ```
+    pub async fn run_with_graceful_shutdown<F>(self, address: impl Into<SocketAddr>, shutdown_signal: F)
+    where
+        F: Future<Output = ()> + Send + 'static
+    {
+        let routes = self.get_routes();
+        let (router, workers) = self.create_router();
+        let router = router.into_make_service();
+
+        // Start the HTTP server
+        let ip = address.into();
+        let listener = tokio::net::TcpListener::bind(ip).await.unwrap();
+
+        info!("Server is set on {}", ip);
+        for route in routes {
+            info!("Bots without authentication token are available on {}{}", ip, route);
+            info!("Bots with authentication token are available on {}{}/?token=<token>", ip, route);
+        }
+
+        axum::serve(listener, router).with_graceful_shutdown(shutdown_signal).await.unwrap();
+
+        // Wait for the background workers to process the queued updates
+        for worker in workers {
+            let _ = worker.await;
+        }
+        info!("Server on {} is shut down", ip);
+    }
+
+    /// Creates a new router for handling HTTP requests of every integration.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the `Router` instance with the routes of every integration and the metrics endpoint,
+    /// and the handles of the background workers of the integrations.
+    fn create_router(self) -> (Router, Vec<JoinHandle<()>>) {
+        let origins_policy = self.origins_policy;
+        let mut workers = Vec::new();
+        let router = self.integrations.into_iter().fold(Router::new(), |router, integration| {
+            let (integration_router, integration_workers) = integration.into_integration_router(origins_policy.as_ref());
+            workers.extend(integration_workers);
+            router.merge(integration_router)
+        });
+
+        // Expose the metrics if they are set
+        let router = match self.metrics {
+            Some(metrics) => router.route("/metrics", get(move || metrics_endpoint(Extension(metrics)))),
+            None => router
+        };
+        (router, workers)
+    }
+}
+
+impl Default for VoiceflousionMultiServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
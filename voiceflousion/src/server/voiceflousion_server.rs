@@ -1,14 +1,20 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use axum::{Extension, Json, Router};
+use axum::{Extension, Router};
+use axum::body::Bytes;
 use axum::extract::{Path, Query};
-use axum::routing::post;
-use serde_json::Value;
+use axum::middleware::from_fn_with_state;
+use axum::routing::{get, post, put, IntoMakeService};
+use tokio::task::JoinHandle;
+use tracing::info;
 use crate::core::base_structs::ClientsManager;
-use crate::server::endpoints::{get_auth_endpoint, main_endpoint};
-use crate::server::subtypes::{QueryParams, VoiceflousionHeadersWrapper};
-use crate::server::traits::{BotHandler, ServerClient};
+use crate::core::metrics::PrometheusMetrics;
+use crate::server::admin_endpoints::{activate_client_endpoint, activate_session_endpoint, admin_auth_middleware, change_token_endpoint, client_endpoint, deactivate_client_endpoint, deactivate_session_endpoint, delete_client_endpoint, delete_session_endpoint, list_clients_endpoint, register_client_endpoint, session_endpoint, sessions_endpoint};
+use crate::server::endpoints::{events_endpoint, get_auth_endpoint, main_endpoint, metrics_endpoint};
+use crate::server::subtypes::{OriginsPolicy, QueryParams, UpdateProcessing, UpdatesQueue, VoiceflousionHeadersWrapper};
+use crate::server::traits::{BotHandler, DeadLetterHandler, IntegrationServer, ServerClient};
 
 /// VoiceflousionServer is responsible for handling HTTP requests to bots and routing them to the appropriate handlers.
 ///
@@ -23,6 +29,14 @@ pub struct VoiceflousionServer<C: ServerClient + 'static> {
     handler: Arc<dyn BotHandler<C>>,
     /// Allowed origins for CORS settings stored in a HashMap for fast lookup.
     allowed_origins: Arc<Option<HashMap<&'static str, ()>>>,
+    /// Optional metrics exposed on the `/metrics` endpoint.
+    metrics: Option<Arc<PrometheusMetrics>>,
+    /// Optional token of the admin API.
+    admin_token: Option<Arc<String>>,
+    /// Optional number of the background workers and capacity of their queues for processing updates after acknowledgment.
+    background_processing: Option<(usize, usize)>,
+    /// Optional handler of the updates failed in the background processing.
+    dead_letter_handler: Option<Arc<dyn DeadLetterHandler<C>>>,
 }
 
 impl<C: ServerClient + 'static> VoiceflousionServer<C> {
@@ -55,7 +69,11 @@ impl<C: ServerClient + 'static> VoiceflousionServer<C> {
             clients: None,
             extend_url: None,
             handler,
-            allowed_origins: Arc::new(None)
+            allowed_origins: Arc::new(None),
+            metrics: None,
+            admin_token: None,
+            background_processing: None,
+            dead_letter_handler: None
         }
     }
 
@@ -197,6 +215,136 @@ impl<C: ServerClient + 'static> VoiceflousionServer<C> {
         self
     }
 
+    /// Sets the metrics exposed on the `/metrics` endpoint in the Prometheus text format.
+    ///
+    /// The metrics are recorded only if the same `PrometheusMetrics` is installed with `set_metrics_recorder()`.
+    ///
+    /// # Parameters
+    ///
+    /// * `metrics` - The metrics to expose.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::metrics::{set_metrics_recorder, PrometheusMetrics};
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    ///
+    /// let metrics = Arc::new(PrometheusMetrics::new());
+    /// set_metrics_recorder(metrics.clone()).unwrap();
+    ///
+    /// let voiceflousion_telegram_server = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    /// })
+    /// .set_metrics(metrics);
+    /// ```
+    pub fn set_metrics(mut self, metrics: Arc<PrometheusMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Enables the admin API authenticated with the token.
+    ///
+    /// The admin API is mounted on `/admin/{BASE_URL}` and requires the `Authorization: Bearer <token>` header.
+    /// It lists clients and their sessions, activates or deactivates clients and sessions,
+    /// rotates bot authentication tokens, deletes sessions and registers new clients from their JSON description.
+    ///
+    /// # Parameters
+    ///
+    /// * `admin_token` - The token of the admin API.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    ///
+    /// let voiceflousion_telegram_server = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    /// })
+    /// .set_admin_token("admin_token".to_string());
+    /// ```
+    pub fn set_admin_token(mut self, admin_token: String) -> Self {
+        self.admin_token = Some(Arc::new(admin_token));
+        self
+    }
+
+    /// Enables the acknowledgment of the webhook requests before processing the updates.
+    ///
+    /// The validated updates are put into a bounded queue and the requests are acknowledged right away.
+    /// The updates are processed by the pool of background workers, every chat is assigned to one worker,
+    /// so the updates of the chat are processed in the order they were received.
+    /// When the queue of the worker is full, the request is answered with `503 Service Unavailable`, so the platform retries it later.
+    ///
+    /// # Parameters
+    ///
+    /// * `workers` - The number of the workers processing the updates concurrently.
+    /// * `capacity` - The maximum number of the updates waiting in the queue of every worker.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    ///
+    /// let voiceflousion_telegram_server = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    /// })
+    /// .enable_background_processing(8, 100);
+    /// ```
+    pub fn enable_background_processing(mut self, workers: usize, capacity: usize) -> Self {
+        self.background_processing = Some((workers, capacity));
+        self
+    }
+
+    /// Sets the handler of the updates failed in the background processing.
+    ///
+    /// The handler receives the raw JSON body of the update, the client and the error returned by the update handler.
+    ///
+    /// # Parameters
+    ///
+    /// * `dead_letter_handler` - The handler function for the failed updates.
+    ///
+    /// # Returns
+    ///
+    /// The updated `VoiceflousionServer` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    ///
+    /// let voiceflousion_telegram_server = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    /// })
+    /// .enable_background_processing(8, 100)
+    /// .set_dead_letter_handler(|body, _client, error| Box::pin(async move {
+    ///     eprintln!("Update {} failed: {}", body, error);
+    /// }));
+    /// ```
+    pub fn set_dead_letter_handler(mut self, dead_letter_handler: impl DeadLetterHandler<C> + 'static) -> Self {
+        self.dead_letter_handler = Some(Arc::new(dead_letter_handler));
+        self
+    }
+
     /// Constructs the route path based on the base URL and optional extension.
     ///
     /// # Returns
@@ -265,48 +413,223 @@ impl<C: ServerClient + 'static> VoiceflousionServer<C> {
     /// }
     /// ```
     pub async fn run(self, address: impl Into<SocketAddr>) {
+        self.run_with_graceful_shutdown(address, std::future::pending()).await
+    }
+
+    /// Starts the server and begins listening for incoming requests until the shutdown signal completes.
+    ///
+    /// After the signal the server stops accepting new connections and waits for the in-flight requests,
+    /// including their handler tasks and the updates waiting in the background processing queue, to finish.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` - The address to bind the server to.
+    /// * `shutdown_signal` - The future completing when the server should shut down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use tokio;
+    /// use tokio::sync::oneshot;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///     let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    ///     let telegram_client = TelegramClient::new(builder);
+    ///
+    ///     let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![telegram_client]));
+    ///
+    ///     let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+    ///     let server = tokio::spawn(async move {
+    ///         VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    ///         })
+    ///         .set_clients_manager(telegram_client_manager)
+    ///         .run_with_graceful_shutdown(([127, 0, 0, 1], 0), async {
+    ///             let _ = shutdown_receiver.await;
+    ///         })
+    ///         .await
+    ///     });
+    ///
+    ///     shutdown_sender.send(()).unwrap();
+    ///     server.await.unwrap();
+    /// }
+    /// ```
+    pub async fn run_with_graceful_shutdown<F>(self, address: impl Into<SocketAddr>, shutdown_signal: F)
+    where
+        F: Future<Output = ()> + Send + 'static
+    {
         let route = self.get_route();
-        let router = self.create_router(route.clone()).await.into_make_service();
+        let (router, workers) = self.create_router();
+        let router = router.into_make_service();
 
         // Start the HTTP server
         let ip = address.into();
         let listener = tokio::net::TcpListener::bind(ip).await.unwrap();
 
-        println!("Server is set on {}", ip);
-        println!("Bots without authentication token are available on {}{}", ip, route);
-        println!("Bots with authentication token are available on {}{}/?token=<token>", ip, route);
+        info!("Server is set on {}", ip);
+        info!("Bots without authentication token are available on {}{}", ip, route);
+        info!("Bots with authentication token are available on {}{}/?token=<token>", ip, route);
 
-        axum::serve(listener, router).await.unwrap();
+        axum::serve(listener, router).with_graceful_shutdown(shutdown_signal).await.unwrap();
+
+        // Wait for the background workers to process the queued updates
+        for worker in workers {
+            let _ = worker.await;
+        }
+        info!("Server on {} is shut down", ip);
     }
 
+    /// Converts the server into a `Router` with the configured routes, CORS settings and the metrics endpoint.
+    ///
+    /// The router can be merged into an existing axum application, wrapped with tower layers
+    /// or served with a custom listener.
+    ///
+    /// # Returns
+    ///
+    /// A `Router` instance configured with the appropriate routes and CORS settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clients manager is not set, or if the background processing is enabled outside the tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use axum::Router;
+    /// use axum::routing::get;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::VoiceflousionServer;
+    ///
+    /// let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    /// let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    /// let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    /// let voiceflousion_router = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    /// })
+    /// .set_clients_manager(telegram_client_manager)
+    /// .into_router();
+    ///
+    /// let app: Router = Router::new()
+    ///     .route("/health", get(|| async { "OK" }))
+    ///     .merge(voiceflousion_router);
+    /// ```
+    pub fn into_router(self) -> Router{
+        self.create_router().0
+    }
+
+    /// Converts the server into a service creating the router for every connection.
+    ///
+    /// The service can be served with `axum::serve()` or any hyper based server, e.g. over TLS.
+    ///
+    /// # Returns
+    ///
+    /// An `IntoMakeService` wrapping the router of the server.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clients manager is not set, or if the background processing is enabled outside the tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use voiceflousion::core::base_structs::ClientsManager;
+    /// use voiceflousion::core::ClientBuilder;
+    /// use voiceflousion::core::voiceflow::VoiceflowClient;
+    /// use voiceflousion::integrations::telegram::TelegramClient;
+    /// use voiceflousion::server::handlers::base_dialog_handler;
+    /// use voiceflousion::server::VoiceflousionServer;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let voiceflow_client = Arc::new(VoiceflowClient::new("vf_api_key".to_string(), "bot_id".to_string(), "version_id".to_string(), 10, Some(120)));
+    ///     let builder = ClientBuilder::new("client_id".to_string(), "api_key".to_string(), voiceflow_client, 10);
+    ///     let telegram_client_manager = Arc::new(ClientsManager::from_clients(vec![TelegramClient::new(builder)]));
+    ///
+    ///     let service = VoiceflousionServer::<TelegramClient>::new({
+    ///             |update, client| Box::pin(base_dialog_handler(update, client))
+    ///     })
+    ///     .set_clients_manager(telegram_client_manager)
+    ///     .into_service();
+    ///
+    ///     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    ///     tokio::spawn(async move {
+    ///         axum::serve(listener, service).await
+    ///     });
+    /// }
+    /// ```
+    pub fn into_service(self) -> IntoMakeService<Router> {
+        self.into_router().into_make_service()
+    }
 
     /// Creates a new router for handling HTTP requests.
     ///
-    /// # Parameters
+    /// # Returns
     ///
-    /// * `url` - The constructed URL route for the server.
+    /// A tuple with the `Router` instance configured with the appropriate routes, CORS settings and the metrics endpoint,
+    /// and the handles of the background workers.
+    fn create_router(mut self) -> (Router, Vec<JoinHandle<()>>){
+        let metrics = self.metrics.take();
+        let (router, workers) = self.create_integration_router();
+
+        // Expose the metrics if they are set
+        let router = match metrics {
+            Some(metrics) => router.route("/metrics", get(move || metrics_endpoint(Extension(metrics)))),
+            None => router
+        };
+        (router, workers)
+    }
+
+    /// Creates the router of the integration without the metrics endpoint.
     ///
     /// # Returns
     ///
-    /// A `Router` instance configured with the appropriate routes and CORS settings.
-    async fn create_router(self, url: String) -> Router{
+    /// A tuple with the `Router` instance configured with the webhook, events and admin routes and CORS settings,
+    /// and the handles of the background workers.
+    fn create_integration_router(self) -> (Router, Vec<JoinHandle<()>>){
+        let url = self.get_route();
         let clients = self.clients.clone().expect("Webhook is not set");
         let handler = self.handler.clone();
         let optional_allowed_origins = self.allowed_origins.clone();
-        Router::new()
+
+        // Spawn the background workers if the background processing is enabled
+        let (processing, workers) = match self.background_processing {
+            Some((workers, capacity)) => {
+                let (updates_queue, workers) = UpdatesQueue::new(workers, capacity, handler, self.dead_letter_handler.clone());
+                (UpdateProcessing::Background(Arc::new(updates_queue)), workers)
+            },
+            None => (UpdateProcessing::Immediate(handler), Vec::new())
+        };
+
+        let router = Router::new()
             .route(&url, post({
                        let clients = clients.clone();
                        let optional_allowed_origins = optional_allowed_origins.clone();
-                       let handler = handler.clone();
-                       move |headers: VoiceflousionHeadersWrapper,  path: Path<String>, params: Query<QueryParams>, json: Json<Value>| {
+                       move |headers: VoiceflousionHeadersWrapper,  path: Path<String>, params: Query<QueryParams>, raw_body: Bytes| {
                            main_endpoint(
                                path,
                                params,
-                               json,
+                               raw_body,
                                headers,
                                Extension(clients),
                                Extension(optional_allowed_origins),
-                               Extension(handler)
+                               Extension(processing)
                            )
                        }
                    })
@@ -324,5 +647,70 @@ impl<C: ServerClient + 'static> VoiceflousionServer<C> {
                        }
                    }),
             )
+            .route(&format!("{}/events", url), get({
+                       let clients = clients.clone();
+                       let optional_allowed_origins = optional_allowed_origins.clone();
+                       move |headers: VoiceflousionHeadersWrapper, path: Path<String>, params: Query<QueryParams>| {
+                           events_endpoint(
+                               path,
+                               params,
+                               headers,
+                               Extension(clients),
+                               Extension(optional_allowed_origins)
+                           )
+                       }
+                   }),
+            );
+
+        // Mount the admin API if the admin token is set
+        let router = match self.admin_token {
+            Some(admin_token) => router.nest(&format!("/admin/{}", C::BASE_URL), Self::create_admin_router(clients, admin_token)),
+            None => router
+        };
+        (router, workers)
+    }
+
+    /// Creates the router of the admin API.
+    ///
+    /// # Parameters
+    ///
+    /// * `clients` - The clients manager containing the bot clients.
+    /// * `admin_token` - The token of the admin API.
+    ///
+    /// # Returns
+    ///
+    /// A `Router` instance with the admin endpoints behind the admin authentication.
+    fn create_admin_router(clients: Arc<ClientsManager<C>>, admin_token: Arc<String>) -> Router {
+        Router::new()
+            .route("/clients", get(list_clients_endpoint::<C>).post(register_client_endpoint::<C>))
+            .route("/clients/:id", get(client_endpoint::<C>).delete(delete_client_endpoint::<C>))
+            .route("/clients/:id/activate", post(activate_client_endpoint::<C>))
+            .route("/clients/:id/deactivate", post(deactivate_client_endpoint::<C>))
+            .route("/clients/:id/token", put(change_token_endpoint::<C>))
+            .route("/clients/:id/sessions", get(sessions_endpoint::<C>))
+            .route("/clients/:id/sessions/:chat_id", get(session_endpoint::<C>).delete(delete_session_endpoint::<C>))
+            .route("/clients/:id/sessions/:chat_id/activate", post(activate_session_endpoint::<C>))
+            .route("/clients/:id/sessions/:chat_id/deactivate", post(deactivate_session_endpoint::<C>))
+            .route_layer(from_fn_with_state(admin_token, admin_auth_middleware))
+            .layer(Extension(clients))
+    }
+}
+
+impl<C: ServerClient + 'static> IntegrationServer for VoiceflousionServer<C> {
+    fn base_url(&self) -> &'static str {
+        C::BASE_URL
+    }
+
+    fn route(&self) -> String {
+        self.get_route()
+    }
+
+    fn into_integration_router(self: Box<Self>, origins_policy: Option<&OriginsPolicy>) -> (Router, Vec<JoinHandle<()>>) {
+        let server = match origins_policy {
+            Some(OriginsPolicy::Default) => self.enable_default_origins(),
+            Some(OriginsPolicy::Override(origins)) => self.override_allow_origins(origins.clone()),
+            None => *self
+        };
+        server.create_integration_router()
     }
 }
\ No newline at end of file